@@ -21,13 +21,29 @@
  * DEALINGS IN THE SOFTWARE.
  *
  */
-//use std::path::Path;
+use std::path::Path;
 
-//use xkbcommon_rs_codegen::*;
+use xkbcommon_rs_codegen::*;
 
 fn main() {
     // converts all lalrpop files into .rs files
     lalrpop::process_root().unwrap();
+    println!("cargo:rerun-if-changed=src/parser.lalrpop");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // generates the keysym tables from the vendored xorgproto headers
+    let headers_dir = Path::new("xorgproto").join("include").join("X11");
+    for header in KEYSYM_HEADERS {
+        println!(
+            "cargo:rerun-if-changed={}",
+            headers_dir.join(header).display()
+        );
+    }
+    let out_dir = std::env::var_os("OUT_DIR").unwrap();
+    make_keysyms_file(
+        &headers_dir,
+        &Path::new(&out_dir).join("keysyms_generated_phf.rs"),
+    );
 
     // TODO: enable as feature
     /*
@@ -0,0 +1,292 @@
+/*
+ * Copyright 2022-2023 John Nunley
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+//! Generates the keysym name tables from the xorgproto headers, based on the keysym generator
+//! of the `xkeysym` crate.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// The headers defining keysyms, in the order in which they are read.
+pub const KEYSYM_HEADERS: [&str; 5] = [
+    "keysymdef.h",
+    "XF86keysym.h",
+    "Sunkeysym.h",
+    "DECkeysym.h",
+    "HPkeysym.h",
+];
+
+struct KeysymDef {
+    value: u64,
+    // e.g. `XF86AudioPlay`
+    name: String,
+    // the comment of the definition, if any
+    comment: Option<String>,
+    deprecated: bool,
+    // the Unicode code point, if the keysym corresponds one-to-one to a character
+    unicode: Option<u32>,
+}
+
+fn read_keysym_defs(headers_dir: &Path) -> Vec<KeysymDef> {
+    // we're looking for lines of the following form:
+    // #define {some prefix}XK_{some key name} {some key code}
+    // the keycode may be wrapped in _EVDEVK(*), wchich means we have to
+    // add to it
+    //
+    // the keycode will always be in hexadecimal (0x2F) form
+    let line_matcher =
+        regex::Regex::new(r#"^#define\s+([a-zA-Z0-9_]*XK_[A-Za-z0-9_]+)\s+(.*)"#).unwrap();
+    let evdevk = regex::Regex::new(r#"_EVDEVK\(0x(.*)\)"#).unwrap();
+    let hex_matcher = regex::Regex::new(r#"0x([0-9a-fA-F]+)"#).unwrap();
+    let comment_matcher = regex::Regex::new(r#"/\*\s*(.*?)\s*\*/"#).unwrap();
+    // legacy mappings, which are not one-to-one, are enclosed in parentheses
+    let unicode_matcher = regex::Regex::new(r#"^U\+([0-9A-Fa-f]{4,6})\s"#).unwrap();
+
+    let mut defs: Vec<KeysymDef> = vec![];
+
+    for header in KEYSYM_HEADERS {
+        let file = BufReader::new(File::open(headers_dir.join(header)).unwrap());
+
+        for line in file.lines() {
+            let line = line.unwrap();
+            let Some((_, [name, value])) = line_matcher.captures(&line).map(|caps| caps.extract())
+            else {
+                continue;
+            };
+
+            // if the value is wrapped in _EVDEVK(*), unwrap it
+            let (is_evdevk, hex_value) = match evdevk.captures(value) {
+                Some(captures) => (true, captures.get(1)),
+                None => (false, hex_matcher.captures(value).and_then(|c| c.get(1))),
+            };
+            let Some(hex_value) = hex_value else {
+                continue;
+            };
+
+            // compute the value of the keysym
+            let Ok(mut hex_value) = u64::from_str_radix(hex_value.as_str(), 16) else {
+                continue;
+            };
+            if is_evdevk {
+                hex_value += 0x10081000;
+            }
+
+            // there is a duplicate symbol somewhere in here
+            if name.contains("Ydiaeresis") && hex_value == 0x100000ee {
+                continue;
+            }
+
+            // split apart the symbol at the end
+            let (prefix, symbol) = name.split_once("XK_").unwrap();
+
+            let comment = comment_matcher
+                .captures(value)
+                .and_then(|c| c.get(1))
+                .map(|c| c.as_str().to_owned());
+
+            let unicode = comment
+                .as_deref()
+                .and_then(|c| unicode_matcher.captures(c))
+                .and_then(|c| u32::from_str_radix(&c[1], 16).ok());
+
+            defs.push(KeysymDef {
+                value: hex_value,
+                name: format!("{}{}", prefix, symbol),
+                comment,
+                deprecated: false,
+                unicode,
+            });
+        }
+    }
+
+    // The comment tells whether the name is deprecated: `deprecated`,
+    // `deprecated alias for X` or `non-deprecated alias for X`.
+    //
+    // Where several names are defined for the same keysym, all but the first one listed should
+    // be considered deprecated, unless they are explicitly marked otherwise. Names listed
+    // before it which are explicitly deprecated do not count.
+    let explicit = |def: &KeysymDef| match def.comment.as_deref() {
+        Some(comment) if comment.contains("non-deprecated") => Some(false),
+        Some(comment) if comment.contains("deprecated") => Some(true),
+        _ => None,
+    };
+    let deprecated = defs
+        .iter()
+        .enumerate()
+        .map(|(idx, def)| {
+            explicit(def).unwrap_or_else(|| {
+                defs.iter()
+                    .position(|d| d.value == def.value && explicit(d) != Some(true))
+                    != Some(idx)
+            })
+        })
+        .collect::<Vec<_>>();
+    for (def, deprecated) in defs.iter_mut().zip(deprecated) {
+        def.deprecated = deprecated;
+    }
+
+    defs
+}
+
+/// Generate the keysym tables from the headers in `headers_dir`, e.g. `/usr/include/X11`.
+///
+/// The following tables are written to `out_path`:
+/// - `NAME_TO_KEYSYM`: the keysyms by name, in case-insensitive order of the names;
+/// - `KEYSYM_TO_NAME`: the names by keysym, in order of the keysyms, the aliases of a keysym in
+///   the order in which they are defined;
+/// - `DEPRECATED_KEYSYM_NAMES`: the deprecated names, in order;
+/// - `KEYSYM_TO_UNICODE`: the Unicode code points of the keysyms which correspond one-to-one
+///   to a character, in order of the keysyms.
+pub fn make_keysyms_file(headers_dir: &Path, out_path: &Path) {
+    let defs = read_keysym_defs(headers_dir);
+
+    let mut out_file = BufWriter::new(File::create(out_path).unwrap());
+
+    writeln!(
+        &mut out_file,
+        r#"
+// This file is autogenerated by codegen/src/keysyms.rs
+/* Copyright 2022-2023 John Nunley
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+use phf::phf_ordered_map;
+use xkeysym::Keysym;"#
+    )
+    .unwrap();
+
+    // The names, in case-insensitive order
+    let mut by_name = defs.iter().collect::<Vec<_>>();
+    by_name.sort_by(|a, b| {
+        a.name
+            .to_lowercase()
+            .cmp(&b.name.to_lowercase())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let mut name_to_keysym = "
+#[allow(unreachable_patterns)]
+pub(crate) static NAME_TO_KEYSYM: phf::OrderedMap<&'static str, Keysym> = phf_ordered_map! {
+    \"NoSymbol\" => Keysym::NoSymbol,\n"
+        .to_string();
+    for def in &by_name {
+        writeln!(
+            name_to_keysym,
+            "    \"{}\" => Keysym::new({:#x}),",
+            def.name, def.value
+        )
+        .unwrap();
+    }
+    name_to_keysym.push_str("};\n");
+
+    // The keysyms, in order; the sort is stable, so aliases stay in the order of definition
+    let mut by_value = defs.iter().collect::<Vec<_>>();
+    by_value.sort_by_key(|def| def.value);
+
+    let mut keysym_to_name = format!(
+        "
+pub(crate) static KEYSYM_TO_NAME: [(Keysym, &str); {}] = [
+    (xkeysym::NO_SYMBOL, \"NoSymbol\"),\n",
+        by_value.len() + 1
+    );
+    for def in &by_value {
+        writeln!(
+            keysym_to_name,
+            "    (Keysym::new({:#x}), \"{}\"),",
+            def.value, def.name
+        )
+        .unwrap();
+    }
+    keysym_to_name.push_str("];\n");
+
+    // The deprecated names, sorted for binary search
+    let mut deprecated = defs.iter().filter(|def| def.deprecated).collect::<Vec<_>>();
+    deprecated.sort_by(|a, b| a.name.cmp(&b.name));
+    deprecated.dedup_by(|a, b| a.name == b.name);
+
+    let mut deprecated_names = format!(
+        "
+pub(crate) static DEPRECATED_KEYSYM_NAMES: [&str; {}] = [\n",
+        deprecated.len()
+    );
+    for def in &deprecated {
+        match &def.comment {
+            Some(comment) if comment.contains("deprecated") => {
+                writeln!(deprecated_names, "    \"{}\", // {}", def.name, comment).unwrap()
+            }
+            _ => writeln!(deprecated_names, "    \"{}\",", def.name).unwrap(),
+        }
+    }
+    deprecated_names.push_str("];\n");
+
+    // The Unicode code points
+    let mut unicode = by_value
+        .iter()
+        .filter_map(|def| Some((def, def.unicode?)))
+        .collect::<Vec<_>>();
+    unicode.dedup_by_key(|(def, _)| def.value);
+
+    let mut keysym_to_unicode = format!(
+        "
+pub(crate) static KEYSYM_TO_UNICODE: [(Keysym, u32); {}] = [\n",
+        unicode.len()
+    );
+    for (def, ucs) in &unicode {
+        writeln!(
+            keysym_to_unicode,
+            "    (Keysym::new({:#x}), {:#06x}),",
+            def.value, ucs
+        )
+        .unwrap();
+    }
+    keysym_to_unicode.push_str("];\n");
+
+    writeln!(
+        &mut out_file,
+        "{name_to_keysym}{keysym_to_name}{deprecated_names}{keysym_to_unicode}"
+    )
+    .unwrap();
+}
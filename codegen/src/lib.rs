@@ -2,7 +2,9 @@
 //! as a standalone crate.
 
 mod config;
+mod keysyms;
 mod keywords;
 
 pub use config::make_config;
+pub use keysyms::{make_keysyms_file, KEYSYM_HEADERS};
 pub use keywords::make_keywords_file;
@@ -30,8 +30,10 @@
 use crate::atom::*;
 use crate::config::*;
 use crate::errors::*;
+use crate::keymap::cache::ConsumedFile;
 use crate::rust_xkbcommon::{ContextFlags, RuleNames};
 use crate::utils::*;
+use std::path::PathBuf;
 
 pub mod errors {
     use thiserror::Error;
@@ -67,6 +69,13 @@ pub struct Context {
 
     atom_table: AtomTable,
 
+    // Only recorded for `KeymapCache`, to invalidate cached keymaps:
+    // the files read through `find_file_in_xkb_path` and rules includes,
+    // and the paths searched before them which did not exist.
+    pub(crate) track_files: bool,
+    pub(crate) consumed_files: Vec<ConsumedFile>,
+    pub(crate) missing_files: Vec<PathBuf>,
+
     use_environment_names: bool,
     use_secure_getenv: bool,
 }
@@ -91,6 +100,9 @@ impl Context {
 
             includes: vec![],
             failed_includes: vec![],
+            track_files: false,
+            consumed_files: vec![],
+            missing_files: vec![],
         };
 
         if !context_flags.intersects(ContextFlags::NO_DEFAULT_INCLUDES)
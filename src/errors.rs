@@ -40,7 +40,7 @@ pub mod state {
 pub mod keymap {
 
     //! Errors for the [Keymap](crate::Keymap) struct.
-    pub use super::KeymapCompileError;
+    pub use super::{KeymapCompileError, KeymapLoadError};
 }
 
 use thiserror::Error;
@@ -610,12 +610,18 @@ pub enum KeymapCompileError {
     #[error("Matcher error: {0:?}")]
     MatcherError(String),
 
+    #[error("Rules file \"{0}\" not found")]
+    RulesFileNotFound(String),
+
     #[error("Cannot compile a {0:?} file alone into a keymap")]
     OnlyPartialKeymap(XkbFileType),
 
     #[error("Required section {0:?} missing from keymap")]
     RequiredSectionMissing(XkbFileType),
 
+    #[error("Cannot overlay a {0:?} section on a keymap")]
+    CannotOverlay(XkbFileType),
+
     #[error("Could not parse the provided string: {error:?}")]
     CouldNotParseString { error: XkbFileParseError },
 
@@ -633,6 +639,27 @@ pub enum KeymapCompileError {
 
     #[error("Unrecognized CompileFlags")]
     UnrecognizedCompileFlags,
+
+    #[error("Could not serialize the keymap: {0}")]
+    CouldNotSerialize(String),
+
+    #[error("Invalid keymap view: {0}")]
+    InvalidView(String),
+}
+
+#[derive(Debug, Error)]
+pub enum KeymapLoadError {
+    #[error("Not a serialized keymap")]
+    BadMagic,
+
+    #[error("Unsupported serialized keymap version {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("Unexpected end of serialized keymap")]
+    UnexpectedEnd,
+
+    #[error("Invalid serialized keymap: {0}")]
+    Invalid(String),
 }
 
 #[derive(Debug, Error)]
@@ -696,6 +723,9 @@ pub(crate) enum RulesCompileError {
 
     #[error("No rules components returned from {0}")]
     NoComponentsReturned(PathBuf),
+
+    #[error("Rules file \"{0}\" not found")]
+    RulesFileNotFound(String),
 }
 
 impl From<RulesCompileError> for KeymapCompileError {
@@ -704,6 +734,7 @@ impl From<RulesCompileError> for KeymapCompileError {
         match r {
             NoComponentsReturned(p) => Self::NoComponentsReturned(p),
             MatcherError(e) => Self::MatcherError(e.to_string()),
+            RulesFileNotFound(name) => Self::RulesFileNotFound(name),
         }
     }
 }
@@ -93,6 +93,17 @@ use std::collections::BTreeMap;
 
 pub(crate) const MOD_REAL_MASK_ALL: ModMask = 0x000000ff;
 use crate::rust_xkbcommon::*;
+use crate::xkbcomp::ast::MergeMode;
+
+mod bytes;
+pub(crate) mod cache;
+pub(crate) mod diff;
+mod fingerprint;
+pub(crate) mod key_types;
+pub(crate) mod lint;
+pub(crate) mod lookup;
+#[cfg(feature = "serde")]
+pub(crate) mod view;
 
 /// Maximum number of allowed groups
 ///
@@ -754,6 +765,9 @@ pub struct Keymap {
     pub(crate) symbols_section_name: Option<String>,
     pub(crate) types_section_name: Option<String>,
     pub(crate) compat_section_name: Option<String>,
+
+    // computed once the keymap is complete, since it does not change afterwards
+    pub(crate) fingerprint: [u8; 32],
 }
 
 pub(crate) struct KeymapBuilder<T: KeymapFormatType> {
@@ -846,6 +860,8 @@ impl<T: KeymapFormatType> KeymapBuilder<T> {
             symbols_section_name: self.symbols_section_name,
             types_section_name: self.types_section_name,
             compat_section_name: self.compat_section_name,
+
+            fingerprint: [0; 32],
         }
     }
 }
@@ -954,6 +970,25 @@ impl Keymap {
         self.text_v1_keymap_get_as_string()
     }
 
+    /// Create a new keymap by overlaying a fragment on this keymap.
+    ///
+    /// The fragment is written in the keymap text format. It may be a single section, such as
+    /// `xkb_symbols { key <AC01> { [ q, Q ] }; };`, or a whole `xkb_keymap` with several
+    /// sections. Its definitions are merged into the corresponding sections of this keymap
+    /// using `merge`, as if they had been included with that mode; definitions with an
+    /// explicit merge mode keep it. [MergeMode::Default] is the same as [MergeMode::Override].
+    ///
+    /// The keymap is compiled again in the same context, but without going through the rules.
+    pub fn overlay(&self, fragment: &str, merge: MergeMode) -> Result<Keymap, KeymapCompileError> {
+        let base = self
+            .text_v1_keymap_get_as_string()
+            .map_err(|e| KeymapCompileError::CouldNotSerialize(e.to_string()))?;
+
+        let keymap_builder = KeymapBuilder::new(self.context.clone(), TextV1, self.flags.clone());
+
+        keymap_builder.keymap_new_from_overlay(&base, fragment, merge)
+    }
+
     /// Create a keymap from a keymap file.
     ///
     /// The file must contain a complete keymap. For example, in the [KeymapFormat::TextV1] format,
@@ -1010,6 +1045,47 @@ impl Keymap {
             .and_then(|atom| self.mods.mod_name_to_index(atom, ModType::BOTH))
     }
 
+    /// Get the real modifiers a modifier is bound to, e.g. Mod2 for NumLock.
+    ///
+    /// A real modifier is bound to itself. Returns 0 if the modifier does not exist, or if it
+    /// is a virtual modifier which is not bound to any real modifier.
+    pub fn mod_get_mapping(&self, idx: ModIndex) -> ModMask {
+        match self.mods.mods.get(idx) {
+            Some(_mod) if _mod.mod_type == ModType::REAL => 1 << idx,
+            Some(_mod) => _mod.mapping,
+            None => 0,
+        }
+    }
+
+    /// Test whether a modifier is a virtual modifier, e.g. NumLock or LevelThree.
+    pub fn mod_is_virtual(&self, idx: ModIndex) -> bool {
+        self.mods
+            .mods
+            .get(idx)
+            .is_some_and(|_mod| _mod.mod_type == ModType::VIRT)
+    }
+
+    /// Convert a mask of real and virtual modifiers into the mask of the real modifiers they
+    /// are bound to.
+    ///
+    /// This is how the keymap resolves the modifiers of key types, actions and LEDs.
+    pub fn mod_mask_to_real(&self, mask: ModMask) -> ModMask {
+        self.mods.mod_mask_get_effective(mask)
+    }
+
+    /// Get the real modifiers of a key, as set by `modifier_map` statements.
+    pub fn key_get_modmap(&self, kc: impl Into<RawKeycode>) -> ModMask {
+        self.xkb_key(kc.into()).map(|key| key.modmap).unwrap_or(0)
+    }
+
+    /// Get the virtual modifiers of a key, as set by `virtualMods` or by symbol
+    /// interpretations.
+    ///
+    /// The real modifiers of the key are bound to each of these virtual modifiers.
+    pub fn key_get_vmodmap(&self, kc: impl Into<RawKeycode>) -> ModMask {
+        self.xkb_key(kc.into()).map(|key| key.vmodmap).unwrap_or(0)
+    }
+
     pub fn num_layouts(&self) -> LayoutIndex {
         self.num_groups
     }
@@ -1196,16 +1272,53 @@ impl Keymap {
     }
 
     pub fn key_by_name(&self, name: impl AsRef<str>) -> Option<Keycode> {
-        // try to resolve key alias first, or use plain name
-        let atom = self
-            .context
-            .atom_lookup(name.as_ref())
-            .and_then(|atom| self.resolve_key_alias(atom).or(Some(atom)))?;
+        self.key_by_name_opts(name, true)
+    }
+
+    /// Find a key by its name, e.g. "AE01".
+    ///
+    /// If `use_aliases` is set and no key has the name, the name is
+    /// looked up in the key aliases of the keymap, e.g. "LatQ".
+    // corresponds to XkbKeyByName
+    pub fn key_by_name_opts(&self, name: impl AsRef<str>, use_aliases: bool) -> Option<Keycode> {
+        let atom = self.context.atom_lookup(name.as_ref())?;
+
+        let find = |name: Atom| {
+            self.keys
+                .iter()
+                .find(|(_, key)| key.name == name)
+                .map(|(kc, _)| Keycode::new(*kc))
+        };
+
+        find(atom).or_else(|| {
+            use_aliases
+                .then(|| find(self.resolve_key_alias(atom)?))
+                .flatten()
+        })
+    }
 
-        self.keys
+    /// Get the aliases of a key, e.g. "LatQ" for "AD01".
+    pub fn key_get_aliases(&self, kc: impl Into<RawKeycode>) -> Vec<&str> {
+        let Some(key) = self.xkb_key(kc.into()) else {
+            return vec![];
+        };
+
+        self.key_aliases
             .iter()
-            .find(|(_, key)| key.name == atom)
-            .map(|(kc, _)| Keycode::new(*kc))
+            .filter(|alias| alias.real == key.name)
+            .filter_map(|alias| self.context.atom_text(alias.alias))
+            .collect()
+    }
+
+    /// Iterate over the key aliases of the keymap, as pairs of
+    /// an alias and the name of the key it refers to.
+    pub fn iter_aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.key_aliases.iter().filter_map(|alias| {
+            Some((
+                self.context.atom_text(alias.alias)?,
+                self.context.atom_text(alias.real)?,
+            ))
+        })
     }
 
     pub fn key_repeats(&self, kc: impl Into<RawKeycode>) -> bool {
@@ -0,0 +1,749 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+use crate::errors::KeymapLoadError;
+
+// Identifies a compiled keymap
+const MAGIC: &[u8; 4] = b"xkbk";
+
+/// The version of the format written by [Keymap::to_bytes()].
+///
+/// Must be changed whenever the layout of the serialized keymap changes.
+pub(crate) const FORMAT_VERSION: u32 = 1;
+
+impl Keymap {
+    /// Serialize the compiled keymap to a compact binary format.
+    ///
+    /// Unlike [Keymap::get_as_string()], the result is not meant to be read by anything but
+    /// [Keymap::from_bytes()] from the same version of this crate: loading it does not involve
+    /// the compiler. The format is versioned, and keymaps serialized in another version of the
+    /// format are rejected.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        w.buf.extend_from_slice(MAGIC);
+        w.u32(FORMAT_VERSION);
+
+        w.u32(self.flags.clone().into());
+        w.u32(self.format.into());
+        w.u16(self.enabled_ctrls.bits());
+        w.u32(self.min_key_code);
+        w.u32(self.max_key_code);
+
+        w.len(self.mods.mods.len());
+        for _mod in self.mods.mods.iter() {
+            w.atom(&self.context, _mod.name);
+            w.u8(_mod.mod_type.bits());
+            w.u32(_mod.mapping);
+        }
+
+        w.len(self.types.len());
+        for _type in self.types.iter() {
+            w.atom(&self.context, _type.name);
+            w.mods(&_type.mods);
+            w.len(_type.num_levels);
+            w.len(_type.level_names.len());
+            for (level, name) in _type.level_names.iter() {
+                w.len(*level);
+                w.atom(&self.context, *name);
+            }
+            w.len(_type.entries.len());
+            for entry in _type.entries.iter() {
+                w.len(entry.level);
+                w.mods(&entry.mods);
+                w.mods(&entry.preserve);
+            }
+        }
+
+        w.len(self.sym_interprets.len());
+        for si in self.sym_interprets.iter() {
+            w.keysym(si.sym);
+            w.u8(match si.match_op {
+                MatchOperation::None => 0,
+                MatchOperation::AnyOrNone => 1,
+                MatchOperation::Any => 2,
+                MatchOperation::All => 3,
+                MatchOperation::Exactly => 4,
+            });
+            w.mods(&si.mods);
+            w.option(si.virtual_mod, |w, vmod| w.len(vmod));
+            w.action(&si.action);
+            w.bool(si.level_one_only);
+            w.bool(si.repeat);
+        }
+
+        w.len(self.num_groups);
+        w.len(self.group_names.len());
+        for name in self.group_names.iter() {
+            w.atom(&self.context, *name);
+        }
+
+        w.len(self.leds.iter().flatten().count());
+        for (idx, led) in self.leds.iter().enumerate() {
+            if let Some(led) = led {
+                w.len(idx);
+                w.option(led.name, |w, name| w.atom(&self.context, name));
+                w.u16(led.which_groups.bits());
+                w.u32(led.groups);
+                w.u16(led.which_mods.bits());
+                w.mods(&led.mods);
+                w.u16(led.ctrls.bits());
+            }
+        }
+
+        w.len(self.key_aliases.len());
+        for alias in self.key_aliases.iter() {
+            w.atom(&self.context, alias.alias);
+            w.atom(&self.context, alias.real);
+        }
+
+        w.len(self.keys.len());
+        for key in self.keys.values() {
+            w.u32(key.keycode.0);
+            w.atom(&self.context, key.name);
+            w.u8(key.explicit.bits());
+            w.u32(key.modmap);
+            w.u32(key.vmodmap);
+            w.bool(key.repeats);
+            w.u8(match key.out_of_range_group_action {
+                RangeExceedType::Wrap => 0,
+                RangeExceedType::Saturate => 1,
+                RangeExceedType::Redirect => 2,
+            });
+            w.len(key.out_of_range_group_number);
+            w.len(key.groups.len());
+            for group in key.groups.iter() {
+                w.bool(group.explicit_type);
+                w.len(group.key_type);
+                w.len(group.levels.len());
+                for level in group.levels.iter() {
+                    w.action(&level.action);
+                    w.len(level.syms.len());
+                    for sym in level.syms.iter() {
+                        w.keysym(*sym);
+                    }
+                }
+            }
+        }
+
+        for name in [
+            &self.keycodes_section_name,
+            &self.types_section_name,
+            &self.compat_section_name,
+            &self.symbols_section_name,
+        ] {
+            w.option(name.as_deref(), |w, name| w.str(name));
+        }
+
+        w.buf
+    }
+
+    /// Load a keymap serialized with [Keymap::to_bytes()].
+    ///
+    /// The names in the keymap are interned in the given context. The keymap is validated
+    /// before being returned, so that a truncated or corrupted input is rejected instead of
+    /// producing a keymap which would misbehave later on.
+    pub fn from_bytes(mut context: Context, bytes: &[u8]) -> Result<Self, KeymapLoadError> {
+        let mut r = Reader::new(bytes);
+
+        if r.take(MAGIC.len())? != MAGIC {
+            return Err(KeymapLoadError::BadMagic);
+        }
+        let version = r.u32()?;
+        if version != FORMAT_VERSION {
+            return Err(KeymapLoadError::UnsupportedVersion(version));
+        }
+
+        let flags = r
+            .u32()?
+            .try_into()
+            .map_err(|_| invalid("unrecognized compile flags"))?;
+        let format = r
+            .u32()?
+            .try_into()
+            .map_err(|_| invalid("unrecognized keymap format"))?;
+        let enabled_ctrls = known(ActionControls::from_bits(r.u16()?), "controls")?;
+        let min_key_code = r.u32()?;
+        let max_key_code = r.u32()?;
+
+        let num_mods = r.len()?;
+        if num_mods > XKB_MAX_MODS {
+            return Err(invalid("too many modifiers"));
+        }
+        let mut mods = Vec::with_capacity(num_mods);
+        for _ in 0..num_mods {
+            mods.push(Mod {
+                name: r.atom(&mut context)?,
+                mod_type: known(ModType::from_bits(r.u8()?), "modifier type")?,
+                mapping: r.u32()?,
+            });
+        }
+        let mods = ModSet { mods };
+
+        let num_types = r.len()?;
+        let mut types = Vec::with_capacity(num_types.min(bytes.len()));
+        for _ in 0..num_types {
+            let name = r.atom(&mut context)?;
+            let type_mods = r.mods()?;
+            let num_levels = r.len()?;
+
+            let mut level_names = BTreeMap::new();
+            for _ in 0..r.len()? {
+                level_names.insert(r.len()?, r.atom(&mut context)?);
+            }
+
+            let num_entries = r.len()?;
+            let mut entries = Vec::with_capacity(num_entries.min(bytes.len()));
+            for _ in 0..num_entries {
+                entries.push(KeyTypeEntry {
+                    level: r.len()?,
+                    mods: r.mods()?,
+                    preserve: r.mods()?,
+                });
+            }
+
+            types.push(KeyType {
+                name,
+                mods: type_mods,
+                num_levels,
+                level_names,
+                entries,
+            });
+        }
+
+        let num_interprets = r.len()?;
+        let mut sym_interprets = Vec::with_capacity(num_interprets.min(bytes.len()));
+        for _ in 0..num_interprets {
+            sym_interprets.push(SymInterpret {
+                sym: r.keysym()?,
+                match_op: match r.u8()? {
+                    0 => MatchOperation::None,
+                    1 => MatchOperation::AnyOrNone,
+                    2 => MatchOperation::Any,
+                    3 => MatchOperation::All,
+                    4 => MatchOperation::Exactly,
+                    _ => return Err(invalid("unknown match operation")),
+                },
+                mods: r.mods()?,
+                virtual_mod: r.option(|r| r.len())?,
+                action: r.action()?,
+                level_one_only: r.bool()?,
+                repeat: r.bool()?,
+            });
+        }
+
+        let num_groups = r.len()?;
+        let num_group_names = r.len()?;
+        let mut group_names = Vec::with_capacity(num_group_names.min(bytes.len()));
+        for _ in 0..num_group_names {
+            group_names.push(r.atom(&mut context)?);
+        }
+
+        let mut leds = [None; XKB_MAX_LEDS];
+        for _ in 0..r.len()? {
+            let idx = r.len()?;
+            let led = Led {
+                name: r.option(|r| r.atom(&mut context))?,
+                which_groups: known(StateComponent::from_bits(r.u16()?), "state components")?,
+                groups: r.u32()?,
+                which_mods: known(StateComponent::from_bits(r.u16()?), "state components")?,
+                mods: r.mods()?,
+                ctrls: known(ActionControls::from_bits(r.u16()?), "controls")?,
+            };
+            *leds
+                .get_mut(idx)
+                .ok_or_else(|| invalid("LED index out of range"))? = Some(led);
+        }
+
+        let num_aliases = r.len()?;
+        let mut key_aliases = Vec::with_capacity(num_aliases.min(bytes.len()));
+        for _ in 0..num_aliases {
+            key_aliases.push(KeyAlias {
+                alias: r.atom(&mut context)?,
+                real: r.atom(&mut context)?,
+            });
+        }
+
+        let mut keys = BTreeMap::new();
+        for _ in 0..r.len()? {
+            let keycode = Keycode(r.u32()?);
+            let name = r.atom(&mut context)?;
+            let explicit = known(
+                ExplicitComponents::from_bits(r.u8()?),
+                "explicit components",
+            )?;
+            let modmap = r.u32()?;
+            let vmodmap = r.u32()?;
+            let repeats = r.bool()?;
+            let out_of_range_group_action = match r.u8()? {
+                0 => RangeExceedType::Wrap,
+                1 => RangeExceedType::Saturate,
+                2 => RangeExceedType::Redirect,
+                _ => return Err(invalid("unknown out of range group action")),
+            };
+            let out_of_range_group_number = r.len()?;
+
+            let num_key_groups = r.len()?;
+            let mut groups = Vec::with_capacity(num_key_groups.min(bytes.len()));
+            for _ in 0..num_key_groups {
+                let explicit_type = r.bool()?;
+                let key_type = r.len()?;
+
+                let num_levels = r.len()?;
+                let mut levels = Vec::with_capacity(num_levels.min(bytes.len()));
+                for _ in 0..num_levels {
+                    let action = r.action()?;
+                    let num_syms = r.len()?;
+                    let mut syms = Vec::with_capacity(num_syms.min(bytes.len()));
+                    for _ in 0..num_syms {
+                        syms.push(r.keysym()?);
+                    }
+                    levels.push(Level { action, syms });
+                }
+
+                groups.push(Group {
+                    explicit_type,
+                    key_type,
+                    levels,
+                });
+            }
+
+            let key = Key {
+                keycode,
+                name,
+                explicit,
+                modmap,
+                vmodmap,
+                repeats,
+                out_of_range_group_action,
+                out_of_range_group_number,
+                groups,
+            };
+            if keys.insert(keycode.0, key).is_some() {
+                return Err(invalid("duplicate keycode"));
+            }
+        }
+
+        let mut section_names = [None, None, None, None];
+        for name in section_names.iter_mut() {
+            *name = r.option(|r| r.str().map(String::from))?;
+        }
+        let [keycodes_section_name, types_section_name, compat_section_name, symbols_section_name] =
+            section_names;
+
+        if r.pos != bytes.len() {
+            return Err(invalid("trailing data"));
+        }
+
+        let mut keymap = Keymap {
+            context,
+            flags,
+            format,
+            enabled_ctrls,
+            min_key_code,
+            max_key_code,
+            keys,
+            key_aliases,
+            types,
+            sym_interprets,
+            mods,
+            num_groups,
+            group_names,
+            leds,
+            keycodes_section_name,
+            symbols_section_name,
+            types_section_name,
+            compat_section_name,
+            fingerprint: [0; 32],
+        };
+
+        keymap.validate()?;
+        keymap.fingerprint = keymap.compute_fingerprint();
+
+        Ok(keymap)
+    }
+
+    // Check the invariants the compiler guarantees,
+    // and which the rest of the crate relies on.
+    fn validate(&self) -> Result<(), KeymapLoadError> {
+        let num_mods = self.mods.mods.len();
+        let all_mods: ModMask = match num_mods {
+            XKB_MAX_MODS => ModMask::MAX,
+            n => (1 << n) - 1,
+        };
+        let check_mask = |mask: ModMask| match mask & !all_mods {
+            0 => Ok(()),
+            _ => Err(invalid("modifier mask out of range")),
+        };
+
+        if self
+            .mods
+            .mods
+            .iter()
+            .take(8)
+            .any(|_mod| _mod.mod_type != ModType::REAL)
+        {
+            return Err(invalid("missing real modifiers"));
+        }
+        for _mod in self.mods.mods.iter() {
+            check_mask(_mod.mapping)?;
+        }
+
+        for _type in self.types.iter() {
+            check_mask(_type.mods.mods)?;
+            if _type.num_levels == 0 {
+                return Err(invalid("key type without levels"));
+            }
+            for entry in _type.entries.iter() {
+                check_mask(entry.mods.mods)?;
+                check_mask(entry.preserve.mods)?;
+                if entry.level >= _type.num_levels {
+                    return Err(invalid("key type entry level out of range"));
+                }
+            }
+            if _type
+                .level_names
+                .keys()
+                .any(|level| *level >= _type.num_levels)
+            {
+                return Err(invalid("level name out of range"));
+            }
+        }
+
+        for si in self.sym_interprets.iter() {
+            check_mask(si.mods.mods)?;
+            if si.virtual_mod.is_some_and(|vmod| vmod >= num_mods) {
+                return Err(invalid("modifier index out of range"));
+            }
+        }
+
+        for led in self.leds.iter().flatten() {
+            check_mask(led.mods.mods)?;
+        }
+
+        let mut num_groups = 0;
+        for (kc, key) in self.keys.iter() {
+            if *kc < self.min_key_code || *kc > self.max_key_code {
+                return Err(invalid("keycode out of range"));
+            }
+            check_mask(key.modmap)?;
+            check_mask(key.vmodmap)?;
+            if key.groups.len() > XKB_MAX_GROUPS.into() {
+                return Err(invalid("too many groups"));
+            }
+            if !key.groups.is_empty() && key.out_of_range_group_number >= key.groups.len() {
+                return Err(invalid("out of range group number out of range"));
+            }
+            for group in key.groups.iter() {
+                let _type = self
+                    .types
+                    .get(group.key_type)
+                    .ok_or_else(|| invalid("key type index out of range"))?;
+                if group.levels.len() != _type.num_levels {
+                    return Err(invalid("wrong number of levels"));
+                }
+            }
+            num_groups = usize::max(num_groups, key.groups.len());
+        }
+        if num_groups != self.num_groups {
+            return Err(invalid("wrong number of groups"));
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid(reason: &str) -> KeymapLoadError {
+    KeymapLoadError::Invalid(reason.into())
+}
+
+// Flags with unknown bits are rejected
+fn known<T>(flags: Option<T>, what: &str) -> Result<T, KeymapLoadError> {
+    flags.ok_or_else(|| KeymapLoadError::Invalid(format!("unknown {}", what)))
+}
+
+#[derive(Default)]
+pub(super) struct Writer {
+    pub(super) buf: Vec<u8>,
+}
+
+impl Writer {
+    fn u8(&mut self, v: u8) {
+        self.buf.push(v);
+    }
+
+    fn u16(&mut self, v: u16) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    pub(super) fn u32(&mut self, v: u32) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    pub(super) fn u64(&mut self, v: u64) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn bool(&mut self, v: bool) {
+        self.u8(v.into());
+    }
+
+    // Lengths and indices are stored as 32 bits
+    pub(super) fn len(&mut self, v: usize) {
+        self.u32(v as u32);
+    }
+
+    pub(super) fn str(&mut self, s: &str) {
+        self.len(s.len());
+        self.buf.extend_from_slice(s.as_bytes());
+    }
+
+    fn atom(&mut self, ctx: &Context, atom: Atom) {
+        self.str(ctx.xkb_atom_text(atom));
+    }
+
+    fn option<T>(&mut self, v: Option<T>, write: impl FnOnce(&mut Self, T)) {
+        match v {
+            Some(v) => {
+                self.bool(true);
+                write(self, v);
+            }
+            None => self.bool(false),
+        }
+    }
+
+    fn mods(&mut self, mods: &Mods) {
+        self.u32(mods.mods);
+        self.u32(mods.mask);
+    }
+
+    fn keysym(&mut self, sym: Option<Keysym>) {
+        self.option(sym, |w, sym| w.u32(sym.raw()));
+    }
+
+    fn action(&mut self, action: &Action) {
+        let header = |w: &mut Self, tag: u8, action_type: ActionType, flags: &ActionFlags| {
+            w.u8(tag);
+            w.u8(action_type.into());
+            w.u16(flags.bits());
+        };
+
+        match action {
+            Action::None => self.u8(0),
+            Action::Mods(a) => {
+                header(self, 1, a.action_type, &a.flags);
+                self.mods(&a.mods);
+            }
+            Action::Group(a) => {
+                header(self, 2, a.action_type, &a.flags);
+                self.option(a.group, |w, group| w.u32(group as u32));
+            }
+            Action::Ctrls(a) => {
+                header(self, 3, a.action_type, &a.flags);
+                self.u16(a.ctrls.bits());
+            }
+            Action::Dflt(a) => {
+                header(self, 4, a.action_type, &a.flags);
+                self.option(a.value, |w, value| w.u8(value as u8));
+            }
+            Action::Screen(a) => {
+                header(self, 5, a.action_type, &a.flags);
+                self.option(a.screen, |w, screen| w.u8(screen as u8));
+            }
+            Action::Ptr(a) => {
+                header(self, 6, a.action_type, &a.flags);
+                self.option(a.x, |w, x| w.u16(x as u16));
+                self.option(a.y, |w, y| w.u16(y as u16));
+            }
+            Action::Btn(a) => {
+                header(self, 7, a.action_type, &a.flags);
+                self.u8(a.count);
+                self.option(a.button, |w, button| w.u8(button));
+            }
+            Action::Private(a) => {
+                header(self, 8, a.action_type, &ActionFlags::empty());
+                for byte in a.data.iter() {
+                    self.option(*byte, |w, byte| w.u8(byte));
+                }
+            }
+            Action::Terminate => self.u8(9),
+        }
+    }
+}
+
+pub(super) struct Reader<'b> {
+    bytes: &'b [u8],
+    pos: usize,
+}
+
+impl<'b> Reader<'b> {
+    pub(super) fn new(bytes: &'b [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    pub(super) fn rest(self) -> &'b [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    pub(super) fn take(&mut self, n: usize) -> Result<&'b [u8], KeymapLoadError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(KeymapLoadError::UnexpectedEnd)?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], KeymapLoadError> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, KeymapLoadError> {
+        Ok(u8::from_le_bytes(self.array()?))
+    }
+
+    fn u16(&mut self) -> Result<u16, KeymapLoadError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    pub(super) fn u32(&mut self) -> Result<u32, KeymapLoadError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    pub(super) fn u64(&mut self) -> Result<u64, KeymapLoadError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn bool(&mut self) -> Result<bool, KeymapLoadError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid("invalid boolean")),
+        }
+    }
+
+    pub(super) fn len(&mut self) -> Result<usize, KeymapLoadError> {
+        Ok(self.u32()? as usize)
+    }
+
+    pub(super) fn str(&mut self) -> Result<&'b str, KeymapLoadError> {
+        let len = self.len()?;
+        std::str::from_utf8(self.take(len)?).map_err(|_| invalid("invalid UTF-8"))
+    }
+
+    fn atom(&mut self, ctx: &mut Context) -> Result<Atom, KeymapLoadError> {
+        Ok(ctx.atom_intern(self.str()?))
+    }
+
+    fn option<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, KeymapLoadError>,
+    ) -> Result<Option<T>, KeymapLoadError> {
+        match self.bool()? {
+            true => Ok(Some(read(self)?)),
+            false => Ok(None),
+        }
+    }
+
+    fn mods(&mut self) -> Result<Mods, KeymapLoadError> {
+        Ok(Mods {
+            mods: self.u32()?,
+            mask: self.u32()?,
+        })
+    }
+
+    fn keysym(&mut self) -> Result<Option<Keysym>, KeymapLoadError> {
+        self.option(|r| match r.u32()? {
+            raw if raw <= XKB_KEYSYM_MAX => Ok(Keysym::new(raw)),
+            _ => Err(invalid("keysym out of range")),
+        })
+    }
+
+    fn action(&mut self) -> Result<Action, KeymapLoadError> {
+        let tag = self.u8()?;
+        match tag {
+            0 => return Ok(Action::None),
+            9 => return Ok(Action::Terminate),
+            _ => {}
+        }
+
+        let action_type = ActionType::from(self.u8()?);
+        let flags = known(ActionFlags::from_bits(self.u16()?), "action flags")?;
+
+        use ActionType::*;
+        let action = match (tag, action_type) {
+            (1, ModSet | ModLatch | ModLock) => Action::Mods(ModAction {
+                action_type,
+                flags,
+                mods: self.mods()?,
+            }),
+            (2, GroupSet | GroupLatch | GroupLock) => Action::Group(GroupAction {
+                action_type,
+                flags,
+                group: self.option(|r| r.u32().map(|group| group as i32))?,
+            }),
+            (3, CtrlSet | CtrlLock) => Action::Ctrls(ControlsAction {
+                action_type,
+                flags,
+                ctrls: known(ActionControls::from_bits(self.u16()?), "controls")?,
+            }),
+            (4, PtrDefault) => Action::Dflt(DefaultAction {
+                action_type,
+                flags,
+                value: self.option(|r| r.u8().map(|value| value as i8))?,
+            }),
+            (5, SwitchVT) => Action::Screen(SwitchScreenAction {
+                action_type,
+                flags,
+                screen: self.option(|r| r.u8().map(|screen| screen as i8))?,
+            }),
+            (6, PtrMove) => Action::Ptr(PointerAction {
+                action_type,
+                flags,
+                x: self.option(|r| r.u16().map(|x| x as i16))?,
+                y: self.option(|r| r.u16().map(|y| y as i16))?,
+            }),
+            (7, PtrButton | PtrLock) => Action::Btn(PointerButtonAction {
+                action_type,
+                flags,
+                count: self.u8()?,
+                button: self.option(|r| r.u8())?,
+            }),
+            (8, Private | PrivateDefinedAction(_)) => {
+                let mut data: ActionData = [Option::None; ACTION_DATA_LEN];
+                for byte in data.iter_mut() {
+                    *byte = self.option(|r| r.u8())?;
+                }
+                Action::Private(PrivateAction { action_type, data })
+            }
+            _ => return Err(invalid("invalid action")),
+        };
+
+        Ok(action)
+    }
+}
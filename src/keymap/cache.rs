@@ -0,0 +1,243 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::bytes::{Reader, Writer, FORMAT_VERSION};
+use super::*;
+
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+// Identifies a cache entry
+const MAGIC: &[u8; 4] = b"xkbc";
+
+/// An on-disk cache of keymaps compiled from RMLVO names.
+///
+/// Entries are keyed by the RMLVO names, the include paths of the context and the compile
+/// flags. Each entry records the files which were read to compile the keymap, and is only used
+/// if none of them has changed since: a file whose modification time differs is hashed and
+/// compared with the hash it had when it was read. The entry also records the paths searched
+/// before each of these files, and is not used if a file now exists at any of them, since it
+/// would take precedence, e.g. a new file in a user include path.
+///
+/// The cache is only an optimization, so errors when reading or writing entries are logged and
+/// otherwise ignored.
+#[derive(Clone, Debug)]
+pub struct KeymapCache {
+    dir: PathBuf,
+}
+
+impl KeymapCache {
+    /// Create a cache which stores its entries in the given directory.
+    ///
+    /// The directory is created when the first entry is written.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Get a keymap from the cache, or compile it and add it to the cache.
+    ///
+    /// This is the same as [Keymap::new_from_names()].
+    pub fn new_from_names<F>(
+        &self,
+        mut context: Context,
+        rmlvo: Option<RuleNames>,
+        compile_flags: F,
+    ) -> Result<Keymap, KeymapCompileError>
+    where
+        F: TryInto<CompileFlags> + Clone,
+    {
+        let flags: CompileFlags = compile_flags
+            .try_into()
+            .map_err(|_| KeymapCompileError::UnrecognizedCompileFlags)?;
+
+        let mut rmlvo = rmlvo.unwrap_or(RuleNames::empty());
+        context.sanitize_rule_names(&mut rmlvo);
+
+        let path = self.entry_path(&context, &rmlvo, &flags);
+
+        if let Ok(entry) = std::fs::read(&path) {
+            match load_entry(&entry) {
+                Some(bytes) => match Keymap::from_bytes(context.clone(), bytes) {
+                    Ok(keymap) => return Ok(keymap),
+                    Err(e) => log::warn!("Ignoring invalid cached keymap {:?}: {}", path, e),
+                },
+                None => log::debug!("Cached keymap {:?} is out of date", path),
+            }
+        }
+
+        context.track_files = true;
+        context.consumed_files.clear();
+        context.missing_files.clear();
+        let keymap = Keymap::new_from_names(context, Some(rmlvo), flags)?;
+
+        if let Err(e) = self.store_entry(&path, &keymap) {
+            log::warn!("Could not write cached keymap {:?}: {}", path, e);
+        }
+
+        Ok(keymap)
+    }
+
+    fn entry_path(&self, context: &Context, rmlvo: &RuleNames, flags: &CompileFlags) -> PathBuf {
+        let mut w = Writer::default();
+        w.u32(FORMAT_VERSION);
+        for name in [
+            &rmlvo.rules,
+            &rmlvo.model,
+            &rmlvo.layout,
+            &rmlvo.variant,
+            &rmlvo.options,
+        ] {
+            w.str(name.as_deref().unwrap_or(""));
+        }
+        // In order, since the first include path containing a file takes precedence
+        w.len(context.includes.len());
+        for include in context.includes.iter() {
+            w.str(include);
+        }
+        w.u32(flags.clone().into());
+
+        let name: String = Sha256::digest(&w.buf)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        self.dir.join(name)
+    }
+
+    fn store_entry(&self, path: &Path, keymap: &Keymap) -> std::io::Result<()> {
+        let mut w = Writer::default();
+        w.buf.extend_from_slice(MAGIC);
+
+        // The files are recorded as they were read, so that
+        // a file edited while compiling invalidates the entry
+        let mut files = keymap.context.consumed_files.clone();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files.dedup_by(|a, b| a.path == b.path);
+
+        w.len(files.len());
+        for file in files.iter() {
+            w.str(&file.path.to_string_lossy());
+            w.u64(file.mtime.0);
+            w.u32(file.mtime.1);
+            w.u64(file.size);
+            w.buf.extend(file.hash);
+        }
+
+        let mut missing = keymap.context.missing_files.clone();
+        missing.sort();
+        missing.dedup();
+
+        w.len(missing.len());
+        for path in missing.iter() {
+            w.str(&path.to_string_lossy());
+        }
+
+        w.buf.extend(keymap.to_bytes());
+
+        // Write the entry atomically, so that concurrent
+        // readers never see a partially written entry
+        std::fs::create_dir_all(&self.dir)?;
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        std::fs::write(&tmp, &w.buf)?;
+        std::fs::rename(&tmp, path)
+    }
+}
+
+// Returns the serialized keymap of a cache entry,
+// or `None` if any of the files it depends on has changed.
+fn load_entry(entry: &[u8]) -> Option<&[u8]> {
+    let mut r = Reader::new(entry);
+
+    if r.take(MAGIC.len()).ok()? != MAGIC {
+        return None;
+    }
+
+    for _ in 0..r.len().ok()? {
+        let file = Path::new(r.str().ok()?);
+        let secs = r.u64().ok()?;
+        let nanos = r.u32().ok()?;
+        let size = r.u64().ok()?;
+        let hash = r.take(32).ok()?;
+
+        let metadata = std::fs::metadata(file).ok()?;
+        if metadata.len() != size {
+            return None;
+        }
+        if mtime(&metadata) != (secs, nanos)
+            && Sha256::digest(std::fs::read(file).ok()?).as_slice() != hash
+        {
+            return None;
+        }
+    }
+
+    for _ in 0..r.len().ok()? {
+        let path = Path::new(r.str().ok()?);
+
+        if std::fs::metadata(path).is_ok_and(|metadata| metadata.is_file()) {
+            return None;
+        }
+    }
+
+    Some(r.rest())
+}
+
+/// A file read to compile a keymap, as it was when it was read.
+#[derive(Clone, Debug)]
+pub(crate) struct ConsumedFile {
+    pub(crate) path: PathBuf,
+    mtime: (u64, u32),
+    size: u64,
+    hash: [u8; 32],
+}
+
+impl ConsumedFile {
+    /// Record a file which has just been opened, and rewind it to be read by the compiler.
+    pub(crate) fn read(path: PathBuf, file: &mut std::fs::File) -> std::io::Result<Self> {
+        use std::io::{Read, Seek};
+
+        // Before reading, so that an edit while reading changes the modification time
+        let metadata = file.metadata()?;
+
+        let mut content = vec![];
+        file.read_to_end(&mut content)?;
+        file.rewind()?;
+
+        Ok(Self {
+            path,
+            mtime: mtime(&metadata),
+            size: content.len() as u64,
+            hash: Sha256::digest(&content).into(),
+        })
+    }
+}
+
+fn mtime(metadata: &std::fs::Metadata) -> (u64, u32) {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| (duration.as_secs(), duration.subsec_nanos()))
+        .unwrap_or_default()
+}
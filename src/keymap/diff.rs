@@ -0,0 +1,457 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+use crate::keysyms::keysym_get_name;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// A single difference between two keymaps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change<T> {
+    /// The item only exists in the new keymap.
+    Added(T),
+    /// The item only exists in the old keymap.
+    Removed(T),
+    /// The item exists in both keymaps, with different values.
+    Changed { old: T, new: T },
+}
+
+impl<T: PartialEq> Change<T> {
+    fn between(old: Option<T>, new: Option<T>) -> Option<Self> {
+        match (old, new) {
+            (None, None) => None,
+            (None, Some(new)) => Some(Change::Added(new)),
+            (Some(old), None) => Some(Change::Removed(old)),
+            (Some(old), Some(new)) if old == new => None,
+            (Some(old), Some(new)) => Some(Change::Changed { old, new }),
+        }
+    }
+
+    fn render(&self, text: impl Fn(&T) -> String) -> String {
+        match self {
+            Change::Added(new) => format!("added {}", text(new)),
+            Change::Removed(old) => format!("removed {}", text(old)),
+            Change::Changed { old, new } => format!("{} -> {}", text(old), text(new)),
+        }
+    }
+}
+
+/// A difference in a named item of the keymap, such as a modifier, key type or LED.
+///
+/// The values are given as they would be written in a keymap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemDiff {
+    /// The name of the item.
+    pub name: String,
+    /// The change of the item.
+    pub change: Change<String>,
+}
+
+/// A difference in the name of a layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayoutDiff {
+    /// The index of the layout.
+    pub layout: LayoutIndex,
+    /// The change of the name of the layout.
+    pub change: Change<String>,
+}
+
+/// A difference in a key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyChange {
+    /// The key only exists in the new keymap.
+    Added,
+    /// The key only exists in the old keymap.
+    Removed,
+    /// The key type of a layout of the key.
+    Type {
+        layout: LayoutIndex,
+        change: Change<String>,
+    },
+    /// The keysyms of a level of the key.
+    Keysyms {
+        layout: LayoutIndex,
+        level: LevelIndex,
+        change: Change<Vec<Keysym>>,
+    },
+    /// The action of a level of the key.
+    Action {
+        layout: LayoutIndex,
+        level: LevelIndex,
+        change: Change<String>,
+    },
+    /// Whether the key repeats.
+    Repeats { old: bool, new: bool },
+    /// The real modifiers bound to the key.
+    Modmap(Change<String>),
+    /// The virtual modifiers bound to the key.
+    Vmodmap(Change<String>),
+}
+
+/// The differences in a key, identified by its name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyDiff {
+    /// The name of the key.
+    pub name: String,
+    /// The keycode of the key in the new keymap, or in the old keymap if it was removed.
+    pub keycode: Keycode,
+    /// The changes of the key.
+    pub changes: Vec<KeyChange>,
+}
+
+/// The differences between two keymaps, as returned by [Keymap::diff()].
+///
+/// Keys, modifiers, key types and LEDs are matched by name, since their indices are not
+/// meaningful across keymaps. The [Display](fmt::Display) implementation renders the
+/// differences with one line per change.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeymapDiff {
+    pub layouts: Vec<LayoutDiff>,
+    pub mods: Vec<ItemDiff>,
+    pub types: Vec<ItemDiff>,
+    pub leds: Vec<ItemDiff>,
+    pub keys: Vec<KeyDiff>,
+}
+
+impl KeymapDiff {
+    /// Returns `true` if the keymaps are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.layouts.is_empty()
+            && self.mods.is_empty()
+            && self.types.is_empty()
+            && self.leds.is_empty()
+            && self.keys.is_empty()
+    }
+}
+
+impl Keymap {
+    /// Compare this keymap to another one.
+    ///
+    /// `self` is the old keymap and `other` is the new one.
+    pub fn diff(&self, other: &Keymap) -> KeymapDiff {
+        KeymapDiff {
+            layouts: self.diff_layouts(other),
+            mods: diff_items(self.mod_texts(), other.mod_texts()),
+            types: diff_items(self.type_texts(), other.type_texts()),
+            leds: diff_items(self.led_texts(), other.led_texts()),
+            keys: self.diff_keys(other),
+        }
+    }
+
+    fn diff_layouts(&self, other: &Keymap) -> Vec<LayoutDiff> {
+        let layout_name = |keymap: &Keymap, layout| {
+            (layout < keymap.num_layouts()).then(|| {
+                keymap
+                    .layout_get_name(layout)
+                    .unwrap_or_default()
+                    .to_owned()
+            })
+        };
+
+        (0..self.num_layouts().max(other.num_layouts()))
+            .filter_map(|layout| {
+                Change::between(layout_name(self, layout), layout_name(other, layout))
+                    .map(|change| LayoutDiff { layout, change })
+            })
+            .collect()
+    }
+
+    fn mod_texts(&self) -> BTreeMap<String, String> {
+        self.mods
+            .mods
+            .iter()
+            .map(|_mod| {
+                let value = match _mod.mod_type {
+                    ModType::REAL => "real".into(),
+                    _ => format!(
+                        "virtual = {}",
+                        self.context.mod_mask_text(&self.mods, _mod.mapping)
+                    ),
+                };
+
+                (self.context.xkb_atom_text(_mod.name).to_owned(), value)
+            })
+            .collect()
+    }
+
+    fn type_texts(&self) -> BTreeMap<String, String> {
+        self.types
+            .iter()
+            .map(|_type| {
+                (
+                    self.context.xkb_atom_text(_type.name).to_owned(),
+                    single_line(&self.key_type_text(_type)),
+                )
+            })
+            .collect()
+    }
+
+    fn led_texts(&self) -> BTreeMap<String, String> {
+        self.leds
+            .iter()
+            .flatten()
+            .filter_map(|led| {
+                let name = self.context.atom_text(led.name?)?;
+
+                Some((name.to_owned(), single_line(&self.led_text(led))))
+            })
+            .collect()
+    }
+
+    fn diff_keys(&self, other: &Keymap) -> Vec<KeyDiff> {
+        let old_keys: BTreeMap<&str, &Key> = self
+            .keys
+            .values()
+            .map(|key| (self.context.xkb_atom_text(key.name), key))
+            .collect();
+        let new_keys: BTreeMap<&str, &Key> = other
+            .keys
+            .values()
+            .map(|key| (other.context.xkb_atom_text(key.name), key))
+            .collect();
+
+        // Report the keys in keycode order, removed keys first
+        let mut names: Vec<(&str, Keycode)> = vec![];
+        let mut seen = BTreeSet::new();
+        for (name, key) in new_keys
+            .iter()
+            .chain(old_keys.iter())
+            .map(|(name, key)| (*name, key.keycode))
+        {
+            if seen.insert(name) {
+                names.push((name, key));
+            }
+        }
+        names.sort_by_key(|(name, keycode)| (new_keys.contains_key(name), *keycode));
+
+        names
+            .into_iter()
+            .filter_map(|(name, keycode)| {
+                let changes = match (old_keys.get(name), new_keys.get(name)) {
+                    (None, _) => vec![KeyChange::Added],
+                    (_, None) => vec![KeyChange::Removed],
+                    (Some(old), Some(new)) => self.diff_key(old, other, new),
+                };
+
+                (!changes.is_empty()).then(|| KeyDiff {
+                    name: name.to_owned(),
+                    keycode,
+                    changes,
+                })
+            })
+            .collect()
+    }
+
+    fn diff_key(&self, old: &Key, other: &Keymap, new: &Key) -> Vec<KeyChange> {
+        let mut changes = vec![];
+
+        for layout in 0..old.groups.len().max(new.groups.len()) {
+            let old_group = old.groups.get(layout);
+            let new_group = new.groups.get(layout);
+
+            let type_name = |keymap: &Keymap, group: &Group| {
+                keymap
+                    .types
+                    .get(group.key_type)
+                    .map(|_type| keymap.context.xkb_atom_text(_type.name).to_owned())
+            };
+            if let Some(change) = Change::between(
+                old_group.and_then(|group| type_name(self, group)),
+                new_group.and_then(|group| type_name(other, group)),
+            ) {
+                changes.push(KeyChange::Type { layout, change });
+            }
+
+            let num_levels = |group: Option<&Group>| group.map_or(0, |group| group.levels.len());
+            for level in 0..num_levels(old_group).max(num_levels(new_group)) {
+                let old_level = old_group.and_then(|group| group.levels.get(level));
+                let new_level = new_group.and_then(|group| group.levels.get(level));
+
+                let syms = |level: Option<&Level>| {
+                    let syms: Vec<Keysym> = level?.syms.iter().flatten().copied().collect();
+                    (!syms.is_empty()).then_some(syms)
+                };
+                if let Some(change) = Change::between(syms(old_level), syms(new_level)) {
+                    changes.push(KeyChange::Keysyms {
+                        layout,
+                        level,
+                        change,
+                    });
+                }
+
+                let action = |keymap: &Keymap, level: Option<&Level>| match &level?.action {
+                    Action::None => None,
+                    action => Some(keymap.action_text(action)),
+                };
+                if let Some(change) =
+                    Change::between(action(self, old_level), action(other, new_level))
+                {
+                    changes.push(KeyChange::Action {
+                        layout,
+                        level,
+                        change,
+                    });
+                }
+            }
+        }
+
+        if old.repeats != new.repeats {
+            changes.push(KeyChange::Repeats {
+                old: old.repeats,
+                new: new.repeats,
+            });
+        }
+
+        let mods = |keymap: &Keymap, mask: ModMask| {
+            (mask != 0).then(|| keymap.context.mod_mask_text(&keymap.mods, mask))
+        };
+        if let Some(change) = Change::between(mods(self, old.modmap), mods(other, new.modmap)) {
+            changes.push(KeyChange::Modmap(change));
+        }
+        if let Some(change) = Change::between(mods(self, old.vmodmap), mods(other, new.vmodmap)) {
+            changes.push(KeyChange::Vmodmap(change));
+        }
+
+        changes
+    }
+}
+
+fn diff_items(old: BTreeMap<String, String>, mut new: BTreeMap<String, String>) -> Vec<ItemDiff> {
+    let mut diffs = vec![];
+
+    for (name, old_value) in old {
+        if let Some(change) = Change::between(Some(old_value), new.remove(&name)) {
+            diffs.push(ItemDiff { name, change });
+        }
+    }
+    diffs.extend(new.into_iter().map(|(name, value)| ItemDiff {
+        name,
+        change: Change::Added(value),
+    }));
+
+    diffs
+}
+
+/// Collapses a definition written over several lines.
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn keysyms_text(syms: &[Keysym]) -> String {
+    let names: Vec<String> = syms
+        .iter()
+        .map(|sym| keysym_get_name(sym).unwrap_or_else(|| "Invalid".into()))
+        .collect();
+
+    match names.as_slice() {
+        [name] => name.clone(),
+        names => format!("{{ {} }}", names.join(", ")),
+    }
+}
+
+impl fmt::Display for KeymapDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quoted = |text: &String| format!("\"{}\"", text);
+
+        for diff in self.layouts.iter() {
+            writeln!(
+                f,
+                "layout {}: {}",
+                diff.layout + 1,
+                diff.change.render(quoted)
+            )?;
+        }
+        for diff in self.mods.iter() {
+            writeln!(
+                f,
+                "modifier {}: {}",
+                diff.name,
+                diff.change.render(String::clone)
+            )?;
+        }
+        for diff in self.types.iter() {
+            writeln!(
+                f,
+                "type \"{}\": {}",
+                diff.name,
+                diff.change.render(String::clone)
+            )?;
+        }
+        for diff in self.leds.iter() {
+            writeln!(
+                f,
+                "indicator \"{}\": {}",
+                diff.name,
+                diff.change.render(String::clone)
+            )?;
+        }
+        for diff in self.keys.iter() {
+            for change in diff.changes.iter() {
+                write!(f, "key <{}>: ", diff.name)?;
+
+                // Layouts and levels are written 1-indexed, as in keymap files
+                match change {
+                    KeyChange::Added => writeln!(f, "added")?,
+                    KeyChange::Removed => writeln!(f, "removed")?,
+                    KeyChange::Type { layout, change } => {
+                        writeln!(f, "type of group {}: {}", layout + 1, change.render(quoted))?
+                    }
+                    KeyChange::Keysyms {
+                        layout,
+                        level,
+                        change,
+                    } => writeln!(
+                        f,
+                        "keysyms of group {}, level {}: {}",
+                        layout + 1,
+                        level + 1,
+                        change.render(|syms| keysyms_text(syms))
+                    )?,
+                    KeyChange::Action {
+                        layout,
+                        level,
+                        change,
+                    } => writeln!(
+                        f,
+                        "action of group {}, level {}: {}",
+                        layout + 1,
+                        level + 1,
+                        change.render(String::clone)
+                    )?,
+                    KeyChange::Repeats { old, new } => writeln!(f, "repeats: {} -> {}", old, new)?,
+                    KeyChange::Modmap(change) => {
+                        writeln!(f, "modifier map: {}", change.render(String::clone))?
+                    }
+                    KeyChange::Vmodmap(change) => {
+                        writeln!(f, "virtual modifier map: {}", change.render(String::clone))?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+use std::hash::{Hash, Hasher};
+
+impl Keymap {
+    /// Get a stable hash of the contents of the keymap.
+    ///
+    /// The fingerprint is the SHA-256 digest of a canonical serialization of the keycodes, key
+    /// types, compatibility map, modifiers, LEDs and keys of the keymap. It does not depend on
+    /// the context, on the atom numbering, on the section names, or on the order of the key
+    /// aliases and key types, so it can be used to identify identical keymaps across processes.
+    ///
+    /// The fingerprint is computed once, when the keymap is created.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.fingerprint
+    }
+
+    pub(crate) fn compute_fingerprint(&self) -> [u8; 32] {
+        let bytes = match self.canonical_text() {
+            Ok(text) => text.into_bytes(),
+            Err(e) => {
+                // Still identifies this keymap, but not the keymaps with the same meaning
+                log::warn!("Could not serialize keymap for its fingerprint: {}", e);
+                self.to_bytes()
+            }
+        };
+
+        Sha256::digest(bytes).into()
+    }
+}
+
+/// Keymaps are equal if they have the same meaning, as in [Keymap::fingerprint()].
+impl PartialEq for Keymap {
+    fn eq(&self, other: &Self) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
+}
+
+impl Eq for Keymap {}
+
+impl Hash for Keymap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fingerprint().hash(state);
+    }
+}
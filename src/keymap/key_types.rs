@@ -0,0 +1,137 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+/// A modifier combination of a key type and the level it selects, as returned by
+/// [Keymap::key_type_get_entries()].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyTypeMapEntry {
+    /// The modifiers which select the level, as real modifiers.
+    pub mods: ModMask,
+    /// The level selected by the modifiers.
+    pub level: LevelIndex,
+    /// The modifiers which are not consumed when the level is selected.
+    pub preserve: ModMask,
+}
+
+impl Keymap {
+    /// Get the name of a shift level of a key, e.g. "Shift" or "AltGr".
+    ///
+    /// Level names are set with `level_name[Level2] = "Shift"` in the key type of the key.
+    /// Returns `None` if the key does not exist or the level has no name.
+    pub fn key_get_level_name(
+        &self,
+        kc: impl Into<RawKeycode>,
+        layout: LayoutIndex,
+        level: LevelIndex,
+    ) -> Option<&str> {
+        self.key_get_type(kc.into(), layout)?
+            .level_names
+            .get(&level)
+            .and_then(|name| self.context.atom_text(*name))
+    }
+
+    /// Get the name of the key type of a key in a layout, e.g. "ALPHABETIC".
+    pub fn key_get_type_name(
+        &self,
+        kc: impl Into<RawKeycode>,
+        layout: LayoutIndex,
+    ) -> Option<&str> {
+        self.context
+            .atom_text(self.key_get_type(kc.into(), layout)?.name)
+    }
+
+    /// Iterate over the names of the key types of the keymap.
+    pub fn key_type_names(&self) -> impl Iterator<Item = &str> {
+        self.types
+            .iter()
+            .filter_map(|_type| self.context.atom_text(_type.name))
+    }
+
+    /// Get the number of levels of a key type.
+    pub fn key_type_num_levels(&self, name: impl AsRef<str>) -> Option<LevelIndex> {
+        self.key_type_by_name(name.as_ref())
+            .map(|_type| _type.num_levels)
+    }
+
+    /// Get the modifiers a key type depends on, as real modifiers.
+    pub fn key_type_get_mods(&self, name: impl AsRef<str>) -> Option<ModMask> {
+        self.key_type_by_name(name.as_ref())
+            .map(|_type| _type.mods.mask)
+    }
+
+    /// Get the name of a level of a key type.
+    pub fn key_type_get_level_name(
+        &self,
+        name: impl AsRef<str>,
+        level: LevelIndex,
+    ) -> Option<&str> {
+        self.key_type_by_name(name.as_ref())?
+            .level_names
+            .get(&level)
+            .and_then(|name| self.context.atom_text(*name))
+    }
+
+    /// Get the modifier combinations of a key type and the levels they select.
+    ///
+    /// Entries which use virtual modifiers not bound to any real modifier are left out, since
+    /// they can never match.
+    pub fn key_type_get_entries(&self, name: impl AsRef<str>) -> Option<Vec<KeyTypeMapEntry>> {
+        let _type = self.key_type_by_name(name.as_ref())?;
+
+        Some(
+            _type
+                .entries
+                .iter()
+                .filter(|entry| entry.is_active())
+                .map(|entry| KeyTypeMapEntry {
+                    mods: entry.mods.mask,
+                    level: entry.level,
+                    preserve: entry.preserve.mask,
+                })
+                .collect(),
+        )
+    }
+
+    fn key_type_by_name(&self, name: &str) -> Option<&KeyType> {
+        let atom = self.context.atom_lookup(name)?;
+        self.types.iter().find(|_type| _type.name == atom)
+    }
+
+    fn key_get_type(&self, kc: RawKeycode, layout: LayoutIndex) -> Option<&KeyType> {
+        let key = self.xkb_key(kc)?;
+
+        let layout: usize = layout.try_into().ok().and_then(|layout: i32| {
+            crate::state::wrap_group_into_range(
+                layout,
+                key.groups.len(),
+                &key.out_of_range_group_action,
+                &key.out_of_range_group_number,
+            )
+        })?;
+
+        self.types.get(key.groups.get(layout)?.key_type)
+    }
+}
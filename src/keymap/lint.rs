@@ -0,0 +1,310 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The kind of a [LintFinding].
+///
+/// The numeric value of a code is stable, and shown when a finding is displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintCode {
+    /// A level of a key has keysyms or an action, but no modifier combination of its key type
+    /// selects it.
+    UnreachableLevel = 1,
+    /// A key type is not used by any key.
+    UnusedKeyType = 2,
+    /// A virtual modifier is used, but is not mapped to any real modifier, so it has no effect.
+    UnboundVirtualModifier = 3,
+    /// A keysym is produced by several keys in the same layout.
+    DuplicateKeysym = 4,
+    /// A key neither sets whether it repeats nor gets it from a symbol interpretation.
+    MissingRepeat = 5,
+    /// An LED is not driven by any modifier, layout or control.
+    UndrivenLed = 6,
+    /// Keys producing the same modifier keysym have different modifier maps.
+    ConflictingModmap = 7,
+}
+
+impl LintCode {
+    /// Get the numeric value of the code.
+    pub fn id(&self) -> u32 {
+        *self as u32
+    }
+}
+
+/// A possible mistake in a keymap, as returned by [Keymap::lint()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintFinding {
+    /// The kind of the finding.
+    pub code: LintCode,
+    /// The name of the key, key type, modifier or LED concerned.
+    pub name: String,
+    /// The keycode of the key concerned, if any.
+    pub keycode: Option<Keycode>,
+    /// The layout concerned, if any.
+    pub layout: Option<LayoutIndex>,
+    /// The level concerned, if any.
+    pub level: Option<LevelIndex>,
+    /// A human-readable description of the finding.
+    pub message: String,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[XKB-L{:03}] {}", self.code.id(), self.message)
+    }
+}
+
+impl Keymap {
+    /// Check the keymap for common layout mistakes.
+    ///
+    /// The keymap is valid either way: the findings point at parts of the keymap which are
+    /// probably not doing what their author intended. They are ordered by key type, modifier,
+    /// LED and key.
+    pub fn lint(&self) -> Vec<LintFinding> {
+        let mut findings = vec![];
+
+        self.lint_types(&mut findings);
+        self.lint_mods(&mut findings);
+        self.lint_leds(&mut findings);
+        self.lint_keys(&mut findings);
+
+        findings
+    }
+
+    fn lint_types(&self, findings: &mut Vec<LintFinding>) {
+        let used: BTreeSet<usize> = self
+            .keys
+            .values()
+            .flat_map(|key| key.groups.iter().map(|group| group.key_type))
+            .collect();
+
+        for (idx, _type) in self.types.iter().enumerate() {
+            if used.contains(&idx) {
+                continue;
+            }
+            let name = self.context.xkb_atom_text(_type.name);
+            findings.push(LintFinding {
+                code: LintCode::UnusedKeyType,
+                name: name.into(),
+                keycode: None,
+                layout: None,
+                level: None,
+                message: format!("type \"{}\" is not used by any key", name),
+            });
+        }
+    }
+
+    fn lint_mods(&self, findings: &mut Vec<LintFinding>) {
+        // Modifier masks which only work through their real modifiers
+        let mut used: ModMask = 0;
+        for key in self.keys.values() {
+            for group in key.groups.iter() {
+                if let Some(_type) = self.types.get(group.key_type) {
+                    used |= _type.mods.mods;
+                }
+            }
+        }
+        for led in self.leds.iter().flatten() {
+            used |= led.mods.mods;
+        }
+        for key in self.keys.values() {
+            for level in key.groups.iter().flat_map(|group| group.levels.iter()) {
+                if let Action::Mods(action) = &level.action {
+                    if !action.flags.intersects(ActionFlags::ModsLookupModMap) {
+                        used |= action.mods.mods;
+                    }
+                }
+            }
+        }
+
+        for (idx, _mod) in self.mods.mods.iter().enumerate() {
+            if _mod.mod_type != ModType::VIRT || _mod.mapping != 0 || used & (1 << idx) == 0 {
+                continue;
+            }
+            let name = self.context.xkb_atom_text(_mod.name);
+            findings.push(LintFinding {
+                code: LintCode::UnboundVirtualModifier,
+                name: name.into(),
+                keycode: None,
+                layout: None,
+                level: None,
+                message: format!(
+                    "virtual modifier {} is used, but is not mapped to any real modifier",
+                    name
+                ),
+            });
+        }
+    }
+
+    fn lint_leds(&self, findings: &mut Vec<LintFinding>) {
+        for led in self.leds.iter().flatten() {
+            // See State::led_update_all()
+            let driven = (!led.which_mods.is_empty() && led.mods.mask != 0)
+                || (!led.which_groups.is_empty() && led.groups != 0)
+                || !led.ctrls.is_empty();
+            if driven {
+                continue;
+            }
+            let name = led
+                .name
+                .map(|name| self.context.xkb_atom_text(name))
+                .unwrap_or_default();
+            findings.push(LintFinding {
+                code: LintCode::UndrivenLed,
+                name: name.into(),
+                keycode: None,
+                layout: None,
+                level: None,
+                message: format!(
+                    "LED \"{}\" is not driven by any modifier, layout or control",
+                    name
+                ),
+            });
+        }
+    }
+
+    fn lint_keys(&self, findings: &mut Vec<LintFinding>) {
+        let ctx = &self.context;
+
+        // The first key producing each keysym in each layout
+        let mut producers: BTreeMap<(LayoutIndex, Keysym), Atom> = BTreeMap::new();
+        // The first key producing each modifier keysym, and its modifier map
+        let mut modifier_keys: BTreeMap<Keysym, (Atom, ModMask)> = BTreeMap::new();
+
+        for (kc, key) in self.keys.iter() {
+            let name = ctx.xkb_atom_text(key.name);
+            let finding = |code, layout, level, message| LintFinding {
+                code,
+                name: name.into(),
+                keycode: Some(Keycode(*kc)),
+                layout,
+                level,
+                message,
+            };
+
+            for (layout, group) in key.groups.iter().enumerate() {
+                let Some(_type) = self.types.get(group.key_type) else {
+                    continue;
+                };
+
+                for (level, level_data) in group.levels.iter().enumerate() {
+                    let reachable = level == 0
+                        || _type
+                            .entries
+                            .iter()
+                            .any(|entry| entry.level == level && entry.is_active());
+                    let used = level_data.num_syms() > 0 || level_data.action != Action::None;
+                    if used && !reachable {
+                        findings.push(finding(
+                            LintCode::UnreachableLevel,
+                            Some(layout),
+                            Some(level),
+                            format!(
+                                "key <{}>: level {} of group {} is not selected by any modifiers of type \"{}\"",
+                                name,
+                                level + 1,
+                                layout + 1,
+                                ctx.xkb_atom_text(_type.name)
+                            ),
+                        ));
+                    }
+
+                    let [Some(sym)] = level_data.syms.as_slice() else {
+                        continue;
+                    };
+                    match producers.get(&(layout, *sym)) {
+                        Some(first) if *first == key.name => {}
+                        Some(first) if reachable => findings.push(finding(
+                            LintCode::DuplicateKeysym,
+                            Some(layout),
+                            Some(level),
+                            format!(
+                                "key <{}>: {} in group {} is also produced by <{}>",
+                                name,
+                                ctx.keysym_text(sym),
+                                layout + 1,
+                                ctx.xkb_atom_text(*first)
+                            ),
+                        )),
+                        Some(_) => {}
+                        None if reachable => {
+                            producers.insert((layout, *sym), key.name);
+                        }
+                        None => {}
+                    }
+                }
+            }
+
+            if !key.groups.is_empty()
+                && !key.explicit.intersects(ExplicitComponents::REPEAT)
+                && (key.explicit.intersects(ExplicitComponents::INTERP)
+                    || key.groups[0].levels.first().map(Level::num_syms) == Some(0))
+            {
+                findings.push(finding(
+                    LintCode::MissingRepeat,
+                    None,
+                    None,
+                    format!(
+                        "key <{}>: repeat is neither set explicitly nor by a symbol interpretation",
+                        name
+                    ),
+                ));
+            }
+
+            let base_sym = key
+                .groups
+                .first()
+                .and_then(|group| group.levels.first())
+                .and_then(|level| match level.syms.as_slice() {
+                    [Some(sym)] if sym.is_modifier_key() => Some(*sym),
+                    _ => None,
+                });
+            if let Some(sym) = base_sym {
+                match modifier_keys.get(&sym) {
+                    Some((first, modmap)) if *modmap != key.modmap => findings.push(finding(
+                        LintCode::ConflictingModmap,
+                        None,
+                        None,
+                        format!(
+                            "key <{}>: {} is mapped to {}, but to {} on <{}>",
+                            name,
+                            ctx.keysym_text(&sym),
+                            ctx.mod_mask_text(&self.mods, key.modmap),
+                            ctx.mod_mask_text(&self.mods, *modmap),
+                            ctx.xkb_atom_text(*first)
+                        ),
+                    )),
+                    Some(_) => {}
+                    None => {
+                        modifier_keys.insert(sym, (key.name, key.modmap));
+                    }
+                }
+            }
+        }
+    }
+}
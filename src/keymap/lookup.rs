@@ -0,0 +1,96 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+/// A way of typing a keysym, as returned by [Keymap::keysym_lookup()] and
+/// [Keymap::char_lookup()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyLookup {
+    /// The key to press.
+    pub keycode: Keycode,
+    /// The layout which must be active.
+    pub layout: LayoutIndex,
+    /// The shift level of the key which produces the keysym.
+    pub level: LevelIndex,
+    /// The modifier masks which select the level, as in [Keymap::key_get_mods_for_level()],
+    /// with the fewest modifiers first.
+    pub mods: Vec<ModMask>,
+}
+
+impl Keymap {
+    /// Find every key, layout and level which produces the given keysym.
+    ///
+    /// This is the reverse of [State::key_get_one_sym()](crate::State::key_get_one_sym), as
+    /// performed by the `how-to-type` tool. Only levels producing exactly one keysym are
+    /// considered.
+    ///
+    /// The results are ordered by preference: by layout, then by level, then by keycode.
+    /// Levels which cannot be selected by any modifier combination are omitted.
+    pub fn keysym_lookup(&self, sym: Keysym) -> Vec<KeyLookup> {
+        self.lookup(|level_sym| *level_sym == sym)
+    }
+
+    /// Find every key, layout and level which produces the given character.
+    ///
+    /// Same as [Keymap::keysym_lookup()], but matches every keysym which corresponds to the
+    /// character, e.g. both `eacute` and `U00E9` for `é`.
+    pub fn char_lookup(&self, ch: char) -> Vec<KeyLookup> {
+        self.lookup(|level_sym| crate::keysyms_utf::keysym_to_utf32(level_sym) == Some(ch as u32))
+    }
+
+    fn lookup(&self, matches: impl Fn(&Keysym) -> bool) -> Vec<KeyLookup> {
+        let mut results = vec![];
+
+        for (kc, key) in self.keys.iter() {
+            for (layout, group) in key.groups.iter().enumerate() {
+                for (level, syms) in group.levels.iter().map(|level| &level.syms).enumerate() {
+                    match syms.as_slice() {
+                        [Some(level_sym)] if matches(level_sym) => {}
+                        _ => continue,
+                    }
+
+                    let mut mods = self
+                        .key_get_mods_for_level(*kc, layout, level, usize::MAX)
+                        .unwrap_or_default();
+                    if mods.is_empty() {
+                        continue;
+                    }
+                    mods.sort_by_key(|mask| mask.count_ones());
+
+                    results.push(KeyLookup {
+                        keycode: Keycode(*kc),
+                        layout,
+                        level,
+                        mods,
+                    });
+                }
+            }
+        }
+
+        results.sort_by_key(|result| (result.layout, result.level, result.keycode));
+
+        results
+    }
+}
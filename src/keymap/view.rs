@@ -0,0 +1,792 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+use crate::keysyms::keysym_from_name;
+use crate::text::{
+    lookup_key, lookup_value, ACTION_TYPE_NAMES, CTRL_MASK_NAMES, GROUP_COMPONENT_MASK_NAMES,
+    MOD_COMPONENT_MASK_NAMES, SYM_INTERPRET_MATCH_MASK_NAMES,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Write;
+
+/// A structural view of a [Keymap], which can be serialized with `serde`.
+///
+/// Modifiers, keys and key types are referred to by name. Keysyms are written with their
+/// names, and actions in the syntax of the XKB text format, e.g.
+/// `SetMods(modifiers=Shift,clearLocks)`.
+///
+/// A view is turned back into a [Keymap] with [Keymap::from_view()], which goes through the
+/// same validation as the compiler.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeymapView {
+    /// The modifiers, real modifiers first, in the order of their indices.
+    pub mods: Vec<ModView>,
+    /// The names of the layouts.
+    #[serde(default)]
+    pub group_names: Vec<String>,
+    #[serde(default)]
+    pub aliases: Vec<AliasView>,
+    pub types: Vec<KeyTypeView>,
+    /// The symbol interpretations of the compatibility map.
+    #[serde(default)]
+    pub interprets: Vec<InterpretView>,
+    #[serde(default)]
+    pub leds: Vec<LedView>,
+    pub keys: Vec<KeyView>,
+}
+
+/// A modifier of a [KeymapView].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModView {
+    pub name: String,
+    pub is_virtual: bool,
+    /// The real modifiers a virtual modifier is mapped to.
+    ///
+    /// This is computed from the modifier maps of the keys, and is
+    /// ignored by [Keymap::from_view()].
+    #[serde(default)]
+    pub mapping: Vec<String>,
+}
+
+/// A key alias of a [KeymapView].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AliasView {
+    pub alias: String,
+    pub real: String,
+}
+
+/// A key type of a [KeymapView].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeyTypeView {
+    pub name: String,
+    pub modifiers: Vec<String>,
+    #[serde(default)]
+    pub map: Vec<KeyTypeEntryView>,
+    #[serde(default)]
+    pub level_names: BTreeMap<LevelIndex, String>,
+}
+
+/// A modifier combination of a [KeyTypeView] and the level it selects.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeyTypeEntryView {
+    pub modifiers: Vec<String>,
+    pub level: LevelIndex,
+    #[serde(default)]
+    pub preserve: Vec<String>,
+}
+
+/// A symbol interpretation of a [KeymapView].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InterpretView {
+    /// The keysym to match, or `None` to match any keysym.
+    pub sym: Option<String>,
+    /// How the modifiers are matched, e.g. `AnyOfOrNone` or `Exactly`.
+    pub match_op: String,
+    pub modifiers: Vec<String>,
+    #[serde(default)]
+    pub virtual_modifier: Option<String>,
+    #[serde(default)]
+    pub level_one_only: bool,
+    #[serde(default)]
+    pub repeat: bool,
+    pub action: String,
+}
+
+/// An LED of a [KeymapView].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LedView {
+    pub index: LedIndex,
+    pub name: Option<String>,
+    /// The layout state components, e.g. `locked`, which are compared with `groups`.
+    #[serde(default)]
+    pub which_groups: Vec<String>,
+    #[serde(default)]
+    pub groups: LayoutMask,
+    /// The modifier state components, e.g. `effective`, which are compared with `modifiers`.
+    #[serde(default)]
+    pub which_mods: Vec<String>,
+    #[serde(default)]
+    pub modifiers: Vec<String>,
+    #[serde(default)]
+    pub controls: Vec<String>,
+}
+
+/// What to do with a layout index which is out of the range of the layouts of a key.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum OutOfRangeGroupView {
+    #[default]
+    Wrap,
+    Clamp,
+    Redirect(LayoutIndex),
+}
+
+/// A key of a [KeymapView].
+///
+/// The repeat flag, the virtual modifier map and the actions of a key which are not explicit
+/// are computed from the symbol interpretations when the keymap is compiled.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeyView {
+    pub name: String,
+    pub keycode: RawKeycode,
+    #[serde(default)]
+    pub groups: Vec<GroupView>,
+    #[serde(default)]
+    pub repeats: bool,
+    #[serde(default)]
+    pub explicit_repeat: bool,
+    #[serde(default)]
+    pub modmap: Vec<String>,
+    #[serde(default)]
+    pub vmodmap: Vec<String>,
+    #[serde(default)]
+    pub explicit_vmodmap: bool,
+    #[serde(default)]
+    pub explicit_actions: bool,
+    #[serde(default)]
+    pub out_of_range_group: OutOfRangeGroupView,
+}
+
+/// A layout of a [KeyView].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GroupView {
+    pub key_type: String,
+    #[serde(default)]
+    pub explicit_type: bool,
+    pub levels: Vec<LevelView>,
+}
+
+/// A shift level of a [GroupView].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LevelView {
+    #[serde(default)]
+    pub syms: Vec<String>,
+    /// The action of the level, or `None` for `NoAction()`.
+    #[serde(default)]
+    pub action: Option<String>,
+}
+
+impl Keymap {
+    /// Get a structural view of the keymap.
+    pub fn to_view(&self) -> KeymapView {
+        let ctx = &self.context;
+
+        let mods = self
+            .mods
+            .mods
+            .iter()
+            .map(|_mod| ModView {
+                name: ctx.xkb_atom_text(_mod.name).into(),
+                is_virtual: _mod.mod_type == ModType::VIRT,
+                mapping: match _mod.mod_type == ModType::VIRT {
+                    true => self.mod_names(_mod.mapping),
+                    false => vec![],
+                },
+            })
+            .collect();
+
+        let aliases = self
+            .key_aliases
+            .iter()
+            .map(|alias| AliasView {
+                alias: ctx.xkb_atom_text(alias.alias).into(),
+                real: ctx.xkb_atom_text(alias.real).into(),
+            })
+            .collect();
+
+        let types = self
+            .types
+            .iter()
+            .map(|_type| KeyTypeView {
+                name: ctx.xkb_atom_text(_type.name).into(),
+                modifiers: self.mod_names(_type.mods.mods),
+                map: _type
+                    .entries
+                    .iter()
+                    .map(|entry| KeyTypeEntryView {
+                        modifiers: self.mod_names(entry.mods.mods),
+                        level: entry.level,
+                        preserve: self.mod_names(entry.preserve.mods),
+                    })
+                    .collect(),
+                level_names: _type
+                    .level_names
+                    .iter()
+                    .map(|(level, name)| (*level, ctx.xkb_atom_text(*name).into()))
+                    .collect(),
+            })
+            .collect();
+
+        let interprets = self
+            .sym_interprets
+            .iter()
+            .map(|si| InterpretView {
+                sym: si.sym.map(|sym| ctx.keysym_text(&sym)),
+                match_op: ctx.si_match_text(&si.match_op).into(),
+                modifiers: self.mod_names(si.mods.mods),
+                virtual_modifier: si
+                    .virtual_mod
+                    .map(|vmod| ctx.mod_index_text(&self.mods, vmod).into()),
+                level_one_only: si.level_one_only,
+                repeat: si.repeat,
+                action: self.action_text(&si.action),
+            })
+            .collect();
+
+        let leds = self
+            .leds
+            .iter()
+            .enumerate()
+            .filter_map(|(index, led)| Some((index, led.as_ref()?)))
+            .map(|(index, led)| LedView {
+                index,
+                name: led.name.map(|name| ctx.xkb_atom_text(name).into()),
+                which_groups: mask_names(&GROUP_COMPONENT_MASK_NAMES, led.which_groups),
+                groups: led.groups,
+                which_mods: mask_names(&MOD_COMPONENT_MASK_NAMES, led.which_mods),
+                modifiers: self.mod_names(led.mods.mods),
+                controls: mask_names(&CTRL_MASK_NAMES, led.ctrls),
+            })
+            .collect();
+
+        let keys = self
+            .keys
+            .iter()
+            .map(|(kc, key)| self.key_view(*kc, key))
+            .collect();
+
+        KeymapView {
+            mods,
+            group_names: self
+                .group_names
+                .iter()
+                .map(|name| ctx.xkb_atom_text(*name).into())
+                .collect(),
+            aliases,
+            types,
+            interprets,
+            leds,
+            keys,
+        }
+    }
+
+    /// Create a keymap from a structural view.
+    ///
+    /// The view is compiled as a keymap in the XKB text format would be, so an invalid view
+    /// results in the same errors and warnings.
+    pub fn from_view<F>(
+        context: Context,
+        view: &KeymapView,
+        flags: F,
+    ) -> Result<Self, KeymapCompileError>
+    where
+        F: TryInto<CompileFlags> + Into<u32> + Clone,
+    {
+        view.validate().map_err(KeymapCompileError::InvalidView)?;
+
+        let text = view
+            .to_text()
+            .map_err(|e| KeymapCompileError::CouldNotSerialize(e.to_string()))?;
+
+        Self::new_from_string(context, &text, KeymapFormat::TextV1, flags)
+    }
+
+    fn key_view(&self, kc: RawKeycode, key: &Key) -> KeyView {
+        let ctx = &self.context;
+
+        let groups = key
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(layout, group)| GroupView {
+                key_type: self
+                    .types
+                    .get(group.key_type)
+                    .map(|_type| ctx.xkb_atom_text(_type.name).into())
+                    .unwrap_or_default(),
+                explicit_type: group.explicit_type,
+                levels: group
+                    .levels
+                    .iter()
+                    .enumerate()
+                    .map(|(level, level_data)| LevelView {
+                        syms: self
+                            .key_get_syms_by_level(kc, layout, level)
+                            .unwrap_or_default()
+                            .iter()
+                            .map(|sym| ctx.keysym_text(sym))
+                            .collect(),
+                        action: match level_data.action {
+                            Action::None => None,
+                            ref action => Some(self.action_text(action)),
+                        },
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        KeyView {
+            name: ctx.xkb_atom_text(key.name).into(),
+            keycode: kc,
+            groups,
+            repeats: key.repeats,
+            explicit_repeat: key.explicit.intersects(ExplicitComponents::REPEAT),
+            modmap: self.mod_names(key.modmap),
+            vmodmap: self.mod_names(key.vmodmap),
+            explicit_vmodmap: key.explicit.intersects(ExplicitComponents::VMODMAP),
+            explicit_actions: key.explicit.intersects(ExplicitComponents::INTERP),
+            out_of_range_group: match key.out_of_range_group_action {
+                RangeExceedType::Wrap => OutOfRangeGroupView::Wrap,
+                RangeExceedType::Saturate => OutOfRangeGroupView::Clamp,
+                RangeExceedType::Redirect => {
+                    OutOfRangeGroupView::Redirect(key.out_of_range_group_number)
+                }
+            },
+        }
+    }
+
+    fn mod_names(&self, mask: ModMask) -> Vec<String> {
+        self.mods
+            .mods
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| mask & (1 << idx) != 0)
+            .map(|(_, _mod)| self.context.xkb_atom_text(_mod.name).into())
+            .collect()
+    }
+}
+
+// Names of the flags of a mask, as written in the XKB text format
+fn mask_names<T>(tab: &phf::OrderedMap<unicase::UniCase<&'static str>, T>, mask: T) -> Vec<String>
+where
+    T: bitflags::Flags<Bits = u16> + PartialEq,
+{
+    (0..16)
+        .filter(|i| mask.bits() & (1 << i) != 0)
+        .filter_map(|i| lookup_value(tab, T::from_bits_retain(1 << i), false))
+        .map(String::from)
+        .collect()
+}
+
+fn mask_text(names: &[String]) -> String {
+    match names.is_empty() {
+        true => "none".into(),
+        false => names.join("+"),
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s)
+}
+
+// The lexer has no escapes for quotes, and drops backslashes
+fn is_string(s: &str) -> bool {
+    !s.chars().any(|c| c == '"' || c == '\\' || c.is_control())
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// The characters allowed between `<` and `>` by the lexer
+fn is_key_name(s: &str) -> bool {
+    s.bytes()
+        .all(|b| matches!(b, 0x21..=0x3b | 0x3d | 0x3f..=0x7e))
+}
+
+// An action in the syntax of the keymap dump, e.g. `SetMods(modifiers=Shift,clearLocks)`,
+// whose arguments cannot end the statement
+fn is_action(s: &str) -> bool {
+    let Some((name, args)) = s.split_once('(') else {
+        return false;
+    };
+    let Some(args) = args.strip_suffix(')') else {
+        return false;
+    };
+
+    lookup_key(&ACTION_TYPE_NAMES, name).is_some()
+        && args
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || "_+-=,<>[].~!".contains(c))
+}
+
+impl KeymapView {
+    // Check the fields which are written into the XKB text, so that
+    // a view cannot inject other statements.
+    fn validate(&self) -> Result<(), String> {
+        let check = |valid: bool, what: &str, value: &str| match valid {
+            true => Ok(()),
+            false => Err(format!("invalid {} {:?}", what, value)),
+        };
+        let check_mods = |mods: &[String]| {
+            mods.iter().try_for_each(|name| {
+                check(
+                    self.mods.iter().any(|_mod| _mod.name == *name),
+                    "modifier",
+                    name,
+                )
+            })
+        };
+        let check_keysym = |sym: &String| {
+            check(
+                keysym_from_name(sym, KeysymFlags::NO_FLAGS).is_some(),
+                "keysym",
+                sym,
+            )
+        };
+
+        for _mod in self.mods.iter() {
+            check(is_ident(&_mod.name), "modifier name", &_mod.name)?;
+        }
+
+        for name in self.group_names.iter() {
+            check(is_string(name), "layout name", name)?;
+        }
+
+        for alias in self.aliases.iter() {
+            check(is_key_name(&alias.alias), "key name", &alias.alias)?;
+            check(is_key_name(&alias.real), "key name", &alias.real)?;
+        }
+
+        for _type in self.types.iter() {
+            check(is_string(&_type.name), "type name", &_type.name)?;
+            for name in _type.level_names.values() {
+                check(is_string(name), "level name", name)?;
+            }
+            check_mods(&_type.modifiers)?;
+            for entry in _type.map.iter() {
+                check_mods(&entry.modifiers)?;
+                check_mods(&entry.preserve)?;
+            }
+        }
+
+        for si in self.interprets.iter() {
+            if let Some(sym) = si.sym.as_ref() {
+                check_keysym(sym)?;
+            }
+            check(
+                lookup_key(&SYM_INTERPRET_MATCH_MASK_NAMES, &si.match_op).is_some(),
+                "match operation",
+                &si.match_op,
+            )?;
+            check_mods(&si.modifiers)?;
+            if let Some(vmod) = si.virtual_modifier.as_ref() {
+                check_mods(std::slice::from_ref(vmod))?;
+            }
+            check(is_action(&si.action), "action", &si.action)?;
+        }
+
+        for led in self.leds.iter() {
+            if let Some(name) = led.name.as_ref() {
+                check(is_string(name), "LED name", name)?;
+            }
+            for which in led.which_groups.iter() {
+                check(
+                    lookup_key(&GROUP_COMPONENT_MASK_NAMES, which).is_some(),
+                    "layout state component",
+                    which,
+                )?;
+            }
+            for which in led.which_mods.iter() {
+                check(
+                    lookup_key(&MOD_COMPONENT_MASK_NAMES, which).is_some(),
+                    "modifier state component",
+                    which,
+                )?;
+            }
+            for control in led.controls.iter() {
+                check(
+                    lookup_key(&CTRL_MASK_NAMES, control).is_some(),
+                    "control",
+                    control,
+                )?;
+            }
+            check_mods(&led.modifiers)?;
+        }
+
+        for key in self.keys.iter() {
+            check(is_key_name(&key.name), "key name", &key.name)?;
+            check_mods(&key.modmap)?;
+            check_mods(&key.vmodmap)?;
+            for group in key.groups.iter() {
+                check(is_string(&group.key_type), "type name", &group.key_type)?;
+            }
+            for level in key.groups.iter().flat_map(|group| group.levels.iter()) {
+                level.syms.iter().try_for_each(check_keysym)?;
+                if let Some(action) = level.action.as_ref() {
+                    check(is_action(action), "action", action)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write the view as a keymap in the XKB text format, in the same
+    // layout as the keymap dump, so that the compiler can validate it.
+    fn to_text(&self) -> Result<String, std::fmt::Error> {
+        let mut buf = String::new();
+
+        writeln!(buf, "xkb_keymap {{")?;
+
+        // keycodes
+        writeln!(buf, "xkb_keycodes {{")?;
+        let min = self.keys.iter().map(|key| key.keycode).min().unwrap_or(8);
+        let max = self.keys.iter().map(|key| key.keycode).max().unwrap_or(255);
+        writeln!(buf, "\tminimum = {};", u32::min(min, 8))?;
+        writeln!(buf, "\tmaximum = {};", u32::max(max, 255))?;
+        for key in self.keys.iter() {
+            writeln!(buf, "\t<{}> = {};", key.name, key.keycode)?;
+        }
+        for led in self.leds.iter() {
+            if let Some(name) = led.name.as_ref() {
+                writeln!(buf, "\tindicator {} = {};", led.index + 1, quote(name))?;
+            }
+        }
+        for alias in self.aliases.iter() {
+            writeln!(buf, "\talias <{}> = <{}>;", alias.alias, alias.real)?;
+        }
+        writeln!(buf, "}};\n")?;
+
+        // types
+        writeln!(buf, "xkb_types {{")?;
+        self.write_vmods(&mut buf)?;
+        for _type in self.types.iter() {
+            writeln!(buf, "\ttype {} {{", quote(&_type.name))?;
+            writeln!(buf, "\t\tmodifiers= {};", mask_text(&_type.modifiers))?;
+            for entry in _type.map.iter() {
+                if entry.level == 0 && entry.preserve.is_empty() {
+                    continue;
+                }
+                let mods = mask_text(&entry.modifiers);
+                writeln!(buf, "\t\tmap[{}]= {};", mods, entry.level + 1)?;
+                if !entry.preserve.is_empty() {
+                    writeln!(
+                        buf,
+                        "\t\tpreserve[{}]= {};",
+                        mods,
+                        mask_text(&entry.preserve)
+                    )?;
+                }
+            }
+            for (level, name) in _type.level_names.iter() {
+                writeln!(buf, "\t\tlevel_name[{}]= {};", level + 1, quote(name))?;
+            }
+            writeln!(buf, "\t}};")?;
+        }
+        writeln!(buf, "}};\n")?;
+
+        // compat
+        writeln!(buf, "xkb_compatibility {{")?;
+        self.write_vmods(&mut buf)?;
+        writeln!(buf, "\tinterpret.useModMapMods= AnyLevel;")?;
+        writeln!(buf, "\tinterpret.repeat= False;")?;
+        for si in self.interprets.iter() {
+            writeln!(
+                buf,
+                "\tinterpret {}+{}({}) {{",
+                si.sym.as_deref().unwrap_or("Any"),
+                si.match_op,
+                mask_text(&si.modifiers)
+            )?;
+            if let Some(vmod) = si.virtual_modifier.as_ref() {
+                writeln!(buf, "\t\tvirtualModifier= {};", vmod)?;
+            }
+            if si.level_one_only {
+                writeln!(buf, "\t\tuseModMapMods=level1;")?;
+            }
+            if si.repeat {
+                writeln!(buf, "\t\trepeat= True;")?;
+            }
+            writeln!(buf, "\t\taction= {};", si.action)?;
+            writeln!(buf, "\t}};")?;
+        }
+        for led in self.leds.iter() {
+            if led.which_groups.is_empty()
+                && led.groups == 0
+                && led.which_mods.is_empty()
+                && led.modifiers.is_empty()
+                && led.controls.is_empty()
+            {
+                continue;
+            }
+            writeln!(
+                buf,
+                "\tindicator {} {{",
+                quote(led.name.as_deref().unwrap_or(""))
+            )?;
+            if !led.which_groups.is_empty() {
+                writeln!(buf, "\t\twhichGroupState= {};", led.which_groups.join("+"))?;
+                writeln!(buf, "\t\tgroups= {:#04x};", led.groups)?;
+            }
+            if !led.which_mods.is_empty() {
+                writeln!(buf, "\t\twhichModState= {};", led.which_mods.join("+"))?;
+                writeln!(buf, "\t\tmodifiers= {};", mask_text(&led.modifiers))?;
+            }
+            if !led.controls.is_empty() {
+                writeln!(buf, "\t\tcontrols= {};", led.controls.join("+"))?;
+            }
+            writeln!(buf, "\t}};")?;
+        }
+        writeln!(buf, "}};\n")?;
+
+        // symbols
+        writeln!(buf, "xkb_symbols {{")?;
+        for (idx, name) in self.group_names.iter().enumerate() {
+            writeln!(buf, "\tname[Group{}]={};", idx + 1, quote(name))?;
+        }
+        for key in self.keys.iter().filter(|key| !key.groups.is_empty()) {
+            self.write_key(&mut buf, key)?;
+        }
+        for _mod in self.mods.iter() {
+            let keys: Vec<_> = self
+                .keys
+                .iter()
+                .filter(|key| key.modmap.contains(&_mod.name))
+                .map(|key| format!("<{}>", key.name))
+                .collect();
+            if !keys.is_empty() {
+                writeln!(
+                    buf,
+                    "\tmodifier_map {} {{ {} }};",
+                    _mod.name,
+                    keys.join(", ")
+                )?;
+            }
+        }
+        writeln!(buf, "}};\n")?;
+
+        writeln!(buf, "}};")?;
+
+        Ok(buf)
+    }
+
+    fn write_vmods(&self, buf: &mut String) -> std::fmt::Result {
+        let vmods: Vec<_> = self
+            .mods
+            .iter()
+            .filter(|_mod| _mod.is_virtual)
+            .map(|_mod| _mod.name.as_str())
+            .collect();
+
+        if !vmods.is_empty() {
+            write!(buf, "\tvirtual_modifiers {};\n\n", vmods.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_key(&self, buf: &mut String, key: &KeyView) -> std::fmt::Result {
+        let mut fields = vec![];
+
+        for (idx, group) in key.groups.iter().enumerate() {
+            if group.explicit_type {
+                fields.push(format!(
+                    "type[Group{}]= {}",
+                    idx + 1,
+                    quote(&group.key_type)
+                ));
+            }
+        }
+        if key.explicit_repeat {
+            fields.push(format!(
+                "repeat= {}",
+                match key.repeats {
+                    true => "Yes",
+                    false => "No",
+                }
+            ));
+        }
+        if key.explicit_vmodmap && !key.vmodmap.is_empty() {
+            fields.push(format!("virtualMods= {}", mask_text(&key.vmodmap)));
+        }
+        match key.out_of_range_group {
+            OutOfRangeGroupView::Wrap => {}
+            OutOfRangeGroupView::Clamp => fields.push("groupsClamp".into()),
+            OutOfRangeGroupView::Redirect(group) => {
+                fields.push(format!("groupsRedirect= Group{}", group + 1))
+            }
+        }
+        for (idx, group) in key.groups.iter().enumerate() {
+            let syms: Vec<_> = group
+                .levels
+                .iter()
+                .map(|level| match level.syms.as_slice() {
+                    [] => "NoSymbol".into(),
+                    [sym] => sym.clone(),
+                    syms => format!("{{ {} }}", syms.join(", ")),
+                })
+                .collect();
+            fields.push(format!(
+                "symbols[Group{}]= [ {} ]",
+                idx + 1,
+                syms.join(", ")
+            ));
+
+            if key.explicit_actions {
+                let actions: Vec<_> = group
+                    .levels
+                    .iter()
+                    .map(|level| level.action.as_deref().unwrap_or("NoAction()"))
+                    .collect();
+                fields.push(format!(
+                    "actions[Group{}]= [ {} ]",
+                    idx + 1,
+                    actions.join(", ")
+                ));
+            }
+        }
+
+        writeln!(
+            buf,
+            "\tkey <{}> {{\n\t\t{}\n\t}};",
+            key.name,
+            fields.join(",\n\t\t")
+        )
+    }
+}
+
+/// Keymaps are serialized as their [KeymapView].
+impl Serialize for Keymap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_view().serialize(serializer)
+    }
+}
+
+/// Keymaps are deserialized from a [KeymapView], in a new context without include paths.
+/// Use [Keymap::from_view()] to choose the context and the compile flags.
+impl<'de> Deserialize<'de> for Keymap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let view = KeymapView::deserialize(deserializer)?;
+
+        let context =
+            Context::new(ContextFlags::NO_DEFAULT_INCLUDES | ContextFlags::NO_ENVIRONMENT_NAMES)
+                .map_err(serde::de::Error::custom)?;
+
+        Keymap::from_view(context, &view, CompileFlags::NO_FLAGS).map_err(serde::de::Error::custom)
+    }
+}
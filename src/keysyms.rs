@@ -49,12 +49,11 @@
  * DEALINGS IN THE SOFTWARE.
  */
 
-#[cfg(test)]
 use xkeysym::RawKeysym;
 
 use crate::rust_xkbcommon::*;
 
-use crate::keysyms_generated_phf::{KEYSYM_TO_NAME, NAME_TO_KEYSYM};
+use crate::keysyms_generated_phf::{DEPRECATED_KEYSYM_NAMES, KEYSYM_TO_NAME, NAME_TO_KEYSYM};
 
 #[allow(dead_code)]
 mod constants {
@@ -123,23 +122,27 @@ pub fn keysym_get_name(ks: &Keysym) -> Option<String> {
     Some(format!("{:#010x}", ks))
 }
 
-// TODO: move test functions/structs to separate module
-#[cfg(test)]
-pub(crate) fn keysym_is_assigned(ks: &Keysym) -> bool {
+/// Determines whether a keysym is assigned, i.e. whether it has a name or is a Unicode keysym.
+pub fn keysym_is_assigned(ks: &Keysym) -> bool {
     (XKB_KEYSYM_UNICODE_MIN..=XKB_KEYSYM_UNICODE_MAX).contains(&ks.raw())
         || find_keysym_name(ks).is_some()
 }
 
-#[cfg(test)]
-pub(crate) struct KeysymIterator {
+/// An iterator over the assigned keysyms, in ascending order.
+///
+/// Each keysym is returned once, even if it has several names.
+pub struct KeysymIterator {
     explicit: bool,       // If true, traverse only explicitly named keysyms
     index: Option<usize>, // Current index in keysym_to_name
     keysym: RawKeysym,
 }
 
-#[cfg(test)]
 impl KeysymIterator {
-    pub(crate) fn new(iterate_only_explicit_keysyms: bool) -> Self {
+    /// Create an iterator over the assigned keysyms.
+    ///
+    /// If `iterate_only_explicit_keysyms` is set, only the keysyms with an explicit name are
+    /// returned; otherwise, all the Unicode keysyms are returned as well.
+    pub fn new(iterate_only_explicit_keysyms: bool) -> Self {
         Self {
             explicit: iterate_only_explicit_keysyms,
             index: None,
@@ -147,39 +150,7 @@ impl KeysymIterator {
         }
     }
 
-    /*
-    pub(crate) fn is_explicitly_named(&self) -> bool {
-        // ensure >= 0
-        let index: usize = match self.index {
-            Some(index) if index < KEYSYM_TO_NAME.len() => index,
-            _ => return false,
-        };
-
-        self.explicit
-            || KEYSYM_TO_NAME.get(index)
-                .map(|(sym, _)| sym.raw() == self.keysym)
-                .unwrap_or(false)
-    }
-    */
-
-    pub(crate) fn get_name(&self) -> Option<String> {
-        let index: usize = match self.index {
-            Some(index) if index < KEYSYM_TO_NAME.len() => index,
-            _ => return None,
-        };
-        if self.explicit
-            || KEYSYM_TO_NAME
-                .get(index)
-                .map(|(sym, _)| sym.raw() == self.keysym)
-                .unwrap_or(false)
-        {
-            return KEYSYM_TO_NAME.get(index).map(|(_, name)| name.to_string());
-        }
-
-        Some(get_unicode_name(self.keysym))
-    }
-
-    pub(crate) fn next(&mut self) -> Option<RawKeysym> {
+    fn step(&mut self) -> Option<RawKeysym> {
         if let Some(index) = self.index {
             if index >= KEYSYM_TO_NAME.len() - 1 {
                 return None;
@@ -230,6 +201,106 @@ impl KeysymIterator {
         Some(self.keysym)
     }
 }
+
+impl Iterator for KeysymIterator {
+    type Item = Keysym;
+
+    fn next(&mut self) -> Option<Keysym> {
+        let prev = self.index.map(|_| self.keysym);
+
+        // Skip the aliases of the previous keysym
+        loop {
+            let keysym = self.step()?;
+            if prev != Some(keysym) {
+                return Some(Keysym::from(keysym));
+            }
+        }
+    }
+}
+
+fn find_keysym_entries(ks: &Keysym) -> &'static [(Keysym, &'static str)] {
+    let start = KEYSYM_TO_NAME.partition_point(|(sym, _)| sym < ks);
+    let len = KEYSYM_TO_NAME[start..].partition_point(|(sym, _)| sym == ks);
+
+    &KEYSYM_TO_NAME[start..start + len]
+}
+
+/// Get all the explicit names of a keysym, starting with the name returned by
+/// [keysym_get_name()].
+///
+/// Unicode keysyms without an explicit name, e.g. `U0100`, have no names.
+pub fn keysym_get_names(ks: &Keysym) -> Vec<&'static str> {
+    find_keysym_entries(ks)
+        .iter()
+        .map(|&(_, name)| name)
+        .collect()
+}
+
+/// Determines whether a keysym name is deprecated.
+///
+/// A name is deprecated if it is marked as such in the X11 headers, or if it is a
+/// legacy alias of the canonical name of the keysym. Names are case-sensitive.
+pub fn keysym_name_is_deprecated(name: &str) -> bool {
+    DEPRECATED_KEYSYM_NAMES.binary_search(&name).is_ok()
+}
+
+/// Determines whether a keysym is deprecated, i.e. all of its names are deprecated.
+pub fn keysym_is_deprecated(ks: &Keysym) -> bool {
+    let entries = find_keysym_entries(ks);
+
+    !entries.is_empty()
+        && entries
+            .iter()
+            .all(|(_, name)| keysym_name_is_deprecated(name))
+}
+
+/// Information about an assigned keysym, as returned by [keysym_get_info()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeysymInfo {
+    /// The keysym.
+    pub keysym: Keysym,
+    /// The name of the keysym, as in [keysym_get_name()].
+    ///
+    /// This is the first name defined for the keysym, which may itself be deprecated, e.g.
+    /// `guillemotleft` for `guillemetleft`; see [KeysymInfo::preferred_name].
+    pub name: String,
+    /// The first name of the keysym which is not deprecated, or [KeysymInfo::name] if there is
+    /// no such name.
+    pub preferred_name: String,
+    /// The other names of the keysym, deprecated or not.
+    pub aliases: Vec<&'static str>,
+    /// The Unicode code point of the keysym, as in
+    /// [keysym_to_utf32()](crate::keysym::keysym_to_utf32).
+    pub utf32: Option<u32>,
+    /// Whether the keysym is deprecated, as in [keysym_is_deprecated()].
+    pub deprecated: bool,
+}
+
+/// Get the names, Unicode mapping and deprecation status of a keysym.
+///
+/// Returns `None` if the keysym is not assigned.
+pub fn keysym_get_info(ks: &Keysym) -> Option<KeysymInfo> {
+    if !keysym_is_assigned(ks) {
+        return None;
+    }
+
+    let names = keysym_get_names(ks);
+    let name = keysym_get_name(ks)?;
+    let preferred_name = match names.iter().find(|name| !keysym_name_is_deprecated(name)) {
+        Some(preferred_name) => preferred_name.to_string(),
+        None => name.clone(),
+    };
+
+    Some(KeysymInfo {
+        keysym: *ks,
+        name,
+        preferred_name,
+        aliases: names.into_iter().skip(1).collect(),
+        utf32: crate::keysyms_utf::keysym_to_utf32(ks),
+        deprecated: keysym_is_deprecated(ks),
+    })
+}
+
 fn parse_keysym_hex(s: &str) -> Option<u32> {
     let mut result: u32 = 0;
 
@@ -714,6 +785,202 @@ pub fn keysym_is_modifier(sym: &Keysym) -> bool {
         || sym == Keysym::Num_Lock.raw()
 }
 
+/// Get the names of the keysyms starting with `prefix`, for autocompletion.
+///
+/// The search is case-insensitive, and the names are returned in case-insensitive order.
+/// Deprecated names are not returned. At most `limit` names are returned.
+pub fn keysym_search_prefix(prefix: &str, limit: usize) -> Vec<&'static str> {
+    // Keysym names are ASCII
+    let prefix = prefix.to_ascii_lowercase();
+    let lower = |name: &'static str| name.bytes().map(|b| b.to_ascii_lowercase());
+
+    // The names after `NoSymbol` are sorted case-insensitively,
+    // so the names starting with the prefix are contiguous
+    let sorted = &NAME_TO_KEYSYM.entries[1..];
+    let start = sorted.partition_point(|(name, _)| lower(name).lt(prefix.bytes()));
+
+    let mut names: Vec<&'static str> = sorted[start..]
+        .iter()
+        .map(|(name, _)| *name)
+        .take_while(|name| lower(name).take(prefix.len()).eq(prefix.bytes()))
+        .filter(|name| !keysym_name_is_deprecated(name))
+        .take(limit)
+        .collect();
+
+    // `NoSymbol` is not sorted with the other names
+    if "nosymbol".starts_with(&prefix) {
+        let pos = names.partition_point(|name| {
+            lower(name)
+                .cmp(lower("NoSymbol"))
+                .then_with(|| name.cmp(&"NoSymbol"))
+                .is_lt()
+        });
+        names.insert(pos, "NoSymbol");
+        names.truncate(limit);
+    }
+
+    names
+}
+
+/// Get the names of the keysyms closest to `name`, e.g. to suggest a correction for a typo.
+///
+/// The names are ranked by their edit distance to `name`, ignoring case first; names too far
+/// from `name` are not returned. Deprecated names are not returned. At most `limit` names are
+/// returned.
+pub fn keysym_suggest(name: &str, limit: usize) -> Vec<&'static str> {
+    let name_lower: Vec<char> = name.to_lowercase().chars().collect();
+    let name_chars: Vec<char> = name.chars().collect();
+    let max_distance = name_chars.len() / 3 + 1;
+
+    let mut suggestions: Vec<((usize, usize), &'static str)> = NAME_TO_KEYSYM
+        .keys()
+        .filter(|candidate| !keysym_name_is_deprecated(candidate))
+        .filter(|candidate| candidate.len().abs_diff(name.len()) <= max_distance)
+        .filter_map(|candidate| {
+            let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+            let distance = edit_distance(&name_lower, &candidate_lower);
+            if distance > max_distance {
+                return None;
+            }
+            let candidate_chars: Vec<char> = candidate.chars().collect();
+            let exact_distance = edit_distance(&name_chars, &candidate_chars);
+
+            Some(((distance, exact_distance), *candidate))
+        })
+        .collect();
+
+    suggestions.sort();
+    suggestions.truncate(limit);
+
+    suggestions.into_iter().map(|(_, name)| name).collect()
+}
+
+/// Warn about an unrecognized keysym name, suggesting the closest keysym name if any.
+pub(crate) fn log_unrecognized_keysym(name: &str) {
+    use crate::message_codes::XkbWarning;
+
+    // Looking for a suggestion is expensive
+    if !log::log_enabled!(log::Level::Warn) {
+        return;
+    }
+
+    match keysym_suggest(name, 1).first() {
+        Some(suggestion) => log::warn!(
+            "{:?}: Unrecognized keysym \"{}\"; did you mean \"{}\"?",
+            XkbWarning::UnrecognizedKeysym,
+            name,
+            suggestion
+        ),
+        None => log::warn!(
+            "{:?}: Unrecognized keysym \"{}\"",
+            XkbWarning::UnrecognizedKeysym,
+            name
+        ),
+    }
+}
+
+/// The optimal string alignment distance: the number of insertions, deletions, substitutions
+/// and transpositions of adjacent characters needed to turn `a` into `b`.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in rows[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+/// The category of a keysym, as returned by [keysym_category()].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeysymCategory {
+    /// ISO lock and latch keys, e.g. `ISO_Level3_Latch` or `ISO_Next_Group_Lock`.
+    IsoLockLatch,
+    /// Modifier keys, e.g. `Shift_L` or `ISO_Level3_Shift`, as in [keysym_is_modifier()].
+    Modifier,
+    /// Keypad PF keys, `KP_F1` to `KP_F4`.
+    Pf,
+    /// Other keypad keys, as in [keysym_is_keypad()].
+    Keypad,
+    /// Function keys, `F1` to `F35`.
+    Function,
+    /// Cursor and navigation keys, e.g. `Home` or `Left`.
+    Cursor,
+    /// Miscellaneous function keys, e.g. `Print` or `Menu`.
+    MiscFunction,
+    /// TTY function keys, e.g. `BackSpace`, `Return` or `Escape`.
+    Tty,
+    /// Dead keys, e.g. `dead_acute`.
+    Dead,
+    /// XF86 keys, e.g. `XF86AudioPlay`.
+    XF86,
+    /// Keysyms of Unicode letters, e.g. `a` or `Cyrillic_ZHE`.
+    Letter,
+    /// Keysyms of Unicode digits, e.g. `1` or `U0661`.
+    Digit,
+    /// Keysyms of other printable Unicode characters: punctuation, symbols and spaces, e.g.
+    /// `comma`, `EuroSign` or `space`.
+    Symbol,
+    /// Any other keysym, including `NoSymbol` and the vendor-specific keysyms.
+    Other,
+}
+
+/// Get the category of a keysym.
+///
+/// Each keysym has exactly one category; e.g. `KP_1` is a [KeysymCategory::Keypad] key and not a
+/// [KeysymCategory::Digit].
+pub fn keysym_category(ks: &Keysym) -> KeysymCategory {
+    let name = find_keysym_name(ks).unwrap_or_default();
+
+    if name.starts_with("ISO_") && (name.ends_with("_Lock") || name.ends_with("_Latch")) {
+        KeysymCategory::IsoLockLatch
+    } else if keysym_is_modifier(ks) {
+        KeysymCategory::Modifier
+    } else if ks.is_pf_key() {
+        KeysymCategory::Pf
+    } else if keysym_is_keypad(ks) {
+        KeysymCategory::Keypad
+    } else if ks.is_function_key() {
+        KeysymCategory::Function
+    } else if ks.is_misc_function_key() {
+        // Before cursor keys, which include `Select`
+        KeysymCategory::MiscFunction
+    } else if ks.is_cursor_key() {
+        KeysymCategory::Cursor
+    } else if (Keysym::BackSpace.raw()..=Keysym::Escape.raw()).contains(&ks.raw())
+        || *ks == Keysym::Delete
+    {
+        KeysymCategory::Tty
+    } else if name.starts_with("dead_") {
+        KeysymCategory::Dead
+    } else if name.starts_with("XF86") {
+        KeysymCategory::XF86
+    } else {
+        match crate::keysyms_utf::keysym_to_utf32(ks).and_then(char::from_u32) {
+            Some(c) if c.is_alphabetic() => KeysymCategory::Letter,
+            Some(c) if c.is_numeric() => KeysymCategory::Digit,
+            Some(c) if !c.is_control() => KeysymCategory::Symbol,
+            _ => KeysymCategory::Other,
+        }
+    }
+}
+
 struct ConvertCase {
     upper: u32,
     lower: u32,
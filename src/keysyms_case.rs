@@ -0,0 +1,141 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use crate::keysyms::keysym_to_upper;
+use crate::keysyms_utf::keysym_to_utf32;
+use xkeysym::Keysym;
+
+/// Converts a keysym to its titlecase representation.
+///
+/// The titlecase of a character usually is its uppercase; it differs for the digraphs, e.g.
+/// `ǅ` (U+01C5) for `ǆ` (U+01C6), and for the Georgian letters, which have no titlecase.
+pub fn keysym_to_title(ks: &Keysym) -> Keysym {
+    let Some(cp) = keysym_to_utf32(ks) else {
+        return keysym_to_upper(ks);
+    };
+
+    let title = match cp {
+        // Latin digraphs DŽ, LJ, NJ and DZ
+        0x01c4..=0x01c6 => 0x01c5,
+        0x01c7..=0x01c9 => 0x01c8,
+        0x01ca..=0x01cc => 0x01cb,
+        0x01f1..=0x01f3 => 0x01f2,
+        // Georgian Mkhedruli letters
+        0x10d0..=0x10fa | 0x10fd..=0x10ff => return *ks,
+        _ => return keysym_to_upper(ks),
+    };
+
+    crate::keysyms_utf::utf32_to_keysym(title).unwrap_or(*ks)
+}
+
+/// Full and locale-sensitive case mapping of keysyms, using the Unicode special casing rules.
+///
+/// Unlike [keysym_to_upper()] and [keysym_to_lower()](crate::keysym::keysym_to_lower), which
+/// only use the simple case mappings, this handles e.g. the Turkish dotted and dotless `i`,
+/// or the Greek uppercasing without accents. A mapping to several characters, such as `ß` to
+/// `SS`, cannot be represented by a single keysym; the simple case mapping is used instead.
+///
+/// Requires the `unicode-case` feature.
+#[cfg(feature = "unicode-case")]
+#[derive(Clone, Debug)]
+pub struct KeysymCaseMapper {
+    mapper: icu_casemap::CaseMapper,
+    langid: icu_locid::LanguageIdentifier,
+}
+
+#[cfg(feature = "unicode-case")]
+impl KeysymCaseMapper {
+    /// Create a case mapper for a language, given as a BCP-47 language tag, e.g. `tr` or
+    /// `el-GR`. The empty string selects the language-independent mappings.
+    ///
+    /// Returns `None` if the language tag is invalid.
+    pub fn new(language: &str) -> Option<Self> {
+        let langid = match language {
+            "" => icu_locid::LanguageIdentifier::UND,
+            language => language.parse().ok()?,
+        };
+
+        Some(Self {
+            mapper: icu_casemap::CaseMapper::new(),
+            langid,
+        })
+    }
+
+    /// Converts a keysym to its uppercase representation.
+    pub fn to_upper(&self, ks: &Keysym) -> Keysym {
+        self.map(
+            ks,
+            |s| self.mapper.uppercase_to_string(s, &self.langid),
+            |c| self.mapper.simple_uppercase(c),
+        )
+    }
+
+    /// Converts a keysym to its lowercase representation.
+    pub fn to_lower(&self, ks: &Keysym) -> Keysym {
+        self.map(
+            ks,
+            |s| self.mapper.lowercase_to_string(s, &self.langid),
+            |c| self.mapper.simple_lowercase(c),
+        )
+    }
+
+    /// Converts a keysym to its titlecase representation.
+    pub fn to_title(&self, ks: &Keysym) -> Keysym {
+        self.map(
+            ks,
+            |s| {
+                self.mapper.titlecase_segment_with_only_case_data_to_string(
+                    s,
+                    &self.langid,
+                    Default::default(),
+                )
+            },
+            |c| self.mapper.simple_titlecase(c),
+        )
+    }
+
+    fn map(
+        &self,
+        ks: &Keysym,
+        full: impl Fn(&str) -> String,
+        simple: impl Fn(char) -> char,
+    ) -> Keysym {
+        let Some(c) = keysym_to_utf32(ks).and_then(char::from_u32) else {
+            return *ks;
+        };
+
+        let mapped = full(c.encode_utf8(&mut [0; 4]));
+        let mut chars = mapped.chars();
+        let mapped = match (chars.next(), chars.next()) {
+            (Some(mapped), None) => mapped,
+            _ => simple(c),
+        };
+
+        if mapped == c {
+            return *ks;
+        }
+
+        crate::keysyms_utf::utf32_to_keysym(mapped as u32).unwrap_or(*ks)
+    }
+}
@@ -1,5224 +1,2 @@
-// This file was automatically generated using keysym_map_gen.
-/* Copyright 2022-2023 John Nunley
- * Copyright © 2024 wysiwys
- *
- * Permission is hereby granted, free of charge, to any person obtaining a
- * copy of this software and associated documentation files (the "Software"),
- * to deal in the Software without restriction, including without limitation
- * the rights to use, copy, modify, merge, publish, distribute, sublicense,
- * and/or sell copies of the Software, and to permit persons to whom the
- * Software is furnished to do so, subject to the following conditions:
- *
- * The above copyright notice and this permission notice (including the next
- * paragraph) shall be included in all copies or substantial portions of the
- * Software.
- *
- * THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
- * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
- * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
- * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
- * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
- * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
- * DEALINGS IN THE SOFTWARE.
- *
- */
-use xkeysym::Keysym;
-
-#[allow(unreachable_patterns)]
-pub(crate) static NAME_TO_KEYSYM: phf::OrderedMap<&'static str, Keysym> = phf_ordered_map! {
-        "NoSymbol" => Keysym::NoSymbol,
-        "0" => Keysym::_0,
-        "1" => Keysym::_1,
-        "2" => Keysym::_2,
-        "3" => Keysym::_3,
-        "3270_AltCursor" => Keysym::_3270_AltCursor,
-        "3270_Attn" => Keysym::_3270_Attn,
-        "3270_BackTab" => Keysym::_3270_BackTab,
-        "3270_ChangeScreen" => Keysym::_3270_ChangeScreen,
-        "3270_Copy" => Keysym::_3270_Copy,
-        "3270_CursorBlink" => Keysym::_3270_CursorBlink,
-        "3270_CursorSelect" => Keysym::_3270_CursorSelect,
-        "3270_DeleteWord" => Keysym::_3270_DeleteWord,
-        "3270_Duplicate" => Keysym::_3270_Duplicate,
-        "3270_Enter" => Keysym::_3270_Enter,
-        "3270_EraseEOF" => Keysym::_3270_EraseEOF,
-        "3270_EraseInput" => Keysym::_3270_EraseInput,
-        "3270_ExSelect" => Keysym::_3270_ExSelect,
-        "3270_FieldMark" => Keysym::_3270_FieldMark,
-        "3270_Ident" => Keysym::_3270_Ident,
-        "3270_Jump" => Keysym::_3270_Jump,
-        "3270_KeyClick" => Keysym::_3270_KeyClick,
-        "3270_Left2" => Keysym::_3270_Left2,
-        "3270_PA1" => Keysym::_3270_PA1,
-        "3270_PA2" => Keysym::_3270_PA2,
-        "3270_PA3" => Keysym::_3270_PA3,
-        "3270_Play" => Keysym::_3270_Play,
-        "3270_PrintScreen" => Keysym::_3270_PrintScreen,
-        "3270_Quit" => Keysym::_3270_Quit,
-        "3270_Record" => Keysym::_3270_Record,
-        "3270_Reset" => Keysym::_3270_Reset,
-        "3270_Right2" => Keysym::_3270_Right2,
-        "3270_Rule" => Keysym::_3270_Rule,
-        "3270_Setup" => Keysym::_3270_Setup,
-        "3270_Test" => Keysym::_3270_Test,
-        "4" => Keysym::_4,
-        "5" => Keysym::_5,
-        "6" => Keysym::_6,
-        "7" => Keysym::_7,
-        "8" => Keysym::_8,
-        "9" => Keysym::_9,
-        "A" => Keysym::A,
-        "a" => Keysym::a,
-        "Aacute" => Keysym::Aacute,
-        "aacute" => Keysym::aacute,
-        "Abelowdot" => Keysym::Abelowdot,
-        "abelowdot" => Keysym::abelowdot,
-        "abovedot" => Keysym::abovedot,
-        "Abreve" => Keysym::Abreve,
-        "abreve" => Keysym::abreve,
-        "Abreveacute" => Keysym::Abreveacute,
-        "abreveacute" => Keysym::abreveacute,
-        "Abrevebelowdot" => Keysym::Abrevebelowdot,
-        "abrevebelowdot" => Keysym::abrevebelowdot,
-        "Abrevegrave" => Keysym::Abrevegrave,
-        "abrevegrave" => Keysym::abrevegrave,
-        "Abrevehook" => Keysym::Abrevehook,
-        "abrevehook" => Keysym::abrevehook,
-        "Abrevetilde" => Keysym::Abrevetilde,
-        "abrevetilde" => Keysym::abrevetilde,
-        "AccessX_Enable" => Keysym::AccessX_Enable,
-        "AccessX_Feedback_Enable" => Keysym::AccessX_Feedback_Enable,
-        "Acircumflex" => Keysym::Acircumflex,
-        "acircumflex" => Keysym::acircumflex,
-        "Acircumflexacute" => Keysym::Acircumflexacute,
-        "acircumflexacute" => Keysym::acircumflexacute,
-        "Acircumflexbelowdot" => Keysym::Acircumflexbelowdot,
-        "acircumflexbelowdot" => Keysym::acircumflexbelowdot,
-        "Acircumflexgrave" => Keysym::Acircumflexgrave,
-        "acircumflexgrave" => Keysym::acircumflexgrave,
-        "Acircumflexhook" => Keysym::Acircumflexhook,
-        "acircumflexhook" => Keysym::acircumflexhook,
-        "Acircumflextilde" => Keysym::Acircumflextilde,
-        "acircumflextilde" => Keysym::acircumflextilde,
-        "acute" => Keysym::acute,
-        "Adiaeresis" => Keysym::Adiaeresis,
-        "adiaeresis" => Keysym::adiaeresis,
-        "AE" => Keysym::AE,
-        "ae" => Keysym::ae,
-        "Agrave" => Keysym::Agrave,
-        "agrave" => Keysym::agrave,
-        "Ahook" => Keysym::Ahook,
-        "ahook" => Keysym::ahook,
-        "Alt_L" => Keysym::Alt_L,
-        "Alt_R" => Keysym::Alt_R,
-        "Amacron" => Keysym::Amacron,
-        "amacron" => Keysym::amacron,
-        "ampersand" => Keysym::ampersand,
-        "Aogonek" => Keysym::Aogonek,
-        "aogonek" => Keysym::aogonek,
-        "apostrophe" => Keysym::apostrophe,
-        "approxeq" => Keysym::approxeq,
-        "approximate" => Keysym::approximate,
-        "Arabic_0" => Keysym::Arabic_0,
-        "Arabic_1" => Keysym::Arabic_1,
-        "Arabic_2" => Keysym::Arabic_2,
-        "Arabic_3" => Keysym::Arabic_3,
-        "Arabic_4" => Keysym::Arabic_4,
-        "Arabic_5" => Keysym::Arabic_5,
-        "Arabic_6" => Keysym::Arabic_6,
-        "Arabic_7" => Keysym::Arabic_7,
-        "Arabic_8" => Keysym::Arabic_8,
-        "Arabic_9" => Keysym::Arabic_9,
-        "Arabic_ain" => Keysym::Arabic_ain,
-        "Arabic_alef" => Keysym::Arabic_alef,
-        "Arabic_alefmaksura" => Keysym::Arabic_alefmaksura,
-        "Arabic_beh" => Keysym::Arabic_beh,
-        "Arabic_comma" => Keysym::Arabic_comma,
-        "Arabic_dad" => Keysym::Arabic_dad,
-        "Arabic_dal" => Keysym::Arabic_dal,
-        "Arabic_damma" => Keysym::Arabic_damma,
-        "Arabic_dammatan" => Keysym::Arabic_dammatan,
-        "Arabic_ddal" => Keysym::Arabic_ddal,
-        "Arabic_farsi_yeh" => Keysym::Arabic_farsi_yeh,
-        "Arabic_fatha" => Keysym::Arabic_fatha,
-        "Arabic_fathatan" => Keysym::Arabic_fathatan,
-        "Arabic_feh" => Keysym::Arabic_feh,
-        "Arabic_fullstop" => Keysym::Arabic_fullstop,
-        "Arabic_gaf" => Keysym::Arabic_gaf,
-        "Arabic_ghain" => Keysym::Arabic_ghain,
-        "Arabic_ha" => Keysym::Arabic_ha,
-        "Arabic_hah" => Keysym::Arabic_hah,
-        "Arabic_hamza" => Keysym::Arabic_hamza,
-        "Arabic_hamza_above" => Keysym::Arabic_hamza_above,
-        "Arabic_hamza_below" => Keysym::Arabic_hamza_below,
-        "Arabic_hamzaonalef" => Keysym::Arabic_hamzaonalef,
-        "Arabic_hamzaonwaw" => Keysym::Arabic_hamzaonwaw,
-        "Arabic_hamzaonyeh" => Keysym::Arabic_hamzaonyeh,
-        "Arabic_hamzaunderalef" => Keysym::Arabic_hamzaunderalef,
-        "Arabic_heh" => Keysym::Arabic_heh,
-        "Arabic_heh_doachashmee" => Keysym::Arabic_heh_doachashmee,
-        "Arabic_heh_goal" => Keysym::Arabic_heh_goal,
-        "Arabic_jeem" => Keysym::Arabic_jeem,
-        "Arabic_jeh" => Keysym::Arabic_jeh,
-        "Arabic_kaf" => Keysym::Arabic_kaf,
-        "Arabic_kasra" => Keysym::Arabic_kasra,
-        "Arabic_kasratan" => Keysym::Arabic_kasratan,
-        "Arabic_keheh" => Keysym::Arabic_keheh,
-        "Arabic_khah" => Keysym::Arabic_khah,
-        "Arabic_lam" => Keysym::Arabic_lam,
-        "Arabic_madda_above" => Keysym::Arabic_madda_above,
-        "Arabic_maddaonalef" => Keysym::Arabic_maddaonalef,
-        "Arabic_meem" => Keysym::Arabic_meem,
-        "Arabic_noon" => Keysym::Arabic_noon,
-        "Arabic_noon_ghunna" => Keysym::Arabic_noon_ghunna,
-        "Arabic_peh" => Keysym::Arabic_peh,
-        "Arabic_percent" => Keysym::Arabic_percent,
-        "Arabic_qaf" => Keysym::Arabic_qaf,
-        "Arabic_question_mark" => Keysym::Arabic_question_mark,
-        "Arabic_ra" => Keysym::Arabic_ra,
-        "Arabic_rreh" => Keysym::Arabic_rreh,
-        "Arabic_sad" => Keysym::Arabic_sad,
-        "Arabic_seen" => Keysym::Arabic_seen,
-        "Arabic_semicolon" => Keysym::Arabic_semicolon,
-        "Arabic_shadda" => Keysym::Arabic_shadda,
-        "Arabic_sheen" => Keysym::Arabic_sheen,
-        "Arabic_sukun" => Keysym::Arabic_sukun,
-        "Arabic_superscript_alef" => Keysym::Arabic_superscript_alef,
-        "Arabic_switch" => Keysym::Arabic_switch,
-        "Arabic_tah" => Keysym::Arabic_tah,
-        "Arabic_tatweel" => Keysym::Arabic_tatweel,
-        "Arabic_tcheh" => Keysym::Arabic_tcheh,
-        "Arabic_teh" => Keysym::Arabic_teh,
-        "Arabic_tehmarbuta" => Keysym::Arabic_tehmarbuta,
-        "Arabic_thal" => Keysym::Arabic_thal,
-        "Arabic_theh" => Keysym::Arabic_theh,
-        "Arabic_tteh" => Keysym::Arabic_tteh,
-        "Arabic_veh" => Keysym::Arabic_veh,
-        "Arabic_waw" => Keysym::Arabic_waw,
-        "Arabic_yeh" => Keysym::Arabic_yeh,
-        "Arabic_yeh_baree" => Keysym::Arabic_yeh_baree,
-        "Arabic_zah" => Keysym::Arabic_zah,
-        "Arabic_zain" => Keysym::Arabic_zain,
-        "Aring" => Keysym::Aring,
-        "aring" => Keysym::aring,
-        "Armenian_accent" => Keysym::Armenian_accent,
-        "Armenian_amanak" => Keysym::Armenian_amanak,
-        "Armenian_apostrophe" => Keysym::Armenian_apostrophe,
-        "Armenian_AT" => Keysym::Armenian_AT,
-        "Armenian_at" => Keysym::Armenian_at,
-        "Armenian_AYB" => Keysym::Armenian_AYB,
-        "Armenian_ayb" => Keysym::Armenian_ayb,
-        "Armenian_BEN" => Keysym::Armenian_BEN,
-        "Armenian_ben" => Keysym::Armenian_ben,
-        "Armenian_but" => Keysym::Armenian_but,
-        "Armenian_CHA" => Keysym::Armenian_CHA,
-        "Armenian_cha" => Keysym::Armenian_cha,
-        "Armenian_DA" => Keysym::Armenian_DA,
-        "Armenian_da" => Keysym::Armenian_da,
-        "Armenian_DZA" => Keysym::Armenian_DZA,
-        "Armenian_dza" => Keysym::Armenian_dza,
-        "Armenian_E" => Keysym::Armenian_E,
-        "Armenian_e" => Keysym::Armenian_e,
-        "Armenian_exclam" => Keysym::Armenian_exclam,
-        "Armenian_FE" => Keysym::Armenian_FE,
-        "Armenian_fe" => Keysym::Armenian_fe,
-        "Armenian_full_stop" => Keysym::Armenian_full_stop,
-        "Armenian_GHAT" => Keysym::Armenian_GHAT,
-        "Armenian_ghat" => Keysym::Armenian_ghat,
-        "Armenian_GIM" => Keysym::Armenian_GIM,
-        "Armenian_gim" => Keysym::Armenian_gim,
-        "Armenian_HI" => Keysym::Armenian_HI,
-        "Armenian_hi" => Keysym::Armenian_hi,
-        "Armenian_HO" => Keysym::Armenian_HO,
-        "Armenian_ho" => Keysym::Armenian_ho,
-        "Armenian_hyphen" => Keysym::Armenian_hyphen,
-        "Armenian_INI" => Keysym::Armenian_INI,
-        "Armenian_ini" => Keysym::Armenian_ini,
-        "Armenian_JE" => Keysym::Armenian_JE,
-        "Armenian_je" => Keysym::Armenian_je,
-        "Armenian_KE" => Keysym::Armenian_KE,
-        "Armenian_ke" => Keysym::Armenian_ke,
-        "Armenian_KEN" => Keysym::Armenian_KEN,
-        "Armenian_ken" => Keysym::Armenian_ken,
-        "Armenian_KHE" => Keysym::Armenian_KHE,
-        "Armenian_khe" => Keysym::Armenian_khe,
-        "Armenian_ligature_ew" => Keysym::Armenian_ligature_ew,
-        "Armenian_LYUN" => Keysym::Armenian_LYUN,
-        "Armenian_lyun" => Keysym::Armenian_lyun,
-        "Armenian_MEN" => Keysym::Armenian_MEN,
-        "Armenian_men" => Keysym::Armenian_men,
-        "Armenian_NU" => Keysym::Armenian_NU,
-        "Armenian_nu" => Keysym::Armenian_nu,
-        "Armenian_O" => Keysym::Armenian_O,
-        "Armenian_o" => Keysym::Armenian_o,
-        "Armenian_paruyk" => Keysym::Armenian_paruyk,
-        "Armenian_PE" => Keysym::Armenian_PE,
-        "Armenian_pe" => Keysym::Armenian_pe,
-        "Armenian_PYUR" => Keysym::Armenian_PYUR,
-        "Armenian_pyur" => Keysym::Armenian_pyur,
-        "Armenian_question" => Keysym::Armenian_question,
-        "Armenian_RA" => Keysym::Armenian_RA,
-        "Armenian_ra" => Keysym::Armenian_ra,
-        "Armenian_RE" => Keysym::Armenian_RE,
-        "Armenian_re" => Keysym::Armenian_re,
-        "Armenian_SE" => Keysym::Armenian_SE,
-        "Armenian_se" => Keysym::Armenian_se,
-        "Armenian_separation_mark" => Keysym::Armenian_separation_mark,
-        "Armenian_SHA" => Keysym::Armenian_SHA,
-        "Armenian_sha" => Keysym::Armenian_sha,
-        "Armenian_shesht" => Keysym::Armenian_shesht,
-        "Armenian_TCHE" => Keysym::Armenian_TCHE,
-        "Armenian_tche" => Keysym::Armenian_tche,
-        "Armenian_TO" => Keysym::Armenian_TO,
-        "Armenian_to" => Keysym::Armenian_to,
-        "Armenian_TSA" => Keysym::Armenian_TSA,
-        "Armenian_tsa" => Keysym::Armenian_tsa,
-        "Armenian_TSO" => Keysym::Armenian_TSO,
-        "Armenian_tso" => Keysym::Armenian_tso,
-        "Armenian_TYUN" => Keysym::Armenian_TYUN,
-        "Armenian_tyun" => Keysym::Armenian_tyun,
-        "Armenian_verjaket" => Keysym::Armenian_verjaket,
-        "Armenian_VEV" => Keysym::Armenian_VEV,
-        "Armenian_vev" => Keysym::Armenian_vev,
-        "Armenian_VO" => Keysym::Armenian_VO,
-        "Armenian_vo" => Keysym::Armenian_vo,
-        "Armenian_VYUN" => Keysym::Armenian_VYUN,
-        "Armenian_vyun" => Keysym::Armenian_vyun,
-        "Armenian_YECH" => Keysym::Armenian_YECH,
-        "Armenian_yech" => Keysym::Armenian_yech,
-        "Armenian_yentamna" => Keysym::Armenian_yentamna,
-        "Armenian_ZA" => Keysym::Armenian_ZA,
-        "Armenian_za" => Keysym::Armenian_za,
-        "Armenian_ZHE" => Keysym::Armenian_ZHE,
-        "Armenian_zhe" => Keysym::Armenian_zhe,
-        "asciicircum" => Keysym::asciicircum,
-        "asciitilde" => Keysym::asciitilde,
-        "asterisk" => Keysym::asterisk,
-        "at" => Keysym::at,
-        "Atilde" => Keysym::Atilde,
-        "atilde" => Keysym::atilde,
-        "AudibleBell_Enable" => Keysym::AudibleBell_Enable,
-        "B" => Keysym::B,
-        "b" => Keysym::b,
-        "Babovedot" => Keysym::Babovedot,
-        "babovedot" => Keysym::babovedot,
-        "backslash" => Keysym::backslash,
-        "BackSpace" => Keysym::BackSpace,
-        "BackTab" => Keysym::BackTab,
-        "ballotcross" => Keysym::ballotcross,
-        "bar" => Keysym::bar,
-        "because" => Keysym::because,
-        "Begin" => Keysym::Begin,
-        "blank" => Keysym::blank,
-        "block" => Keysym::block,
-        "botintegral" => Keysym::botintegral,
-        "botleftparens" => Keysym::botleftparens,
-        "botleftsqbracket" => Keysym::botleftsqbracket,
-        "botleftsummation" => Keysym::botleftsummation,
-        "botrightparens" => Keysym::botrightparens,
-        "botrightsqbracket" => Keysym::botrightsqbracket,
-        "botrightsummation" => Keysym::botrightsummation,
-        "bott" => Keysym::bott,
-        "botvertsummationconnector" => Keysym::botvertsummationconnector,
-        "BounceKeys_Enable" => Keysym::BounceKeys_Enable,
-        "braceleft" => Keysym::braceleft,
-        "braceright" => Keysym::braceright,
-        "bracketleft" => Keysym::bracketleft,
-        "bracketright" => Keysym::bracketright,
-        "braille_blank" => Keysym::braille_blank,
-        "braille_dot_1" => Keysym::braille_dot_1,
-        "braille_dot_10" => Keysym::braille_dot_10,
-        "braille_dot_2" => Keysym::braille_dot_2,
-        "braille_dot_3" => Keysym::braille_dot_3,
-        "braille_dot_4" => Keysym::braille_dot_4,
-        "braille_dot_5" => Keysym::braille_dot_5,
-        "braille_dot_6" => Keysym::braille_dot_6,
-        "braille_dot_7" => Keysym::braille_dot_7,
-        "braille_dot_8" => Keysym::braille_dot_8,
-        "braille_dot_9" => Keysym::braille_dot_9,
-        "braille_dots_1" => Keysym::braille_dots_1,
-        "braille_dots_12" => Keysym::braille_dots_12,
-        "braille_dots_123" => Keysym::braille_dots_123,
-        "braille_dots_1234" => Keysym::braille_dots_1234,
-        "braille_dots_12345" => Keysym::braille_dots_12345,
-        "braille_dots_123456" => Keysym::braille_dots_123456,
-        "braille_dots_1234567" => Keysym::braille_dots_1234567,
-        "braille_dots_12345678" => Keysym::braille_dots_12345678,
-        "braille_dots_1234568" => Keysym::braille_dots_1234568,
-        "braille_dots_123457" => Keysym::braille_dots_123457,
-        "braille_dots_1234578" => Keysym::braille_dots_1234578,
-        "braille_dots_123458" => Keysym::braille_dots_123458,
-        "braille_dots_12346" => Keysym::braille_dots_12346,
-        "braille_dots_123467" => Keysym::braille_dots_123467,
-        "braille_dots_1234678" => Keysym::braille_dots_1234678,
-        "braille_dots_123468" => Keysym::braille_dots_123468,
-        "braille_dots_12347" => Keysym::braille_dots_12347,
-        "braille_dots_123478" => Keysym::braille_dots_123478,
-        "braille_dots_12348" => Keysym::braille_dots_12348,
-        "braille_dots_1235" => Keysym::braille_dots_1235,
-        "braille_dots_12356" => Keysym::braille_dots_12356,
-        "braille_dots_123567" => Keysym::braille_dots_123567,
-        "braille_dots_1235678" => Keysym::braille_dots_1235678,
-        "braille_dots_123568" => Keysym::braille_dots_123568,
-        "braille_dots_12357" => Keysym::braille_dots_12357,
-        "braille_dots_123578" => Keysym::braille_dots_123578,
-        "braille_dots_12358" => Keysym::braille_dots_12358,
-        "braille_dots_1236" => Keysym::braille_dots_1236,
-        "braille_dots_12367" => Keysym::braille_dots_12367,
-        "braille_dots_123678" => Keysym::braille_dots_123678,
-        "braille_dots_12368" => Keysym::braille_dots_12368,
-        "braille_dots_1237" => Keysym::braille_dots_1237,
-        "braille_dots_12378" => Keysym::braille_dots_12378,
-        "braille_dots_1238" => Keysym::braille_dots_1238,
-        "braille_dots_124" => Keysym::braille_dots_124,
-        "braille_dots_1245" => Keysym::braille_dots_1245,
-        "braille_dots_12456" => Keysym::braille_dots_12456,
-        "braille_dots_124567" => Keysym::braille_dots_124567,
-        "braille_dots_1245678" => Keysym::braille_dots_1245678,
-        "braille_dots_124568" => Keysym::braille_dots_124568,
-        "braille_dots_12457" => Keysym::braille_dots_12457,
-        "braille_dots_124578" => Keysym::braille_dots_124578,
-        "braille_dots_12458" => Keysym::braille_dots_12458,
-        "braille_dots_1246" => Keysym::braille_dots_1246,
-        "braille_dots_12467" => Keysym::braille_dots_12467,
-        "braille_dots_124678" => Keysym::braille_dots_124678,
-        "braille_dots_12468" => Keysym::braille_dots_12468,
-        "braille_dots_1247" => Keysym::braille_dots_1247,
-        "braille_dots_12478" => Keysym::braille_dots_12478,
-        "braille_dots_1248" => Keysym::braille_dots_1248,
-        "braille_dots_125" => Keysym::braille_dots_125,
-        "braille_dots_1256" => Keysym::braille_dots_1256,
-        "braille_dots_12567" => Keysym::braille_dots_12567,
-        "braille_dots_125678" => Keysym::braille_dots_125678,
-        "braille_dots_12568" => Keysym::braille_dots_12568,
-        "braille_dots_1257" => Keysym::braille_dots_1257,
-        "braille_dots_12578" => Keysym::braille_dots_12578,
-        "braille_dots_1258" => Keysym::braille_dots_1258,
-        "braille_dots_126" => Keysym::braille_dots_126,
-        "braille_dots_1267" => Keysym::braille_dots_1267,
-        "braille_dots_12678" => Keysym::braille_dots_12678,
-        "braille_dots_1268" => Keysym::braille_dots_1268,
-        "braille_dots_127" => Keysym::braille_dots_127,
-        "braille_dots_1278" => Keysym::braille_dots_1278,
-        "braille_dots_128" => Keysym::braille_dots_128,
-        "braille_dots_13" => Keysym::braille_dots_13,
-        "braille_dots_134" => Keysym::braille_dots_134,
-        "braille_dots_1345" => Keysym::braille_dots_1345,
-        "braille_dots_13456" => Keysym::braille_dots_13456,
-        "braille_dots_134567" => Keysym::braille_dots_134567,
-        "braille_dots_1345678" => Keysym::braille_dots_1345678,
-        "braille_dots_134568" => Keysym::braille_dots_134568,
-        "braille_dots_13457" => Keysym::braille_dots_13457,
-        "braille_dots_134578" => Keysym::braille_dots_134578,
-        "braille_dots_13458" => Keysym::braille_dots_13458,
-        "braille_dots_1346" => Keysym::braille_dots_1346,
-        "braille_dots_13467" => Keysym::braille_dots_13467,
-        "braille_dots_134678" => Keysym::braille_dots_134678,
-        "braille_dots_13468" => Keysym::braille_dots_13468,
-        "braille_dots_1347" => Keysym::braille_dots_1347,
-        "braille_dots_13478" => Keysym::braille_dots_13478,
-        "braille_dots_1348" => Keysym::braille_dots_1348,
-        "braille_dots_135" => Keysym::braille_dots_135,
-        "braille_dots_1356" => Keysym::braille_dots_1356,
-        "braille_dots_13567" => Keysym::braille_dots_13567,
-        "braille_dots_135678" => Keysym::braille_dots_135678,
-        "braille_dots_13568" => Keysym::braille_dots_13568,
-        "braille_dots_1357" => Keysym::braille_dots_1357,
-        "braille_dots_13578" => Keysym::braille_dots_13578,
-        "braille_dots_1358" => Keysym::braille_dots_1358,
-        "braille_dots_136" => Keysym::braille_dots_136,
-        "braille_dots_1367" => Keysym::braille_dots_1367,
-        "braille_dots_13678" => Keysym::braille_dots_13678,
-        "braille_dots_1368" => Keysym::braille_dots_1368,
-        "braille_dots_137" => Keysym::braille_dots_137,
-        "braille_dots_1378" => Keysym::braille_dots_1378,
-        "braille_dots_138" => Keysym::braille_dots_138,
-        "braille_dots_14" => Keysym::braille_dots_14,
-        "braille_dots_145" => Keysym::braille_dots_145,
-        "braille_dots_1456" => Keysym::braille_dots_1456,
-        "braille_dots_14567" => Keysym::braille_dots_14567,
-        "braille_dots_145678" => Keysym::braille_dots_145678,
-        "braille_dots_14568" => Keysym::braille_dots_14568,
-        "braille_dots_1457" => Keysym::braille_dots_1457,
-        "braille_dots_14578" => Keysym::braille_dots_14578,
-        "braille_dots_1458" => Keysym::braille_dots_1458,
-        "braille_dots_146" => Keysym::braille_dots_146,
-        "braille_dots_1467" => Keysym::braille_dots_1467,
-        "braille_dots_14678" => Keysym::braille_dots_14678,
-        "braille_dots_1468" => Keysym::braille_dots_1468,
-        "braille_dots_147" => Keysym::braille_dots_147,
-        "braille_dots_1478" => Keysym::braille_dots_1478,
-        "braille_dots_148" => Keysym::braille_dots_148,
-        "braille_dots_15" => Keysym::braille_dots_15,
-        "braille_dots_156" => Keysym::braille_dots_156,
-        "braille_dots_1567" => Keysym::braille_dots_1567,
-        "braille_dots_15678" => Keysym::braille_dots_15678,
-        "braille_dots_1568" => Keysym::braille_dots_1568,
-        "braille_dots_157" => Keysym::braille_dots_157,
-        "braille_dots_1578" => Keysym::braille_dots_1578,
-        "braille_dots_158" => Keysym::braille_dots_158,
-        "braille_dots_16" => Keysym::braille_dots_16,
-        "braille_dots_167" => Keysym::braille_dots_167,
-        "braille_dots_1678" => Keysym::braille_dots_1678,
-        "braille_dots_168" => Keysym::braille_dots_168,
-        "braille_dots_17" => Keysym::braille_dots_17,
-        "braille_dots_178" => Keysym::braille_dots_178,
-        "braille_dots_18" => Keysym::braille_dots_18,
-        "braille_dots_2" => Keysym::braille_dots_2,
-        "braille_dots_23" => Keysym::braille_dots_23,
-        "braille_dots_234" => Keysym::braille_dots_234,
-        "braille_dots_2345" => Keysym::braille_dots_2345,
-        "braille_dots_23456" => Keysym::braille_dots_23456,
-        "braille_dots_234567" => Keysym::braille_dots_234567,
-        "braille_dots_2345678" => Keysym::braille_dots_2345678,
-        "braille_dots_234568" => Keysym::braille_dots_234568,
-        "braille_dots_23457" => Keysym::braille_dots_23457,
-        "braille_dots_234578" => Keysym::braille_dots_234578,
-        "braille_dots_23458" => Keysym::braille_dots_23458,
-        "braille_dots_2346" => Keysym::braille_dots_2346,
-        "braille_dots_23467" => Keysym::braille_dots_23467,
-        "braille_dots_234678" => Keysym::braille_dots_234678,
-        "braille_dots_23468" => Keysym::braille_dots_23468,
-        "braille_dots_2347" => Keysym::braille_dots_2347,
-        "braille_dots_23478" => Keysym::braille_dots_23478,
-        "braille_dots_2348" => Keysym::braille_dots_2348,
-        "braille_dots_235" => Keysym::braille_dots_235,
-        "braille_dots_2356" => Keysym::braille_dots_2356,
-        "braille_dots_23567" => Keysym::braille_dots_23567,
-        "braille_dots_235678" => Keysym::braille_dots_235678,
-        "braille_dots_23568" => Keysym::braille_dots_23568,
-        "braille_dots_2357" => Keysym::braille_dots_2357,
-        "braille_dots_23578" => Keysym::braille_dots_23578,
-        "braille_dots_2358" => Keysym::braille_dots_2358,
-        "braille_dots_236" => Keysym::braille_dots_236,
-        "braille_dots_2367" => Keysym::braille_dots_2367,
-        "braille_dots_23678" => Keysym::braille_dots_23678,
-        "braille_dots_2368" => Keysym::braille_dots_2368,
-        "braille_dots_237" => Keysym::braille_dots_237,
-        "braille_dots_2378" => Keysym::braille_dots_2378,
-        "braille_dots_238" => Keysym::braille_dots_238,
-        "braille_dots_24" => Keysym::braille_dots_24,
-        "braille_dots_245" => Keysym::braille_dots_245,
-        "braille_dots_2456" => Keysym::braille_dots_2456,
-        "braille_dots_24567" => Keysym::braille_dots_24567,
-        "braille_dots_245678" => Keysym::braille_dots_245678,
-        "braille_dots_24568" => Keysym::braille_dots_24568,
-        "braille_dots_2457" => Keysym::braille_dots_2457,
-        "braille_dots_24578" => Keysym::braille_dots_24578,
-        "braille_dots_2458" => Keysym::braille_dots_2458,
-        "braille_dots_246" => Keysym::braille_dots_246,
-        "braille_dots_2467" => Keysym::braille_dots_2467,
-        "braille_dots_24678" => Keysym::braille_dots_24678,
-        "braille_dots_2468" => Keysym::braille_dots_2468,
-        "braille_dots_247" => Keysym::braille_dots_247,
-        "braille_dots_2478" => Keysym::braille_dots_2478,
-        "braille_dots_248" => Keysym::braille_dots_248,
-        "braille_dots_25" => Keysym::braille_dots_25,
-        "braille_dots_256" => Keysym::braille_dots_256,
-        "braille_dots_2567" => Keysym::braille_dots_2567,
-        "braille_dots_25678" => Keysym::braille_dots_25678,
-        "braille_dots_2568" => Keysym::braille_dots_2568,
-        "braille_dots_257" => Keysym::braille_dots_257,
-        "braille_dots_2578" => Keysym::braille_dots_2578,
-        "braille_dots_258" => Keysym::braille_dots_258,
-        "braille_dots_26" => Keysym::braille_dots_26,
-        "braille_dots_267" => Keysym::braille_dots_267,
-        "braille_dots_2678" => Keysym::braille_dots_2678,
-        "braille_dots_268" => Keysym::braille_dots_268,
-        "braille_dots_27" => Keysym::braille_dots_27,
-        "braille_dots_278" => Keysym::braille_dots_278,
-        "braille_dots_28" => Keysym::braille_dots_28,
-        "braille_dots_3" => Keysym::braille_dots_3,
-        "braille_dots_34" => Keysym::braille_dots_34,
-        "braille_dots_345" => Keysym::braille_dots_345,
-        "braille_dots_3456" => Keysym::braille_dots_3456,
-        "braille_dots_34567" => Keysym::braille_dots_34567,
-        "braille_dots_345678" => Keysym::braille_dots_345678,
-        "braille_dots_34568" => Keysym::braille_dots_34568,
-        "braille_dots_3457" => Keysym::braille_dots_3457,
-        "braille_dots_34578" => Keysym::braille_dots_34578,
-        "braille_dots_3458" => Keysym::braille_dots_3458,
-        "braille_dots_346" => Keysym::braille_dots_346,
-        "braille_dots_3467" => Keysym::braille_dots_3467,
-        "braille_dots_34678" => Keysym::braille_dots_34678,
-        "braille_dots_3468" => Keysym::braille_dots_3468,
-        "braille_dots_347" => Keysym::braille_dots_347,
-        "braille_dots_3478" => Keysym::braille_dots_3478,
-        "braille_dots_348" => Keysym::braille_dots_348,
-        "braille_dots_35" => Keysym::braille_dots_35,
-        "braille_dots_356" => Keysym::braille_dots_356,
-        "braille_dots_3567" => Keysym::braille_dots_3567,
-        "braille_dots_35678" => Keysym::braille_dots_35678,
-        "braille_dots_3568" => Keysym::braille_dots_3568,
-        "braille_dots_357" => Keysym::braille_dots_357,
-        "braille_dots_3578" => Keysym::braille_dots_3578,
-        "braille_dots_358" => Keysym::braille_dots_358,
-        "braille_dots_36" => Keysym::braille_dots_36,
-        "braille_dots_367" => Keysym::braille_dots_367,
-        "braille_dots_3678" => Keysym::braille_dots_3678,
-        "braille_dots_368" => Keysym::braille_dots_368,
-        "braille_dots_37" => Keysym::braille_dots_37,
-        "braille_dots_378" => Keysym::braille_dots_378,
-        "braille_dots_38" => Keysym::braille_dots_38,
-        "braille_dots_4" => Keysym::braille_dots_4,
-        "braille_dots_45" => Keysym::braille_dots_45,
-        "braille_dots_456" => Keysym::braille_dots_456,
-        "braille_dots_4567" => Keysym::braille_dots_4567,
-        "braille_dots_45678" => Keysym::braille_dots_45678,
-        "braille_dots_4568" => Keysym::braille_dots_4568,
-        "braille_dots_457" => Keysym::braille_dots_457,
-        "braille_dots_4578" => Keysym::braille_dots_4578,
-        "braille_dots_458" => Keysym::braille_dots_458,
-        "braille_dots_46" => Keysym::braille_dots_46,
-        "braille_dots_467" => Keysym::braille_dots_467,
-        "braille_dots_4678" => Keysym::braille_dots_4678,
-        "braille_dots_468" => Keysym::braille_dots_468,
-        "braille_dots_47" => Keysym::braille_dots_47,
-        "braille_dots_478" => Keysym::braille_dots_478,
-        "braille_dots_48" => Keysym::braille_dots_48,
-        "braille_dots_5" => Keysym::braille_dots_5,
-        "braille_dots_56" => Keysym::braille_dots_56,
-        "braille_dots_567" => Keysym::braille_dots_567,
-        "braille_dots_5678" => Keysym::braille_dots_5678,
-        "braille_dots_568" => Keysym::braille_dots_568,
-        "braille_dots_57" => Keysym::braille_dots_57,
-        "braille_dots_578" => Keysym::braille_dots_578,
-        "braille_dots_58" => Keysym::braille_dots_58,
-        "braille_dots_6" => Keysym::braille_dots_6,
-        "braille_dots_67" => Keysym::braille_dots_67,
-        "braille_dots_678" => Keysym::braille_dots_678,
-        "braille_dots_68" => Keysym::braille_dots_68,
-        "braille_dots_7" => Keysym::braille_dots_7,
-        "braille_dots_78" => Keysym::braille_dots_78,
-        "braille_dots_8" => Keysym::braille_dots_8,
-        "Break" => Keysym::Break,
-        "breve" => Keysym::breve,
-        "brokenbar" => Keysym::brokenbar,
-        "Byelorussian_SHORTU" => Keysym::Byelorussian_SHORTU,
-        "Byelorussian_shortu" => Keysym::Byelorussian_shortu,
-        "C" => Keysym::C,
-        "c" => Keysym::c,
-        "C_H" => Keysym::C_H,
-        "C_h" => Keysym::C_h,
-        "c_h" => Keysym::c_h,
-        "Cabovedot" => Keysym::Cabovedot,
-        "cabovedot" => Keysym::cabovedot,
-        "Cacute" => Keysym::Cacute,
-        "cacute" => Keysym::cacute,
-        "Cancel" => Keysym::Cancel,
-        "Caps_Lock" => Keysym::Caps_Lock,
-        "careof" => Keysym::careof,
-        "caret" => Keysym::caret,
-        "caron" => Keysym::caron,
-        "Ccaron" => Keysym::Ccaron,
-        "ccaron" => Keysym::ccaron,
-        "Ccedilla" => Keysym::Ccedilla,
-        "ccedilla" => Keysym::ccedilla,
-        "Ccircumflex" => Keysym::Ccircumflex,
-        "ccircumflex" => Keysym::ccircumflex,
-        "cedilla" => Keysym::cedilla,
-        "cent" => Keysym::cent,
-        "CH" => Keysym::CH,
-        "Ch" => Keysym::Ch,
-        "ch" => Keysym::ch,
-        "checkerboard" => Keysym::checkerboard,
-        "checkmark" => Keysym::checkmark,
-        "circle" => Keysym::circle,
-        "Clear" => Keysym::Clear,
-        "ClearLine" => Keysym::ClearLine,
-        "club" => Keysym::club,
-        "Codeinput" => Keysym::Codeinput,
-        "colon" => Keysym::colon,
-        "ColonSign" => Keysym::ColonSign,
-        "combining_acute" => Keysym::combining_acute,
-        "combining_belowdot" => Keysym::combining_belowdot,
-        "combining_grave" => Keysym::combining_grave,
-        "combining_hook" => Keysym::combining_hook,
-        "combining_tilde" => Keysym::combining_tilde,
-        "comma" => Keysym::comma,
-        "containsas" => Keysym::containsas,
-        "Control_L" => Keysym::Control_L,
-        "Control_R" => Keysym::Control_R,
-        "copyright" => Keysym::copyright,
-        "cr" => Keysym::cr,
-        "crossinglines" => Keysym::crossinglines,
-        "CruzeiroSign" => Keysym::CruzeiroSign,
-        "cuberoot" => Keysym::cuberoot,
-        "currency" => Keysym::currency,
-        "cursor" => Keysym::cursor,
-        "Cyrillic_A" => Keysym::Cyrillic_A,
-        "Cyrillic_a" => Keysym::Cyrillic_a,
-        "Cyrillic_BE" => Keysym::Cyrillic_BE,
-        "Cyrillic_be" => Keysym::Cyrillic_be,
-        "Cyrillic_CHE" => Keysym::Cyrillic_CHE,
-        "Cyrillic_che" => Keysym::Cyrillic_che,
-        "Cyrillic_CHE_descender" => Keysym::Cyrillic_CHE_descender,
-        "Cyrillic_che_descender" => Keysym::Cyrillic_che_descender,
-        "Cyrillic_CHE_vertstroke" => Keysym::Cyrillic_CHE_vertstroke,
-        "Cyrillic_che_vertstroke" => Keysym::Cyrillic_che_vertstroke,
-        "Cyrillic_DE" => Keysym::Cyrillic_DE,
-        "Cyrillic_de" => Keysym::Cyrillic_de,
-        "Cyrillic_DZHE" => Keysym::Cyrillic_DZHE,
-        "Cyrillic_dzhe" => Keysym::Cyrillic_dzhe,
-        "Cyrillic_E" => Keysym::Cyrillic_E,
-        "Cyrillic_e" => Keysym::Cyrillic_e,
-        "Cyrillic_EF" => Keysym::Cyrillic_EF,
-        "Cyrillic_ef" => Keysym::Cyrillic_ef,
-        "Cyrillic_EL" => Keysym::Cyrillic_EL,
-        "Cyrillic_el" => Keysym::Cyrillic_el,
-        "Cyrillic_EM" => Keysym::Cyrillic_EM,
-        "Cyrillic_em" => Keysym::Cyrillic_em,
-        "Cyrillic_EN" => Keysym::Cyrillic_EN,
-        "Cyrillic_en" => Keysym::Cyrillic_en,
-        "Cyrillic_EN_descender" => Keysym::Cyrillic_EN_descender,
-        "Cyrillic_en_descender" => Keysym::Cyrillic_en_descender,
-        "Cyrillic_ER" => Keysym::Cyrillic_ER,
-        "Cyrillic_er" => Keysym::Cyrillic_er,
-        "Cyrillic_ES" => Keysym::Cyrillic_ES,
-        "Cyrillic_es" => Keysym::Cyrillic_es,
-        "Cyrillic_GHE" => Keysym::Cyrillic_GHE,
-        "Cyrillic_ghe" => Keysym::Cyrillic_ghe,
-        "Cyrillic_GHE_bar" => Keysym::Cyrillic_GHE_bar,
-        "Cyrillic_ghe_bar" => Keysym::Cyrillic_ghe_bar,
-        "Cyrillic_HA" => Keysym::Cyrillic_HA,
-        "Cyrillic_ha" => Keysym::Cyrillic_ha,
-        "Cyrillic_HA_descender" => Keysym::Cyrillic_HA_descender,
-        "Cyrillic_ha_descender" => Keysym::Cyrillic_ha_descender,
-        "Cyrillic_HARDSIGN" => Keysym::Cyrillic_HARDSIGN,
-        "Cyrillic_hardsign" => Keysym::Cyrillic_hardsign,
-        "Cyrillic_I" => Keysym::Cyrillic_I,
-        "Cyrillic_i" => Keysym::Cyrillic_i,
-        "Cyrillic_I_macron" => Keysym::Cyrillic_I_macron,
-        "Cyrillic_i_macron" => Keysym::Cyrillic_i_macron,
-        "Cyrillic_IE" => Keysym::Cyrillic_IE,
-        "Cyrillic_ie" => Keysym::Cyrillic_ie,
-        "Cyrillic_IO" => Keysym::Cyrillic_IO,
-        "Cyrillic_io" => Keysym::Cyrillic_io,
-        "Cyrillic_JE" => Keysym::Cyrillic_JE,
-        "Cyrillic_je" => Keysym::Cyrillic_je,
-        "Cyrillic_KA" => Keysym::Cyrillic_KA,
-        "Cyrillic_ka" => Keysym::Cyrillic_ka,
-        "Cyrillic_KA_descender" => Keysym::Cyrillic_KA_descender,
-        "Cyrillic_ka_descender" => Keysym::Cyrillic_ka_descender,
-        "Cyrillic_KA_vertstroke" => Keysym::Cyrillic_KA_vertstroke,
-        "Cyrillic_ka_vertstroke" => Keysym::Cyrillic_ka_vertstroke,
-        "Cyrillic_LJE" => Keysym::Cyrillic_LJE,
-        "Cyrillic_lje" => Keysym::Cyrillic_lje,
-        "Cyrillic_NJE" => Keysym::Cyrillic_NJE,
-        "Cyrillic_nje" => Keysym::Cyrillic_nje,
-        "Cyrillic_O" => Keysym::Cyrillic_O,
-        "Cyrillic_o" => Keysym::Cyrillic_o,
-        "Cyrillic_O_bar" => Keysym::Cyrillic_O_bar,
-        "Cyrillic_o_bar" => Keysym::Cyrillic_o_bar,
-        "Cyrillic_PE" => Keysym::Cyrillic_PE,
-        "Cyrillic_pe" => Keysym::Cyrillic_pe,
-        "Cyrillic_SCHWA" => Keysym::Cyrillic_SCHWA,
-        "Cyrillic_schwa" => Keysym::Cyrillic_schwa,
-        "Cyrillic_SHA" => Keysym::Cyrillic_SHA,
-        "Cyrillic_sha" => Keysym::Cyrillic_sha,
-        "Cyrillic_SHCHA" => Keysym::Cyrillic_SHCHA,
-        "Cyrillic_shcha" => Keysym::Cyrillic_shcha,
-        "Cyrillic_SHHA" => Keysym::Cyrillic_SHHA,
-        "Cyrillic_shha" => Keysym::Cyrillic_shha,
-        "Cyrillic_SHORTI" => Keysym::Cyrillic_SHORTI,
-        "Cyrillic_shorti" => Keysym::Cyrillic_shorti,
-        "Cyrillic_SOFTSIGN" => Keysym::Cyrillic_SOFTSIGN,
-        "Cyrillic_softsign" => Keysym::Cyrillic_softsign,
-        "Cyrillic_TE" => Keysym::Cyrillic_TE,
-        "Cyrillic_te" => Keysym::Cyrillic_te,
-        "Cyrillic_TSE" => Keysym::Cyrillic_TSE,
-        "Cyrillic_tse" => Keysym::Cyrillic_tse,
-        "Cyrillic_U" => Keysym::Cyrillic_U,
-        "Cyrillic_u" => Keysym::Cyrillic_u,
-        "Cyrillic_U_macron" => Keysym::Cyrillic_U_macron,
-        "Cyrillic_u_macron" => Keysym::Cyrillic_u_macron,
-        "Cyrillic_U_straight" => Keysym::Cyrillic_U_straight,
-        "Cyrillic_u_straight" => Keysym::Cyrillic_u_straight,
-        "Cyrillic_U_straight_bar" => Keysym::Cyrillic_U_straight_bar,
-        "Cyrillic_u_straight_bar" => Keysym::Cyrillic_u_straight_bar,
-        "Cyrillic_VE" => Keysym::Cyrillic_VE,
-        "Cyrillic_ve" => Keysym::Cyrillic_ve,
-        "Cyrillic_YA" => Keysym::Cyrillic_YA,
-        "Cyrillic_ya" => Keysym::Cyrillic_ya,
-        "Cyrillic_YERU" => Keysym::Cyrillic_YERU,
-        "Cyrillic_yeru" => Keysym::Cyrillic_yeru,
-        "Cyrillic_YU" => Keysym::Cyrillic_YU,
-        "Cyrillic_yu" => Keysym::Cyrillic_yu,
-        "Cyrillic_ZE" => Keysym::Cyrillic_ZE,
-        "Cyrillic_ze" => Keysym::Cyrillic_ze,
-        "Cyrillic_ZHE" => Keysym::Cyrillic_ZHE,
-        "Cyrillic_zhe" => Keysym::Cyrillic_zhe,
-        "Cyrillic_ZHE_descender" => Keysym::Cyrillic_ZHE_descender,
-        "Cyrillic_zhe_descender" => Keysym::Cyrillic_zhe_descender,
-        "D" => Keysym::D,
-        "d" => Keysym::d,
-        "Dabovedot" => Keysym::Dabovedot,
-        "dabovedot" => Keysym::dabovedot,
-        "Dacute_accent" => Keysym::D_acute_accent,
-        "dagger" => Keysym::dagger,
-        "Dcaron" => Keysym::Dcaron,
-        "dcaron" => Keysym::dcaron,
-        "Dcedilla_accent" => Keysym::D_cedilla_accent,
-        "Dcircumflex_accent" => Keysym::D_circumflex_accent,
-        "Ddiaeresis" => Keysym::D_diaeresis,
-        "dead_A" => Keysym::dead_A,
-        "dead_a" => Keysym::dead_a,
-        "dead_abovecomma" => Keysym::dead_abovecomma,
-        "dead_abovedot" => Keysym::dead_abovedot,
-        "dead_abovereversedcomma" => Keysym::dead_abovereversedcomma,
-        "dead_abovering" => Keysym::dead_abovering,
-        "dead_aboveverticalline" => Keysym::dead_aboveverticalline,
-        "dead_acute" => Keysym::dead_acute,
-        "dead_belowbreve" => Keysym::dead_belowbreve,
-        "dead_belowcircumflex" => Keysym::dead_belowcircumflex,
-        "dead_belowcomma" => Keysym::dead_belowcomma,
-        "dead_belowdiaeresis" => Keysym::dead_belowdiaeresis,
-        "dead_belowdot" => Keysym::dead_belowdot,
-        "dead_belowmacron" => Keysym::dead_belowmacron,
-        "dead_belowring" => Keysym::dead_belowring,
-        "dead_belowtilde" => Keysym::dead_belowtilde,
-        "dead_belowverticalline" => Keysym::dead_belowverticalline,
-        "dead_breve" => Keysym::dead_breve,
-        "dead_capital_schwa" => Keysym::dead_capital_schwa,
-        "dead_caron" => Keysym::dead_caron,
-        "dead_cedilla" => Keysym::dead_cedilla,
-        "dead_circumflex" => Keysym::dead_circumflex,
-        "dead_currency" => Keysym::dead_currency,
-        "dead_dasia" => Keysym::dead_dasia,
-        "dead_diaeresis" => Keysym::dead_diaeresis,
-        "dead_doubleacute" => Keysym::dead_doubleacute,
-        "dead_doublegrave" => Keysym::dead_doublegrave,
-        "dead_E" => Keysym::dead_E,
-        "dead_e" => Keysym::dead_e,
-        "dead_grave" => Keysym::dead_grave,
-        "dead_greek" => Keysym::dead_greek,
-        "dead_hamza" => Keysym::dead_hamza,
-        "dead_hook" => Keysym::dead_hook,
-        "dead_horn" => Keysym::dead_horn,
-        "dead_I" => Keysym::dead_I,
-        "dead_i" => Keysym::dead_i,
-        "dead_invertedbreve" => Keysym::dead_invertedbreve,
-        "dead_iota" => Keysym::dead_iota,
-        "dead_longsolidusoverlay" => Keysym::dead_longsolidusoverlay,
-        "dead_lowline" => Keysym::dead_lowline,
-        "dead_macron" => Keysym::dead_macron,
-        "dead_O" => Keysym::dead_O,
-        "dead_o" => Keysym::dead_o,
-        "dead_ogonek" => Keysym::dead_ogonek,
-        "dead_perispomeni" => Keysym::dead_perispomeni,
-        "dead_psili" => Keysym::dead_psili,
-        "dead_SCHWA" => Keysym::dead_SCHWA,
-        "dead_schwa" => Keysym::dead_schwa,
-        "dead_semivoiced_sound" => Keysym::dead_semivoiced_sound,
-        "dead_small_schwa" => Keysym::dead_small_schwa,
-        "dead_stroke" => Keysym::dead_stroke,
-        "dead_tilde" => Keysym::dead_tilde,
-        "dead_U" => Keysym::dead_U,
-        "dead_u" => Keysym::dead_u,
-        "dead_voiced_sound" => Keysym::dead_voiced_sound,
-        "decimalpoint" => Keysym::decimalpoint,
-        "degree" => Keysym::degree,
-        "Delete" => Keysym::Delete,
-        "DeleteChar" => Keysym::DeleteChar,
-        "DeleteLine" => Keysym::DeleteLine,
-        "Dgrave_accent" => Keysym::D_grave_accent,
-        "diaeresis" => Keysym::diaeresis,
-        "diamond" => Keysym::diamond,
-        "digitspace" => Keysym::digitspace,
-        "dintegral" => Keysym::dintegral,
-        "division" => Keysym::division,
-        "dollar" => Keysym::dollar,
-        "DongSign" => Keysym::DongSign,
-        "doubbaselinedot" => Keysym::doubbaselinedot,
-        "doubleacute" => Keysym::doubleacute,
-        "doubledagger" => Keysym::doubledagger,
-        "doublelowquotemark" => Keysym::doublelowquotemark,
-        "Down" => Keysym::Down,
-        "downarrow" => Keysym::downarrow,
-        "downcaret" => Keysym::downcaret,
-        "downshoe" => Keysym::downshoe,
-        "downstile" => Keysym::downstile,
-        "downtack" => Keysym::downtack,
-        "DRemove" => Keysym::D_Remove,
-        "Dring_accent" => Keysym::D_ring_accent,
-        "Dstroke" => Keysym::Dstroke,
-        "dstroke" => Keysym::dstroke,
-        "Dtilde" => Keysym::D_tilde,
-        "E" => Keysym::E,
-        "e" => Keysym::e,
-        "Eabovedot" => Keysym::Eabovedot,
-        "eabovedot" => Keysym::eabovedot,
-        "Eacute" => Keysym::Eacute,
-        "eacute" => Keysym::eacute,
-        "Ebelowdot" => Keysym::Ebelowdot,
-        "ebelowdot" => Keysym::ebelowdot,
-        "Ecaron" => Keysym::Ecaron,
-        "ecaron" => Keysym::ecaron,
-        "Ecircumflex" => Keysym::Ecircumflex,
-        "ecircumflex" => Keysym::ecircumflex,
-        "Ecircumflexacute" => Keysym::Ecircumflexacute,
-        "ecircumflexacute" => Keysym::ecircumflexacute,
-        "Ecircumflexbelowdot" => Keysym::Ecircumflexbelowdot,
-        "ecircumflexbelowdot" => Keysym::ecircumflexbelowdot,
-        "Ecircumflexgrave" => Keysym::Ecircumflexgrave,
-        "ecircumflexgrave" => Keysym::ecircumflexgrave,
-        "Ecircumflexhook" => Keysym::Ecircumflexhook,
-        "ecircumflexhook" => Keysym::ecircumflexhook,
-        "Ecircumflextilde" => Keysym::Ecircumflextilde,
-        "ecircumflextilde" => Keysym::ecircumflextilde,
-        "EcuSign" => Keysym::EcuSign,
-        "Ediaeresis" => Keysym::Ediaeresis,
-        "ediaeresis" => Keysym::ediaeresis,
-        "Egrave" => Keysym::Egrave,
-        "egrave" => Keysym::egrave,
-        "Ehook" => Keysym::Ehook,
-        "ehook" => Keysym::ehook,
-        "eightsubscript" => Keysym::eightsubscript,
-        "eightsuperior" => Keysym::eightsuperior,
-        "Eisu_Shift" => Keysym::Eisu_Shift,
-        "Eisu_toggle" => Keysym::Eisu_toggle,
-        "elementof" => Keysym::elementof,
-        "ellipsis" => Keysym::ellipsis,
-        "em3space" => Keysym::em3space,
-        "em4space" => Keysym::em4space,
-        "Emacron" => Keysym::Emacron,
-        "emacron" => Keysym::emacron,
-        "emdash" => Keysym::emdash,
-        "emfilledcircle" => Keysym::emfilledcircle,
-        "emfilledrect" => Keysym::emfilledrect,
-        "emopencircle" => Keysym::emopencircle,
-        "emopenrectangle" => Keysym::emopenrectangle,
-        "emptyset" => Keysym::emptyset,
-        "emspace" => Keysym::emspace,
-        "End" => Keysym::End,
-        "endash" => Keysym::endash,
-        "enfilledcircbullet" => Keysym::enfilledcircbullet,
-        "enfilledsqbullet" => Keysym::enfilledsqbullet,
-        "ENG" => Keysym::ENG,
-        "eng" => Keysym::eng,
-        "enopencircbullet" => Keysym::enopencircbullet,
-        "enopensquarebullet" => Keysym::enopensquarebullet,
-        "enspace" => Keysym::enspace,
-        "Eogonek" => Keysym::Eogonek,
-        "eogonek" => Keysym::eogonek,
-        "equal" => Keysym::equal,
-        "Escape" => Keysym::Escape,
-        "ETH" => Keysym::ETH,
-        "Eth" => Keysym::Eth,
-        "eth" => Keysym::eth,
-        "Etilde" => Keysym::Etilde,
-        "etilde" => Keysym::etilde,
-        "EuroSign" => Keysym::EuroSign,
-        "exclam" => Keysym::exclam,
-        "exclamdown" => Keysym::exclamdown,
-        "Execute" => Keysym::Execute,
-        "Ext16bit_L" => Keysym::Ext16bit_L,
-        "Ext16bit_R" => Keysym::Ext16bit_R,
-        "EZH" => Keysym::EZH,
-        "ezh" => Keysym::ezh,
-        "F" => Keysym::F,
-        "f" => Keysym::f,
-        "F1" => Keysym::F1,
-        "F10" => Keysym::F10,
-        "F11" => Keysym::F11,
-        "F12" => Keysym::F12,
-        "F13" => Keysym::F13,
-        "F14" => Keysym::F14,
-        "F15" => Keysym::F15,
-        "F16" => Keysym::F16,
-        "F17" => Keysym::F17,
-        "F18" => Keysym::F18,
-        "F19" => Keysym::F19,
-        "F2" => Keysym::F2,
-        "F20" => Keysym::F20,
-        "F21" => Keysym::F21,
-        "F22" => Keysym::F22,
-        "F23" => Keysym::F23,
-        "F24" => Keysym::F24,
-        "F25" => Keysym::F25,
-        "F26" => Keysym::F26,
-        "F27" => Keysym::F27,
-        "F28" => Keysym::F28,
-        "F29" => Keysym::F29,
-        "F3" => Keysym::F3,
-        "F30" => Keysym::F30,
-        "F31" => Keysym::F31,
-        "F32" => Keysym::F32,
-        "F33" => Keysym::F33,
-        "F34" => Keysym::F34,
-        "F35" => Keysym::F35,
-        "F4" => Keysym::F4,
-        "F5" => Keysym::F5,
-        "F6" => Keysym::F6,
-        "F7" => Keysym::F7,
-        "F8" => Keysym::F8,
-        "F9" => Keysym::F9,
-        "Fabovedot" => Keysym::Fabovedot,
-        "fabovedot" => Keysym::fabovedot,
-        "Farsi_0" => Keysym::Farsi_0,
-        "Farsi_1" => Keysym::Farsi_1,
-        "Farsi_2" => Keysym::Farsi_2,
-        "Farsi_3" => Keysym::Farsi_3,
-        "Farsi_4" => Keysym::Farsi_4,
-        "Farsi_5" => Keysym::Farsi_5,
-        "Farsi_6" => Keysym::Farsi_6,
-        "Farsi_7" => Keysym::Farsi_7,
-        "Farsi_8" => Keysym::Farsi_8,
-        "Farsi_9" => Keysym::Farsi_9,
-        "Farsi_yeh" => Keysym::Farsi_yeh,
-        "femalesymbol" => Keysym::femalesymbol,
-        "ff" => Keysym::ff,
-        "FFrancSign" => Keysym::FFrancSign,
-        "figdash" => Keysym::figdash,
-        "filledlefttribullet" => Keysym::filledlefttribullet,
-        "filledrectbullet" => Keysym::filledrectbullet,
-        "filledrighttribullet" => Keysym::filledrighttribullet,
-        "filledtribulletdown" => Keysym::filledtribulletdown,
-        "filledtribulletup" => Keysym::filledtribulletup,
-        "Find" => Keysym::Find,
-        "First_Virtual_Screen" => Keysym::First_Virtual_Screen,
-        "fiveeighths" => Keysym::fiveeighths,
-        "fivesixths" => Keysym::fivesixths,
-        "fivesubscript" => Keysym::fivesubscript,
-        "fivesuperior" => Keysym::fivesuperior,
-        "fourfifths" => Keysym::fourfifths,
-        "foursubscript" => Keysym::foursubscript,
-        "foursuperior" => Keysym::foursuperior,
-        "fourthroot" => Keysym::fourthroot,
-        "function" => Keysym::function,
-        "G" => Keysym::G,
-        "g" => Keysym::g,
-        "Gabovedot" => Keysym::Gabovedot,
-        "gabovedot" => Keysym::gabovedot,
-        "Gbreve" => Keysym::Gbreve,
-        "gbreve" => Keysym::gbreve,
-        "Gcaron" => Keysym::Gcaron,
-        "gcaron" => Keysym::gcaron,
-        "Gcedilla" => Keysym::Gcedilla,
-        "gcedilla" => Keysym::gcedilla,
-        "Gcircumflex" => Keysym::Gcircumflex,
-        "gcircumflex" => Keysym::gcircumflex,
-        "Georgian_an" => Keysym::Georgian_an,
-        "Georgian_ban" => Keysym::Georgian_ban,
-        "Georgian_can" => Keysym::Georgian_can,
-        "Georgian_char" => Keysym::Georgian_char,
-        "Georgian_chin" => Keysym::Georgian_chin,
-        "Georgian_cil" => Keysym::Georgian_cil,
-        "Georgian_don" => Keysym::Georgian_don,
-        "Georgian_en" => Keysym::Georgian_en,
-        "Georgian_fi" => Keysym::Georgian_fi,
-        "Georgian_gan" => Keysym::Georgian_gan,
-        "Georgian_ghan" => Keysym::Georgian_ghan,
-        "Georgian_hae" => Keysym::Georgian_hae,
-        "Georgian_har" => Keysym::Georgian_har,
-        "Georgian_he" => Keysym::Georgian_he,
-        "Georgian_hie" => Keysym::Georgian_hie,
-        "Georgian_hoe" => Keysym::Georgian_hoe,
-        "Georgian_in" => Keysym::Georgian_in,
-        "Georgian_jhan" => Keysym::Georgian_jhan,
-        "Georgian_jil" => Keysym::Georgian_jil,
-        "Georgian_kan" => Keysym::Georgian_kan,
-        "Georgian_khar" => Keysym::Georgian_khar,
-        "Georgian_las" => Keysym::Georgian_las,
-        "Georgian_man" => Keysym::Georgian_man,
-        "Georgian_nar" => Keysym::Georgian_nar,
-        "Georgian_on" => Keysym::Georgian_on,
-        "Georgian_par" => Keysym::Georgian_par,
-        "Georgian_phar" => Keysym::Georgian_phar,
-        "Georgian_qar" => Keysym::Georgian_qar,
-        "Georgian_rae" => Keysym::Georgian_rae,
-        "Georgian_san" => Keysym::Georgian_san,
-        "Georgian_shin" => Keysym::Georgian_shin,
-        "Georgian_tan" => Keysym::Georgian_tan,
-        "Georgian_tar" => Keysym::Georgian_tar,
-        "Georgian_un" => Keysym::Georgian_un,
-        "Georgian_vin" => Keysym::Georgian_vin,
-        "Georgian_we" => Keysym::Georgian_we,
-        "Georgian_xan" => Keysym::Georgian_xan,
-        "Georgian_zen" => Keysym::Georgian_zen,
-        "Georgian_zhar" => Keysym::Georgian_zhar,
-        "grave" => Keysym::grave,
-        "greater" => Keysym::greater,
-        "greaterthanequal" => Keysym::greaterthanequal,
-        "Greek_accentdieresis" => Keysym::Greek_accentdieresis,
-        "Greek_ALPHA" => Keysym::Greek_ALPHA,
-        "Greek_alpha" => Keysym::Greek_alpha,
-        "Greek_ALPHAaccent" => Keysym::Greek_ALPHAaccent,
-        "Greek_alphaaccent" => Keysym::Greek_alphaaccent,
-        "Greek_BETA" => Keysym::Greek_BETA,
-        "Greek_beta" => Keysym::Greek_beta,
-        "Greek_CHI" => Keysym::Greek_CHI,
-        "Greek_chi" => Keysym::Greek_chi,
-        "Greek_DELTA" => Keysym::Greek_DELTA,
-        "Greek_delta" => Keysym::Greek_delta,
-        "Greek_EPSILON" => Keysym::Greek_EPSILON,
-        "Greek_epsilon" => Keysym::Greek_epsilon,
-        "Greek_EPSILONaccent" => Keysym::Greek_EPSILONaccent,
-        "Greek_epsilonaccent" => Keysym::Greek_epsilonaccent,
-        "Greek_ETA" => Keysym::Greek_ETA,
-        "Greek_eta" => Keysym::Greek_eta,
-        "Greek_ETAaccent" => Keysym::Greek_ETAaccent,
-        "Greek_etaaccent" => Keysym::Greek_etaaccent,
-        "Greek_finalsmallsigma" => Keysym::Greek_finalsmallsigma,
-        "Greek_GAMMA" => Keysym::Greek_GAMMA,
-        "Greek_gamma" => Keysym::Greek_gamma,
-        "Greek_horizbar" => Keysym::Greek_horizbar,
-        "Greek_IOTA" => Keysym::Greek_IOTA,
-        "Greek_iota" => Keysym::Greek_iota,
-        "Greek_IOTAaccent" => Keysym::Greek_IOTAaccent,
-        "Greek_iotaaccent" => Keysym::Greek_iotaaccent,
-        "Greek_iotaaccentdieresis" => Keysym::Greek_iotaaccentdieresis,
-        "Greek_IOTAdiaeresis" => Keysym::Greek_IOTAdiaeresis,
-        "Greek_IOTAdieresis" => Keysym::Greek_IOTAdieresis,
-        "Greek_iotadieresis" => Keysym::Greek_iotadieresis,
-        "Greek_KAPPA" => Keysym::Greek_KAPPA,
-        "Greek_kappa" => Keysym::Greek_kappa,
-        "Greek_LAMBDA" => Keysym::Greek_LAMBDA,
-        "Greek_lambda" => Keysym::Greek_lambda,
-        "Greek_LAMDA" => Keysym::Greek_LAMDA,
-        "Greek_lamda" => Keysym::Greek_lamda,
-        "Greek_MU" => Keysym::Greek_MU,
-        "Greek_mu" => Keysym::Greek_mu,
-        "Greek_NU" => Keysym::Greek_NU,
-        "Greek_nu" => Keysym::Greek_nu,
-        "Greek_OMEGA" => Keysym::Greek_OMEGA,
-        "Greek_omega" => Keysym::Greek_omega,
-        "Greek_OMEGAaccent" => Keysym::Greek_OMEGAaccent,
-        "Greek_omegaaccent" => Keysym::Greek_omegaaccent,
-        "Greek_OMICRON" => Keysym::Greek_OMICRON,
-        "Greek_omicron" => Keysym::Greek_omicron,
-        "Greek_OMICRONaccent" => Keysym::Greek_OMICRONaccent,
-        "Greek_omicronaccent" => Keysym::Greek_omicronaccent,
-        "Greek_PHI" => Keysym::Greek_PHI,
-        "Greek_phi" => Keysym::Greek_phi,
-        "Greek_PI" => Keysym::Greek_PI,
-        "Greek_pi" => Keysym::Greek_pi,
-        "Greek_PSI" => Keysym::Greek_PSI,
-        "Greek_psi" => Keysym::Greek_psi,
-        "Greek_RHO" => Keysym::Greek_RHO,
-        "Greek_rho" => Keysym::Greek_rho,
-        "Greek_SIGMA" => Keysym::Greek_SIGMA,
-        "Greek_sigma" => Keysym::Greek_sigma,
-        "Greek_switch" => Keysym::Greek_switch,
-        "Greek_TAU" => Keysym::Greek_TAU,
-        "Greek_tau" => Keysym::Greek_tau,
-        "Greek_THETA" => Keysym::Greek_THETA,
-        "Greek_theta" => Keysym::Greek_theta,
-        "Greek_UPSILON" => Keysym::Greek_UPSILON,
-        "Greek_upsilon" => Keysym::Greek_upsilon,
-        "Greek_UPSILONaccent" => Keysym::Greek_UPSILONaccent,
-        "Greek_upsilonaccent" => Keysym::Greek_upsilonaccent,
-        "Greek_upsilonaccentdieresis" => Keysym::Greek_upsilonaccentdieresis,
-        "Greek_UPSILONdieresis" => Keysym::Greek_UPSILONdieresis,
-        "Greek_upsilondieresis" => Keysym::Greek_upsilondieresis,
-        "Greek_XI" => Keysym::Greek_XI,
-        "Greek_xi" => Keysym::Greek_xi,
-        "Greek_ZETA" => Keysym::Greek_ZETA,
-        "Greek_zeta" => Keysym::Greek_zeta,
-        "guilder" => Keysym::guilder,
-        "guillemetleft" => Keysym::guillemetleft,
-        "guillemetright" => Keysym::guillemetright,
-        "guillemotleft" => Keysym::guillemotleft,
-        "guillemotright" => Keysym::guillemotright,
-        "H" => Keysym::H,
-        "h" => Keysym::h,
-        "hairspace" => Keysym::hairspace,
-        "Hangul" => Keysym::Hangul,
-        "Hangul_A" => Keysym::Hangul_A,
-        "Hangul_AE" => Keysym::Hangul_AE,
-        "Hangul_AraeA" => Keysym::Hangul_AraeA,
-        "Hangul_AraeAE" => Keysym::Hangul_AraeAE,
-        "Hangul_Banja" => Keysym::Hangul_Banja,
-        "Hangul_Cieuc" => Keysym::Hangul_Cieuc,
-        "Hangul_Codeinput" => Keysym::Hangul_Codeinput,
-        "Hangul_Dikeud" => Keysym::Hangul_Dikeud,
-        "Hangul_E" => Keysym::Hangul_E,
-        "Hangul_End" => Keysym::Hangul_End,
-        "Hangul_EO" => Keysym::Hangul_EO,
-        "Hangul_EU" => Keysym::Hangul_EU,
-        "Hangul_Hanja" => Keysym::Hangul_Hanja,
-        "Hangul_Hieuh" => Keysym::Hangul_Hieuh,
-        "Hangul_I" => Keysym::Hangul_I,
-        "Hangul_Ieung" => Keysym::Hangul_Ieung,
-        "Hangul_J_Cieuc" => Keysym::Hangul_J_Cieuc,
-        "Hangul_J_Dikeud" => Keysym::Hangul_J_Dikeud,
-        "Hangul_J_Hieuh" => Keysym::Hangul_J_Hieuh,
-        "Hangul_J_Ieung" => Keysym::Hangul_J_Ieung,
-        "Hangul_J_Jieuj" => Keysym::Hangul_J_Jieuj,
-        "Hangul_J_Khieuq" => Keysym::Hangul_J_Khieuq,
-        "Hangul_J_Kiyeog" => Keysym::Hangul_J_Kiyeog,
-        "Hangul_J_KiyeogSios" => Keysym::Hangul_J_KiyeogSios,
-        "Hangul_J_KkogjiDalrinIeung" => Keysym::Hangul_J_KkogjiDalrinIeung,
-        "Hangul_J_Mieum" => Keysym::Hangul_J_Mieum,
-        "Hangul_J_Nieun" => Keysym::Hangul_J_Nieun,
-        "Hangul_J_NieunHieuh" => Keysym::Hangul_J_NieunHieuh,
-        "Hangul_J_NieunJieuj" => Keysym::Hangul_J_NieunJieuj,
-        "Hangul_J_PanSios" => Keysym::Hangul_J_PanSios,
-        "Hangul_J_Phieuf" => Keysym::Hangul_J_Phieuf,
-        "Hangul_J_Pieub" => Keysym::Hangul_J_Pieub,
-        "Hangul_J_PieubSios" => Keysym::Hangul_J_PieubSios,
-        "Hangul_J_Rieul" => Keysym::Hangul_J_Rieul,
-        "Hangul_J_RieulHieuh" => Keysym::Hangul_J_RieulHieuh,
-        "Hangul_J_RieulKiyeog" => Keysym::Hangul_J_RieulKiyeog,
-        "Hangul_J_RieulMieum" => Keysym::Hangul_J_RieulMieum,
-        "Hangul_J_RieulPhieuf" => Keysym::Hangul_J_RieulPhieuf,
-        "Hangul_J_RieulPieub" => Keysym::Hangul_J_RieulPieub,
-        "Hangul_J_RieulSios" => Keysym::Hangul_J_RieulSios,
-        "Hangul_J_RieulTieut" => Keysym::Hangul_J_RieulTieut,
-        "Hangul_J_Sios" => Keysym::Hangul_J_Sios,
-        "Hangul_J_SsangKiyeog" => Keysym::Hangul_J_SsangKiyeog,
-        "Hangul_J_SsangSios" => Keysym::Hangul_J_SsangSios,
-        "Hangul_J_Tieut" => Keysym::Hangul_J_Tieut,
-        "Hangul_J_YeorinHieuh" => Keysym::Hangul_J_YeorinHieuh,
-        "Hangul_Jamo" => Keysym::Hangul_Jamo,
-        "Hangul_Jeonja" => Keysym::Hangul_Jeonja,
-        "Hangul_Jieuj" => Keysym::Hangul_Jieuj,
-        "Hangul_Khieuq" => Keysym::Hangul_Khieuq,
-        "Hangul_Kiyeog" => Keysym::Hangul_Kiyeog,
-        "Hangul_KiyeogSios" => Keysym::Hangul_KiyeogSios,
-        "Hangul_KkogjiDalrinIeung" => Keysym::Hangul_KkogjiDalrinIeung,
-        "Hangul_Mieum" => Keysym::Hangul_Mieum,
-        "Hangul_MultipleCandidate" => Keysym::Hangul_MultipleCandidate,
-        "Hangul_Nieun" => Keysym::Hangul_Nieun,
-        "Hangul_NieunHieuh" => Keysym::Hangul_NieunHieuh,
-        "Hangul_NieunJieuj" => Keysym::Hangul_NieunJieuj,
-        "Hangul_O" => Keysym::Hangul_O,
-        "Hangul_OE" => Keysym::Hangul_OE,
-        "Hangul_PanSios" => Keysym::Hangul_PanSios,
-        "Hangul_Phieuf" => Keysym::Hangul_Phieuf,
-        "Hangul_Pieub" => Keysym::Hangul_Pieub,
-        "Hangul_PieubSios" => Keysym::Hangul_PieubSios,
-        "Hangul_PostHanja" => Keysym::Hangul_PostHanja,
-        "Hangul_PreHanja" => Keysym::Hangul_PreHanja,
-        "Hangul_PreviousCandidate" => Keysym::Hangul_PreviousCandidate,
-        "Hangul_Rieul" => Keysym::Hangul_Rieul,
-        "Hangul_RieulHieuh" => Keysym::Hangul_RieulHieuh,
-        "Hangul_RieulKiyeog" => Keysym::Hangul_RieulKiyeog,
-        "Hangul_RieulMieum" => Keysym::Hangul_RieulMieum,
-        "Hangul_RieulPhieuf" => Keysym::Hangul_RieulPhieuf,
-        "Hangul_RieulPieub" => Keysym::Hangul_RieulPieub,
-        "Hangul_RieulSios" => Keysym::Hangul_RieulSios,
-        "Hangul_RieulTieut" => Keysym::Hangul_RieulTieut,
-        "Hangul_RieulYeorinHieuh" => Keysym::Hangul_RieulYeorinHieuh,
-        "Hangul_Romaja" => Keysym::Hangul_Romaja,
-        "Hangul_SingleCandidate" => Keysym::Hangul_SingleCandidate,
-        "Hangul_Sios" => Keysym::Hangul_Sios,
-        "Hangul_Special" => Keysym::Hangul_Special,
-        "Hangul_SsangDikeud" => Keysym::Hangul_SsangDikeud,
-        "Hangul_SsangJieuj" => Keysym::Hangul_SsangJieuj,
-        "Hangul_SsangKiyeog" => Keysym::Hangul_SsangKiyeog,
-        "Hangul_SsangPieub" => Keysym::Hangul_SsangPieub,
-        "Hangul_SsangSios" => Keysym::Hangul_SsangSios,
-        "Hangul_Start" => Keysym::Hangul_Start,
-        "Hangul_SunkyeongeumMieum" => Keysym::Hangul_SunkyeongeumMieum,
-        "Hangul_SunkyeongeumPhieuf" => Keysym::Hangul_SunkyeongeumPhieuf,
-        "Hangul_SunkyeongeumPieub" => Keysym::Hangul_SunkyeongeumPieub,
-        "Hangul_switch" => Keysym::Hangul_switch,
-        "Hangul_Tieut" => Keysym::Hangul_Tieut,
-        "Hangul_U" => Keysym::Hangul_U,
-        "Hangul_WA" => Keysym::Hangul_WA,
-        "Hangul_WAE" => Keysym::Hangul_WAE,
-        "Hangul_WE" => Keysym::Hangul_WE,
-        "Hangul_WEO" => Keysym::Hangul_WEO,
-        "Hangul_WI" => Keysym::Hangul_WI,
-        "Hangul_YA" => Keysym::Hangul_YA,
-        "Hangul_YAE" => Keysym::Hangul_YAE,
-        "Hangul_YE" => Keysym::Hangul_YE,
-        "Hangul_YEO" => Keysym::Hangul_YEO,
-        "Hangul_YeorinHieuh" => Keysym::Hangul_YeorinHieuh,
-        "Hangul_YI" => Keysym::Hangul_YI,
-        "Hangul_YO" => Keysym::Hangul_YO,
-        "Hangul_YU" => Keysym::Hangul_YU,
-        "Hankaku" => Keysym::Hankaku,
-        "Hcircumflex" => Keysym::Hcircumflex,
-        "hcircumflex" => Keysym::hcircumflex,
-        "heart" => Keysym::heart,
-        "hebrew_aleph" => Keysym::hebrew_aleph,
-        "hebrew_ayin" => Keysym::hebrew_ayin,
-        "hebrew_bet" => Keysym::hebrew_bet,
-        "hebrew_beth" => Keysym::hebrew_beth,
-        "hebrew_chet" => Keysym::hebrew_chet,
-        "hebrew_dalet" => Keysym::hebrew_dalet,
-        "hebrew_daleth" => Keysym::hebrew_daleth,
-        "hebrew_doublelowline" => Keysym::hebrew_doublelowline,
-        "hebrew_finalkaph" => Keysym::hebrew_finalkaph,
-        "hebrew_finalmem" => Keysym::hebrew_finalmem,
-        "hebrew_finalnun" => Keysym::hebrew_finalnun,
-        "hebrew_finalpe" => Keysym::hebrew_finalpe,
-        "hebrew_finalzade" => Keysym::hebrew_finalzade,
-        "hebrew_finalzadi" => Keysym::hebrew_finalzadi,
-        "hebrew_gimel" => Keysym::hebrew_gimel,
-        "hebrew_gimmel" => Keysym::hebrew_gimmel,
-        "hebrew_he" => Keysym::hebrew_he,
-        "hebrew_het" => Keysym::hebrew_het,
-        "hebrew_kaph" => Keysym::hebrew_kaph,
-        "hebrew_kuf" => Keysym::hebrew_kuf,
-        "hebrew_lamed" => Keysym::hebrew_lamed,
-        "hebrew_mem" => Keysym::hebrew_mem,
-        "hebrew_nun" => Keysym::hebrew_nun,
-        "hebrew_pe" => Keysym::hebrew_pe,
-        "hebrew_qoph" => Keysym::hebrew_qoph,
-        "hebrew_resh" => Keysym::hebrew_resh,
-        "hebrew_samech" => Keysym::hebrew_samech,
-        "hebrew_samekh" => Keysym::hebrew_samekh,
-        "hebrew_shin" => Keysym::hebrew_shin,
-        "Hebrew_switch" => Keysym::Hebrew_switch,
-        "hebrew_taf" => Keysym::hebrew_taf,
-        "hebrew_taw" => Keysym::hebrew_taw,
-        "hebrew_tet" => Keysym::hebrew_tet,
-        "hebrew_teth" => Keysym::hebrew_teth,
-        "hebrew_waw" => Keysym::hebrew_waw,
-        "hebrew_yod" => Keysym::hebrew_yod,
-        "hebrew_zade" => Keysym::hebrew_zade,
-        "hebrew_zadi" => Keysym::hebrew_zadi,
-        "hebrew_zain" => Keysym::hebrew_zain,
-        "hebrew_zayin" => Keysym::hebrew_zayin,
-        "Help" => Keysym::Help,
-        "Henkan" => Keysym::Henkan,
-        "Henkan_Mode" => Keysym::Henkan_Mode,
-        "hexagram" => Keysym::hexagram,
-        "Hiragana" => Keysym::Hiragana,
-        "Hiragana_Katakana" => Keysym::Hiragana_Katakana,
-        "Home" => Keysym::Home,
-        "horizconnector" => Keysym::horizconnector,
-        "horizlinescan1" => Keysym::horizlinescan1,
-        "horizlinescan3" => Keysym::horizlinescan3,
-        "horizlinescan5" => Keysym::horizlinescan5,
-        "horizlinescan7" => Keysym::horizlinescan7,
-        "horizlinescan9" => Keysym::horizlinescan9,
-        "hpBackTab" => Keysym::HP_BackTab,
-        "hpblock" => Keysym::HP_block,
-        "hpClearLine" => Keysym::HP_ClearLine,
-        "hpDeleteChar" => Keysym::HP_DeleteChar,
-        "hpDeleteLine" => Keysym::HP_DeleteLine,
-        "hpguilder" => Keysym::HP_guilder,
-        "hpInsertChar" => Keysym::HP_InsertChar,
-        "hpInsertLine" => Keysym::HP_InsertLine,
-        "hpIO" => Keysym::HP_IO,
-        "hpKP_BackTab" => Keysym::HP_KP_BackTab,
-        "hplira" => Keysym::HP_lira,
-        "hplongminus" => Keysym::HP_longminus,
-        "hpModelock1" => Keysym::HP_Modelock1,
-        "hpModelock2" => Keysym::HP_Modelock2,
-        "hpmute_acute" => Keysym::HP_mute_acute,
-        "hpmute_asciicircum" => Keysym::HP_mute_asciicircum,
-        "hpmute_asciitilde" => Keysym::HP_mute_asciitilde,
-        "hpmute_diaeresis" => Keysym::HP_mute_diaeresis,
-        "hpmute_grave" => Keysym::HP_mute_grave,
-        "hpReset" => Keysym::HP_Reset,
-        "hpSystem" => Keysym::HP_System,
-        "hpUser" => Keysym::HP_User,
-        "Hstroke" => Keysym::Hstroke,
-        "hstroke" => Keysym::hstroke,
-        "ht" => Keysym::ht,
-        "Hyper_L" => Keysym::Hyper_L,
-        "Hyper_R" => Keysym::Hyper_R,
-        "hyphen" => Keysym::hyphen,
-        "I" => Keysym::I,
-        "i" => Keysym::i,
-        "Iabovedot" => Keysym::Iabovedot,
-        "Iacute" => Keysym::Iacute,
-        "iacute" => Keysym::iacute,
-        "Ibelowdot" => Keysym::Ibelowdot,
-        "ibelowdot" => Keysym::ibelowdot,
-        "Ibreve" => Keysym::Ibreve,
-        "ibreve" => Keysym::ibreve,
-        "Icircumflex" => Keysym::Icircumflex,
-        "icircumflex" => Keysym::icircumflex,
-        "identical" => Keysym::identical,
-        "Idiaeresis" => Keysym::Idiaeresis,
-        "idiaeresis" => Keysym::idiaeresis,
-        "idotless" => Keysym::idotless,
-        "ifonlyif" => Keysym::ifonlyif,
-        "Igrave" => Keysym::Igrave,
-        "igrave" => Keysym::igrave,
-        "Ihook" => Keysym::Ihook,
-        "ihook" => Keysym::ihook,
-        "Imacron" => Keysym::Imacron,
-        "imacron" => Keysym::imacron,
-        "implies" => Keysym::implies,
-        "includedin" => Keysym::includedin,
-        "includes" => Keysym::includes,
-        "infinity" => Keysym::infinity,
-        "Insert" => Keysym::Insert,
-        "InsertChar" => Keysym::InsertChar,
-        "InsertLine" => Keysym::InsertLine,
-        "integral" => Keysym::integral,
-        "intersection" => Keysym::intersection,
-        "IO" => Keysym::IO,
-        "Iogonek" => Keysym::Iogonek,
-        "iogonek" => Keysym::iogonek,
-        "ISO_Center_Object" => Keysym::ISO_Center_Object,
-        "ISO_Continuous_Underline" => Keysym::ISO_Continuous_Underline,
-        "ISO_Discontinuous_Underline" => Keysym::ISO_Discontinuous_Underline,
-        "ISO_Emphasize" => Keysym::ISO_Emphasize,
-        "ISO_Enter" => Keysym::ISO_Enter,
-        "ISO_Fast_Cursor_Down" => Keysym::ISO_Fast_Cursor_Down,
-        "ISO_Fast_Cursor_Left" => Keysym::ISO_Fast_Cursor_Left,
-        "ISO_Fast_Cursor_Right" => Keysym::ISO_Fast_Cursor_Right,
-        "ISO_Fast_Cursor_Up" => Keysym::ISO_Fast_Cursor_Up,
-        "ISO_First_Group" => Keysym::ISO_First_Group,
-        "ISO_First_Group_Lock" => Keysym::ISO_First_Group_Lock,
-        "ISO_Group_Latch" => Keysym::ISO_Group_Latch,
-        "ISO_Group_Lock" => Keysym::ISO_Group_Lock,
-        "ISO_Group_Shift" => Keysym::ISO_Group_Shift,
-        "ISO_Last_Group" => Keysym::ISO_Last_Group,
-        "ISO_Last_Group_Lock" => Keysym::ISO_Last_Group_Lock,
-        "ISO_Left_Tab" => Keysym::ISO_Left_Tab,
-        "ISO_Level2_Latch" => Keysym::ISO_Level2_Latch,
-        "ISO_Level3_Latch" => Keysym::ISO_Level3_Latch,
-        "ISO_Level3_Lock" => Keysym::ISO_Level3_Lock,
-        "ISO_Level3_Shift" => Keysym::ISO_Level3_Shift,
-        "ISO_Level5_Latch" => Keysym::ISO_Level5_Latch,
-        "ISO_Level5_Lock" => Keysym::ISO_Level5_Lock,
-        "ISO_Level5_Shift" => Keysym::ISO_Level5_Shift,
-        "ISO_Lock" => Keysym::ISO_Lock,
-        "ISO_Move_Line_Down" => Keysym::ISO_Move_Line_Down,
-        "ISO_Move_Line_Up" => Keysym::ISO_Move_Line_Up,
-        "ISO_Next_Group" => Keysym::ISO_Next_Group,
-        "ISO_Next_Group_Lock" => Keysym::ISO_Next_Group_Lock,
-        "ISO_Partial_Line_Down" => Keysym::ISO_Partial_Line_Down,
-        "ISO_Partial_Line_Up" => Keysym::ISO_Partial_Line_Up,
-        "ISO_Partial_Space_Left" => Keysym::ISO_Partial_Space_Left,
-        "ISO_Partial_Space_Right" => Keysym::ISO_Partial_Space_Right,
-        "ISO_Prev_Group" => Keysym::ISO_Prev_Group,
-        "ISO_Prev_Group_Lock" => Keysym::ISO_Prev_Group_Lock,
-        "ISO_Release_Both_Margins" => Keysym::ISO_Release_Both_Margins,
-        "ISO_Release_Margin_Left" => Keysym::ISO_Release_Margin_Left,
-        "ISO_Release_Margin_Right" => Keysym::ISO_Release_Margin_Right,
-        "ISO_Set_Margin_Left" => Keysym::ISO_Set_Margin_Left,
-        "ISO_Set_Margin_Right" => Keysym::ISO_Set_Margin_Right,
-        "Itilde" => Keysym::Itilde,
-        "itilde" => Keysym::itilde,
-        "J" => Keysym::J,
-        "j" => Keysym::j,
-        "Jcircumflex" => Keysym::Jcircumflex,
-        "jcircumflex" => Keysym::jcircumflex,
-        "jot" => Keysym::jot,
-        "K" => Keysym::K,
-        "k" => Keysym::k,
-        "kana_A" => Keysym::kana_A,
-        "kana_a" => Keysym::kana_a,
-        "kana_CHI" => Keysym::kana_CHI,
-        "kana_closingbracket" => Keysym::kana_closingbracket,
-        "kana_comma" => Keysym::kana_comma,
-        "kana_conjunctive" => Keysym::kana_conjunctive,
-        "kana_E" => Keysym::kana_E,
-        "kana_e" => Keysym::kana_e,
-        "kana_FU" => Keysym::kana_FU,
-        "kana_fullstop" => Keysym::kana_fullstop,
-        "kana_HA" => Keysym::kana_HA,
-        "kana_HE" => Keysym::kana_HE,
-        "kana_HI" => Keysym::kana_HI,
-        "kana_HO" => Keysym::kana_HO,
-        "kana_HU" => Keysym::kana_HU,
-        "kana_I" => Keysym::kana_I,
-        "kana_i" => Keysym::kana_i,
-        "kana_KA" => Keysym::kana_KA,
-        "kana_KE" => Keysym::kana_KE,
-        "kana_KI" => Keysym::kana_KI,
-        "kana_KO" => Keysym::kana_KO,
-        "kana_KU" => Keysym::kana_KU,
-        "Kana_Lock" => Keysym::Kana_Lock,
-        "kana_MA" => Keysym::kana_MA,
-        "kana_ME" => Keysym::kana_ME,
-        "kana_MI" => Keysym::kana_MI,
-        "kana_middledot" => Keysym::kana_middledot,
-        "kana_MO" => Keysym::kana_MO,
-        "kana_MU" => Keysym::kana_MU,
-        "kana_N" => Keysym::kana_N,
-        "kana_NA" => Keysym::kana_NA,
-        "kana_NE" => Keysym::kana_NE,
-        "kana_NI" => Keysym::kana_NI,
-        "kana_NO" => Keysym::kana_NO,
-        "kana_NU" => Keysym::kana_NU,
-        "kana_O" => Keysym::kana_O,
-        "kana_o" => Keysym::kana_o,
-        "kana_openingbracket" => Keysym::kana_openingbracket,
-        "kana_RA" => Keysym::kana_RA,
-        "kana_RE" => Keysym::kana_RE,
-        "kana_RI" => Keysym::kana_RI,
-        "kana_RO" => Keysym::kana_RO,
-        "kana_RU" => Keysym::kana_RU,
-        "kana_SA" => Keysym::kana_SA,
-        "kana_SE" => Keysym::kana_SE,
-        "kana_SHI" => Keysym::kana_SHI,
-        "Kana_Shift" => Keysym::Kana_Shift,
-        "kana_SO" => Keysym::kana_SO,
-        "kana_SU" => Keysym::kana_SU,
-        "kana_switch" => Keysym::kana_switch,
-        "kana_TA" => Keysym::kana_TA,
-        "kana_TE" => Keysym::kana_TE,
-        "kana_TI" => Keysym::kana_TI,
-        "kana_TO" => Keysym::kana_TO,
-        "kana_TSU" => Keysym::kana_TSU,
-        "kana_tsu" => Keysym::kana_tsu,
-        "kana_TU" => Keysym::kana_TU,
-        "kana_tu" => Keysym::kana_tu,
-        "kana_U" => Keysym::kana_U,
-        "kana_u" => Keysym::kana_u,
-        "kana_WA" => Keysym::kana_WA,
-        "kana_WO" => Keysym::kana_WO,
-        "kana_YA" => Keysym::kana_YA,
-        "kana_ya" => Keysym::kana_ya,
-        "kana_YO" => Keysym::kana_YO,
-        "kana_yo" => Keysym::kana_yo,
-        "kana_YU" => Keysym::kana_YU,
-        "kana_yu" => Keysym::kana_yu,
-        "Kanji" => Keysym::Kanji,
-        "Kanji_Bangou" => Keysym::Kanji_Bangou,
-        "kappa" => Keysym::kappa,
-        "Katakana" => Keysym::Katakana,
-        "Kcedilla" => Keysym::Kcedilla,
-        "kcedilla" => Keysym::kcedilla,
-        "Korean_Won" => Keysym::Korean_Won,
-        "KP_0" => Keysym::KP_0,
-        "KP_1" => Keysym::KP_1,
-        "KP_2" => Keysym::KP_2,
-        "KP_3" => Keysym::KP_3,
-        "KP_4" => Keysym::KP_4,
-        "KP_5" => Keysym::KP_5,
-        "KP_6" => Keysym::KP_6,
-        "KP_7" => Keysym::KP_7,
-        "KP_8" => Keysym::KP_8,
-        "KP_9" => Keysym::KP_9,
-        "KP_Add" => Keysym::KP_Add,
-        "KP_BackTab" => Keysym::KP_BackTab,
-        "KP_Begin" => Keysym::KP_Begin,
-        "KP_Decimal" => Keysym::KP_Decimal,
-        "KP_Delete" => Keysym::KP_Delete,
-        "KP_Divide" => Keysym::KP_Divide,
-        "KP_Down" => Keysym::KP_Down,
-        "KP_End" => Keysym::KP_End,
-        "KP_Enter" => Keysym::KP_Enter,
-        "KP_Equal" => Keysym::KP_Equal,
-        "KP_F1" => Keysym::KP_F1,
-        "KP_F2" => Keysym::KP_F2,
-        "KP_F3" => Keysym::KP_F3,
-        "KP_F4" => Keysym::KP_F4,
-        "KP_Home" => Keysym::KP_Home,
-        "KP_Insert" => Keysym::KP_Insert,
-        "KP_Left" => Keysym::KP_Left,
-        "KP_Multiply" => Keysym::KP_Multiply,
-        "KP_Next" => Keysym::KP_Next,
-        "KP_Page_Down" => Keysym::KP_Page_Down,
-        "KP_Page_Up" => Keysym::KP_Page_Up,
-        "KP_Prior" => Keysym::KP_Prior,
-        "KP_Right" => Keysym::KP_Right,
-        "KP_Separator" => Keysym::KP_Separator,
-        "KP_Space" => Keysym::KP_Space,
-        "KP_Subtract" => Keysym::KP_Subtract,
-        "KP_Tab" => Keysym::KP_Tab,
-        "KP_Up" => Keysym::KP_Up,
-        "kra" => Keysym::kra,
-        "L" => Keysym::L,
-        "l" => Keysym::l,
-        "L1" => Keysym::L1,
-        "L10" => Keysym::L10,
-        "L2" => Keysym::L2,
-        "L3" => Keysym::L3,
-        "L4" => Keysym::L4,
-        "L5" => Keysym::L5,
-        "L6" => Keysym::L6,
-        "L7" => Keysym::L7,
-        "L8" => Keysym::L8,
-        "L9" => Keysym::L9,
-        "Lacute" => Keysym::Lacute,
-        "lacute" => Keysym::lacute,
-        "Last_Virtual_Screen" => Keysym::Last_Virtual_Screen,
-        "latincross" => Keysym::latincross,
-        "Lbelowdot" => Keysym::Lbelowdot,
-        "lbelowdot" => Keysym::lbelowdot,
-        "Lcaron" => Keysym::Lcaron,
-        "lcaron" => Keysym::lcaron,
-        "Lcedilla" => Keysym::Lcedilla,
-        "lcedilla" => Keysym::lcedilla,
-        "Left" => Keysym::Left,
-        "leftanglebracket" => Keysym::leftanglebracket,
-        "leftarrow" => Keysym::leftarrow,
-        "leftcaret" => Keysym::leftcaret,
-        "leftdoublequotemark" => Keysym::leftdoublequotemark,
-        "leftmiddlecurlybrace" => Keysym::leftmiddlecurlybrace,
-        "leftopentriangle" => Keysym::leftopentriangle,
-        "leftpointer" => Keysym::leftpointer,
-        "leftradical" => Keysym::leftradical,
-        "leftshoe" => Keysym::leftshoe,
-        "leftsinglequotemark" => Keysym::leftsinglequotemark,
-        "leftt" => Keysym::leftt,
-        "lefttack" => Keysym::lefttack,
-        "less" => Keysym::less,
-        "lessthanequal" => Keysym::lessthanequal,
-        "lf" => Keysym::lf,
-        "Linefeed" => Keysym::Linefeed,
-        "lira" => Keysym::lira,
-        "LiraSign" => Keysym::LiraSign,
-        "logicaland" => Keysym::logicaland,
-        "logicalor" => Keysym::logicalor,
-        "longminus" => Keysym::longminus,
-        "lowleftcorner" => Keysym::lowleftcorner,
-        "lowrightcorner" => Keysym::lowrightcorner,
-        "Lstroke" => Keysym::Lstroke,
-        "lstroke" => Keysym::lstroke,
-        "M" => Keysym::M,
-        "m" => Keysym::m,
-        "Mabovedot" => Keysym::Mabovedot,
-        "mabovedot" => Keysym::mabovedot,
-        "Macedonia_DSE" => Keysym::Macedonia_DSE,
-        "Macedonia_dse" => Keysym::Macedonia_dse,
-        "Macedonia_GJE" => Keysym::Macedonia_GJE,
-        "Macedonia_gje" => Keysym::Macedonia_gje,
-        "Macedonia_KJE" => Keysym::Macedonia_KJE,
-        "Macedonia_kje" => Keysym::Macedonia_kje,
-        "macron" => Keysym::macron,
-        "Mae_Koho" => Keysym::Mae_Koho,
-        "malesymbol" => Keysym::malesymbol,
-        "maltesecross" => Keysym::maltesecross,
-        "marker" => Keysym::marker,
-        "masculine" => Keysym::masculine,
-        "Massyo" => Keysym::Massyo,
-        "Menu" => Keysym::Menu,
-        "Meta_L" => Keysym::Meta_L,
-        "Meta_R" => Keysym::Meta_R,
-        "MillSign" => Keysym::MillSign,
-        "minus" => Keysym::minus,
-        "minutes" => Keysym::minutes,
-        "Mode_switch" => Keysym::Mode_switch,
-        "MouseKeys_Accel_Enable" => Keysym::MouseKeys_Accel_Enable,
-        "MouseKeys_Enable" => Keysym::MouseKeys_Enable,
-        "mu" => Keysym::mu,
-        "Muhenkan" => Keysym::Muhenkan,
-        "Multi_key" => Keysym::Multi_key,
-        "MultipleCandidate" => Keysym::MultipleCandidate,
-        "multiply" => Keysym::multiply,
-        "musicalflat" => Keysym::musicalflat,
-        "musicalsharp" => Keysym::musicalsharp,
-        "mute_acute" => Keysym::mute_acute,
-        "mute_asciicircum" => Keysym::mute_asciicircum,
-        "mute_asciitilde" => Keysym::mute_asciitilde,
-        "mute_diaeresis" => Keysym::mute_diaeresis,
-        "mute_grave" => Keysym::mute_grave,
-        "N" => Keysym::N,
-        "n" => Keysym::n,
-        "nabla" => Keysym::nabla,
-        "Nacute" => Keysym::Nacute,
-        "nacute" => Keysym::nacute,
-        "NairaSign" => Keysym::NairaSign,
-        "Ncaron" => Keysym::Ncaron,
-        "ncaron" => Keysym::ncaron,
-        "Ncedilla" => Keysym::Ncedilla,
-        "ncedilla" => Keysym::ncedilla,
-        "NewSheqelSign" => Keysym::NewSheqelSign,
-        "Next" => Keysym::Next,
-        "Next_Virtual_Screen" => Keysym::Next_Virtual_Screen,
-        "ninesubscript" => Keysym::ninesubscript,
-        "ninesuperior" => Keysym::ninesuperior,
-        "nl" => Keysym::nl,
-        "nobreakspace" => Keysym::nobreakspace,
-        "notapproxeq" => Keysym::notapproxeq,
-        "notelementof" => Keysym::notelementof,
-        "notequal" => Keysym::notequal,
-        "notidentical" => Keysym::notidentical,
-        "notsign" => Keysym::notsign,
-        "Ntilde" => Keysym::Ntilde,
-        "ntilde" => Keysym::ntilde,
-        "Num_Lock" => Keysym::Num_Lock,
-        "numbersign" => Keysym::numbersign,
-        "numerosign" => Keysym::numerosign,
-        "O" => Keysym::O,
-        "o" => Keysym::o,
-        "Oacute" => Keysym::Oacute,
-        "oacute" => Keysym::oacute,
-        "Obarred" => Keysym::Obarred,
-        "obarred" => Keysym::obarred,
-        "Obelowdot" => Keysym::Obelowdot,
-        "obelowdot" => Keysym::obelowdot,
-        "Ocaron" => Keysym::Ocaron,
-        "ocaron" => Keysym::ocaron,
-        "Ocircumflex" => Keysym::Ocircumflex,
-        "ocircumflex" => Keysym::ocircumflex,
-        "Ocircumflexacute" => Keysym::Ocircumflexacute,
-        "ocircumflexacute" => Keysym::ocircumflexacute,
-        "Ocircumflexbelowdot" => Keysym::Ocircumflexbelowdot,
-        "ocircumflexbelowdot" => Keysym::ocircumflexbelowdot,
-        "Ocircumflexgrave" => Keysym::Ocircumflexgrave,
-        "ocircumflexgrave" => Keysym::ocircumflexgrave,
-        "Ocircumflexhook" => Keysym::Ocircumflexhook,
-        "ocircumflexhook" => Keysym::ocircumflexhook,
-        "Ocircumflextilde" => Keysym::Ocircumflextilde,
-        "ocircumflextilde" => Keysym::ocircumflextilde,
-        "Odiaeresis" => Keysym::Odiaeresis,
-        "odiaeresis" => Keysym::odiaeresis,
-        "Odoubleacute" => Keysym::Odoubleacute,
-        "odoubleacute" => Keysym::odoubleacute,
-        "OE" => Keysym::OE,
-        "oe" => Keysym::oe,
-        "ogonek" => Keysym::ogonek,
-        "Ograve" => Keysym::Ograve,
-        "ograve" => Keysym::ograve,
-        "Ohook" => Keysym::Ohook,
-        "ohook" => Keysym::ohook,
-        "Ohorn" => Keysym::Ohorn,
-        "ohorn" => Keysym::ohorn,
-        "Ohornacute" => Keysym::Ohornacute,
-        "ohornacute" => Keysym::ohornacute,
-        "Ohornbelowdot" => Keysym::Ohornbelowdot,
-        "ohornbelowdot" => Keysym::ohornbelowdot,
-        "Ohorngrave" => Keysym::Ohorngrave,
-        "ohorngrave" => Keysym::ohorngrave,
-        "Ohornhook" => Keysym::Ohornhook,
-        "ohornhook" => Keysym::ohornhook,
-        "Ohorntilde" => Keysym::Ohorntilde,
-        "ohorntilde" => Keysym::ohorntilde,
-        "Omacron" => Keysym::Omacron,
-        "omacron" => Keysym::omacron,
-        "oneeighth" => Keysym::oneeighth,
-        "onefifth" => Keysym::onefifth,
-        "onehalf" => Keysym::onehalf,
-        "onequarter" => Keysym::onequarter,
-        "onesixth" => Keysym::onesixth,
-        "onesubscript" => Keysym::onesubscript,
-        "onesuperior" => Keysym::onesuperior,
-        "onethird" => Keysym::onethird,
-        "Ooblique" => Keysym::Ooblique,
-        "ooblique" => Keysym::ooblique,
-        "openrectbullet" => Keysym::openrectbullet,
-        "openstar" => Keysym::openstar,
-        "opentribulletdown" => Keysym::opentribulletdown,
-        "opentribulletup" => Keysym::opentribulletup,
-        "ordfeminine" => Keysym::ordfeminine,
-        "ordmasculine" => Keysym::ordmasculine,
-        "osfActivate" => Keysym::OSF_Activate,
-        "osfAddMode" => Keysym::OSF_AddMode,
-        "osfBackSpace" => Keysym::OSF_BackSpace,
-        "osfBackTab" => Keysym::OSF_BackTab,
-        "osfBeginData" => Keysym::OSF_BeginData,
-        "osfBeginLine" => Keysym::OSF_BeginLine,
-        "osfCancel" => Keysym::OSF_Cancel,
-        "osfClear" => Keysym::OSF_Clear,
-        "osfCopy" => Keysym::OSF_Copy,
-        "osfCut" => Keysym::OSF_Cut,
-        "osfDelete" => Keysym::OSF_Delete,
-        "osfDeselectAll" => Keysym::OSF_DeselectAll,
-        "osfDown" => Keysym::OSF_Down,
-        "osfEndData" => Keysym::OSF_EndData,
-        "osfEndLine" => Keysym::OSF_EndLine,
-        "osfEscape" => Keysym::OSF_Escape,
-        "osfExtend" => Keysym::OSF_Extend,
-        "osfHelp" => Keysym::OSF_Help,
-        "osfInsert" => Keysym::OSF_Insert,
-        "osfLeft" => Keysym::OSF_Left,
-        "osfMenu" => Keysym::OSF_Menu,
-        "osfMenuBar" => Keysym::OSF_MenuBar,
-        "osfNextField" => Keysym::OSF_NextField,
-        "osfNextMenu" => Keysym::OSF_NextMenu,
-        "osfPageDown" => Keysym::OSF_PageDown,
-        "osfPageLeft" => Keysym::OSF_PageLeft,
-        "osfPageRight" => Keysym::OSF_PageRight,
-        "osfPageUp" => Keysym::OSF_PageUp,
-        "osfPaste" => Keysym::OSF_Paste,
-        "osfPrevField" => Keysym::OSF_PrevField,
-        "osfPrevMenu" => Keysym::OSF_PrevMenu,
-        "osfPrimaryPaste" => Keysym::OSF_PrimaryPaste,
-        "osfQuickPaste" => Keysym::OSF_QuickPaste,
-        "osfReselect" => Keysym::OSF_Reselect,
-        "osfRestore" => Keysym::OSF_Restore,
-        "osfRight" => Keysym::OSF_Right,
-        "osfSelect" => Keysym::OSF_Select,
-        "osfSelectAll" => Keysym::OSF_SelectAll,
-        "osfUndo" => Keysym::OSF_Undo,
-        "osfUp" => Keysym::OSF_Up,
-        "Oslash" => Keysym::Oslash,
-        "oslash" => Keysym::oslash,
-        "Otilde" => Keysym::Otilde,
-        "otilde" => Keysym::otilde,
-        "overbar" => Keysym::overbar,
-        "Overlay1_Enable" => Keysym::Overlay1_Enable,
-        "Overlay2_Enable" => Keysym::Overlay2_Enable,
-        "overline" => Keysym::overline,
-        "P" => Keysym::P,
-        "p" => Keysym::p,
-        "Pabovedot" => Keysym::Pabovedot,
-        "pabovedot" => Keysym::pabovedot,
-        "Page_Down" => Keysym::Page_Down,
-        "Page_Up" => Keysym::Page_Up,
-        "paragraph" => Keysym::paragraph,
-        "parenleft" => Keysym::parenleft,
-        "parenright" => Keysym::parenright,
-        "partdifferential" => Keysym::partdifferential,
-        "partialderivative" => Keysym::partialderivative,
-        "Pause" => Keysym::Pause,
-        "percent" => Keysym::percent,
-        "period" => Keysym::period,
-        "periodcentered" => Keysym::periodcentered,
-        "permille" => Keysym::permille,
-        "PesetaSign" => Keysym::PesetaSign,
-        "phonographcopyright" => Keysym::phonographcopyright,
-        "plus" => Keysym::plus,
-        "plusminus" => Keysym::plusminus,
-        "Pointer_Accelerate" => Keysym::Pointer_Accelerate,
-        "Pointer_Button1" => Keysym::Pointer_Button1,
-        "Pointer_Button2" => Keysym::Pointer_Button2,
-        "Pointer_Button3" => Keysym::Pointer_Button3,
-        "Pointer_Button4" => Keysym::Pointer_Button4,
-        "Pointer_Button5" => Keysym::Pointer_Button5,
-        "Pointer_Button_Dflt" => Keysym::Pointer_Button_Dflt,
-        "Pointer_DblClick1" => Keysym::Pointer_DblClick1,
-        "Pointer_DblClick2" => Keysym::Pointer_DblClick2,
-        "Pointer_DblClick3" => Keysym::Pointer_DblClick3,
-        "Pointer_DblClick4" => Keysym::Pointer_DblClick4,
-        "Pointer_DblClick5" => Keysym::Pointer_DblClick5,
-        "Pointer_DblClick_Dflt" => Keysym::Pointer_DblClick_Dflt,
-        "Pointer_DfltBtnNext" => Keysym::Pointer_DfltBtnNext,
-        "Pointer_DfltBtnPrev" => Keysym::Pointer_DfltBtnPrev,
-        "Pointer_Down" => Keysym::Pointer_Down,
-        "Pointer_DownLeft" => Keysym::Pointer_DownLeft,
-        "Pointer_DownRight" => Keysym::Pointer_DownRight,
-        "Pointer_Drag1" => Keysym::Pointer_Drag1,
-        "Pointer_Drag2" => Keysym::Pointer_Drag2,
-        "Pointer_Drag3" => Keysym::Pointer_Drag3,
-        "Pointer_Drag4" => Keysym::Pointer_Drag4,
-        "Pointer_Drag5" => Keysym::Pointer_Drag5,
-        "Pointer_Drag_Dflt" => Keysym::Pointer_Drag_Dflt,
-        "Pointer_EnableKeys" => Keysym::Pointer_EnableKeys,
-        "Pointer_Left" => Keysym::Pointer_Left,
-        "Pointer_Right" => Keysym::Pointer_Right,
-        "Pointer_Up" => Keysym::Pointer_Up,
-        "Pointer_UpLeft" => Keysym::Pointer_UpLeft,
-        "Pointer_UpRight" => Keysym::Pointer_UpRight,
-        "prescription" => Keysym::prescription,
-        "Prev_Virtual_Screen" => Keysym::Prev_Virtual_Screen,
-        "PreviousCandidate" => Keysym::PreviousCandidate,
-        "Print" => Keysym::Print,
-        "Prior" => Keysym::Prior,
-        "prolongedsound" => Keysym::prolongedsound,
-        "punctspace" => Keysym::punctspace,
-        "Q" => Keysym::Q,
-        "q" => Keysym::q,
-        "quad" => Keysym::quad,
-        "question" => Keysym::question,
-        "questiondown" => Keysym::questiondown,
-        "quotedbl" => Keysym::quotedbl,
-        "quoteleft" => Keysym::quoteleft,
-        "quoteright" => Keysym::quoteright,
-        "R" => Keysym::R,
-        "r" => Keysym::r,
-        "R1" => Keysym::R1,
-        "R10" => Keysym::R10,
-        "R11" => Keysym::R11,
-        "R12" => Keysym::R12,
-        "R13" => Keysym::R13,
-        "R14" => Keysym::R14,
-        "R15" => Keysym::R15,
-        "R2" => Keysym::R2,
-        "R3" => Keysym::R3,
-        "R4" => Keysym::R4,
-        "R5" => Keysym::R5,
-        "R6" => Keysym::R6,
-        "R7" => Keysym::R7,
-        "R8" => Keysym::R8,
-        "R9" => Keysym::R9,
-        "Racute" => Keysym::Racute,
-        "racute" => Keysym::racute,
-        "radical" => Keysym::radical,
-        "Rcaron" => Keysym::Rcaron,
-        "rcaron" => Keysym::rcaron,
-        "Rcedilla" => Keysym::Rcedilla,
-        "rcedilla" => Keysym::rcedilla,
-        "Redo" => Keysym::Redo,
-        "registered" => Keysym::registered,
-        "RepeatKeys_Enable" => Keysym::RepeatKeys_Enable,
-        "Reset" => Keysym::Reset,
-        "Return" => Keysym::Return,
-        "Right" => Keysym::Right,
-        "rightanglebracket" => Keysym::rightanglebracket,
-        "rightarrow" => Keysym::rightarrow,
-        "rightcaret" => Keysym::rightcaret,
-        "rightdoublequotemark" => Keysym::rightdoublequotemark,
-        "rightmiddlecurlybrace" => Keysym::rightmiddlecurlybrace,
-        "rightmiddlesummation" => Keysym::rightmiddlesummation,
-        "rightopentriangle" => Keysym::rightopentriangle,
-        "rightpointer" => Keysym::rightpointer,
-        "rightshoe" => Keysym::rightshoe,
-        "rightsinglequotemark" => Keysym::rightsinglequotemark,
-        "rightt" => Keysym::rightt,
-        "righttack" => Keysym::righttack,
-        "Romaji" => Keysym::Romaji,
-        "RupeeSign" => Keysym::RupeeSign,
-        "S" => Keysym::S,
-        "s" => Keysym::s,
-        "Sabovedot" => Keysym::Sabovedot,
-        "sabovedot" => Keysym::sabovedot,
-        "Sacute" => Keysym::Sacute,
-        "sacute" => Keysym::sacute,
-        "Scaron" => Keysym::Scaron,
-        "scaron" => Keysym::scaron,
-        "Scedilla" => Keysym::Scedilla,
-        "scedilla" => Keysym::scedilla,
-        "SCHWA" => Keysym::SCHWA,
-        "schwa" => Keysym::schwa,
-        "Scircumflex" => Keysym::Scircumflex,
-        "scircumflex" => Keysym::scircumflex,
-        "script_switch" => Keysym::script_switch,
-        "Scroll_Lock" => Keysym::Scroll_Lock,
-        "seconds" => Keysym::seconds,
-        "section" => Keysym::section,
-        "Select" => Keysym::Select,
-        "semicolon" => Keysym::semicolon,
-        "semivoicedsound" => Keysym::semivoicedsound,
-        "Serbian_DJE" => Keysym::Serbian_DJE,
-        "Serbian_dje" => Keysym::Serbian_dje,
-        "Serbian_DZE" => Keysym::Serbian_DZE,
-        "Serbian_dze" => Keysym::Serbian_dze,
-        "Serbian_JE" => Keysym::Serbian_JE,
-        "Serbian_je" => Keysym::Serbian_je,
-        "Serbian_LJE" => Keysym::Serbian_LJE,
-        "Serbian_lje" => Keysym::Serbian_lje,
-        "Serbian_NJE" => Keysym::Serbian_NJE,
-        "Serbian_nje" => Keysym::Serbian_nje,
-        "Serbian_TSHE" => Keysym::Serbian_TSHE,
-        "Serbian_tshe" => Keysym::Serbian_tshe,
-        "seveneighths" => Keysym::seveneighths,
-        "sevensubscript" => Keysym::sevensubscript,
-        "sevensuperior" => Keysym::sevensuperior,
-        "Shift_L" => Keysym::Shift_L,
-        "Shift_Lock" => Keysym::Shift_Lock,
-        "Shift_R" => Keysym::Shift_R,
-        "signaturemark" => Keysym::signaturemark,
-        "signifblank" => Keysym::signifblank,
-        "similarequal" => Keysym::similarequal,
-        "SingleCandidate" => Keysym::SingleCandidate,
-        "singlelowquotemark" => Keysym::singlelowquotemark,
-        "Sinh_a" => Keysym::Sinh_a,
-        "Sinh_aa" => Keysym::Sinh_aa,
-        "Sinh_aa2" => Keysym::Sinh_aa2,
-        "Sinh_ae" => Keysym::Sinh_ae,
-        "Sinh_ae2" => Keysym::Sinh_ae2,
-        "Sinh_aee" => Keysym::Sinh_aee,
-        "Sinh_aee2" => Keysym::Sinh_aee2,
-        "Sinh_ai" => Keysym::Sinh_ai,
-        "Sinh_ai2" => Keysym::Sinh_ai2,
-        "Sinh_al" => Keysym::Sinh_al,
-        "Sinh_au" => Keysym::Sinh_au,
-        "Sinh_au2" => Keysym::Sinh_au2,
-        "Sinh_ba" => Keysym::Sinh_ba,
-        "Sinh_bha" => Keysym::Sinh_bha,
-        "Sinh_ca" => Keysym::Sinh_ca,
-        "Sinh_cha" => Keysym::Sinh_cha,
-        "Sinh_dda" => Keysym::Sinh_dda,
-        "Sinh_ddha" => Keysym::Sinh_ddha,
-        "Sinh_dha" => Keysym::Sinh_dha,
-        "Sinh_dhha" => Keysym::Sinh_dhha,
-        "Sinh_e" => Keysym::Sinh_e,
-        "Sinh_e2" => Keysym::Sinh_e2,
-        "Sinh_ee" => Keysym::Sinh_ee,
-        "Sinh_ee2" => Keysym::Sinh_ee2,
-        "Sinh_fa" => Keysym::Sinh_fa,
-        "Sinh_ga" => Keysym::Sinh_ga,
-        "Sinh_gha" => Keysym::Sinh_gha,
-        "Sinh_h2" => Keysym::Sinh_h2,
-        "Sinh_ha" => Keysym::Sinh_ha,
-        "Sinh_i" => Keysym::Sinh_i,
-        "Sinh_i2" => Keysym::Sinh_i2,
-        "Sinh_ii" => Keysym::Sinh_ii,
-        "Sinh_ii2" => Keysym::Sinh_ii2,
-        "Sinh_ja" => Keysym::Sinh_ja,
-        "Sinh_jha" => Keysym::Sinh_jha,
-        "Sinh_jnya" => Keysym::Sinh_jnya,
-        "Sinh_ka" => Keysym::Sinh_ka,
-        "Sinh_kha" => Keysym::Sinh_kha,
-        "Sinh_kunddaliya" => Keysym::Sinh_kunddaliya,
-        "Sinh_la" => Keysym::Sinh_la,
-        "Sinh_lla" => Keysym::Sinh_lla,
-        "Sinh_lu" => Keysym::Sinh_lu,
-        "Sinh_lu2" => Keysym::Sinh_lu2,
-        "Sinh_luu" => Keysym::Sinh_luu,
-        "Sinh_luu2" => Keysym::Sinh_luu2,
-        "Sinh_ma" => Keysym::Sinh_ma,
-        "Sinh_mba" => Keysym::Sinh_mba,
-        "Sinh_na" => Keysym::Sinh_na,
-        "Sinh_ndda" => Keysym::Sinh_ndda,
-        "Sinh_ndha" => Keysym::Sinh_ndha,
-        "Sinh_ng" => Keysym::Sinh_ng,
-        "Sinh_ng2" => Keysym::Sinh_ng2,
-        "Sinh_nga" => Keysym::Sinh_nga,
-        "Sinh_nja" => Keysym::Sinh_nja,
-        "Sinh_nna" => Keysym::Sinh_nna,
-        "Sinh_nya" => Keysym::Sinh_nya,
-        "Sinh_o" => Keysym::Sinh_o,
-        "Sinh_o2" => Keysym::Sinh_o2,
-        "Sinh_oo" => Keysym::Sinh_oo,
-        "Sinh_oo2" => Keysym::Sinh_oo2,
-        "Sinh_pa" => Keysym::Sinh_pa,
-        "Sinh_pha" => Keysym::Sinh_pha,
-        "Sinh_ra" => Keysym::Sinh_ra,
-        "Sinh_ri" => Keysym::Sinh_ri,
-        "Sinh_rii" => Keysym::Sinh_rii,
-        "Sinh_ru2" => Keysym::Sinh_ru2,
-        "Sinh_ruu2" => Keysym::Sinh_ruu2,
-        "Sinh_sa" => Keysym::Sinh_sa,
-        "Sinh_sha" => Keysym::Sinh_sha,
-        "Sinh_ssha" => Keysym::Sinh_ssha,
-        "Sinh_tha" => Keysym::Sinh_tha,
-        "Sinh_thha" => Keysym::Sinh_thha,
-        "Sinh_tta" => Keysym::Sinh_tta,
-        "Sinh_ttha" => Keysym::Sinh_ttha,
-        "Sinh_u" => Keysym::Sinh_u,
-        "Sinh_u2" => Keysym::Sinh_u2,
-        "Sinh_uu" => Keysym::Sinh_uu,
-        "Sinh_uu2" => Keysym::Sinh_uu2,
-        "Sinh_va" => Keysym::Sinh_va,
-        "Sinh_ya" => Keysym::Sinh_ya,
-        "sixsubscript" => Keysym::sixsubscript,
-        "sixsuperior" => Keysym::sixsuperior,
-        "slash" => Keysym::slash,
-        "SlowKeys_Enable" => Keysym::SlowKeys_Enable,
-        "soliddiamond" => Keysym::soliddiamond,
-        "space" => Keysym::space,
-        "squareroot" => Keysym::squareroot,
-        "ssharp" => Keysym::ssharp,
-        "sterling" => Keysym::sterling,
-        "StickyKeys_Enable" => Keysym::StickyKeys_Enable,
-        "stricteq" => Keysym::stricteq,
-        "SunAgain" => Keysym::SUN_Again,
-        "SunAltGraph" => Keysym::SUN_AltGraph,
-        "SunAudioLowerVolume" => Keysym::SUN_AudioLowerVolume,
-        "SunAudioMute" => Keysym::SUN_AudioMute,
-        "SunAudioRaiseVolume" => Keysym::SUN_AudioRaiseVolume,
-        "SunCompose" => Keysym::SUN_Compose,
-        "SunCopy" => Keysym::SUN_Copy,
-        "SunCut" => Keysym::SUN_Cut,
-        "SunF36" => Keysym::SUN_F36,
-        "SunF37" => Keysym::SUN_F37,
-        "SunFA_Acute" => Keysym::SUN_FA_Acute,
-        "SunFA_Cedilla" => Keysym::SUN_FA_Cedilla,
-        "SunFA_Circum" => Keysym::SUN_FA_Circum,
-        "SunFA_Diaeresis" => Keysym::SUN_FA_Diaeresis,
-        "SunFA_Grave" => Keysym::SUN_FA_Grave,
-        "SunFA_Tilde" => Keysym::SUN_FA_Tilde,
-        "SunFind" => Keysym::SUN_Find,
-        "SunFront" => Keysym::SUN_Front,
-        "SunOpen" => Keysym::SUN_Open,
-        "SunPageDown" => Keysym::SUN_PageDown,
-        "SunPageUp" => Keysym::SUN_PageUp,
-        "SunPaste" => Keysym::SUN_Paste,
-        "SunPowerSwitch" => Keysym::SUN_PowerSwitch,
-        "SunPowerSwitchShift" => Keysym::SUN_PowerSwitchShift,
-        "SunPrint_Screen" => Keysym::SUN_Print_Screen,
-        "SunProps" => Keysym::SUN_Props,
-        "SunStop" => Keysym::SUN_Stop,
-        "SunSys_Req" => Keysym::SUN_Sys_Req,
-        "SunUndo" => Keysym::SUN_Undo,
-        "SunVideoDegauss" => Keysym::SUN_VideoDegauss,
-        "SunVideoLowerBrightness" => Keysym::SUN_VideoLowerBrightness,
-        "SunVideoRaiseBrightness" => Keysym::SUN_VideoRaiseBrightness,
-        "Super_L" => Keysym::Super_L,
-        "Super_R" => Keysym::Super_R,
-        "Sys_Req" => Keysym::Sys_Req,
-        "System" => Keysym::System,
-        "T" => Keysym::T,
-        "t" => Keysym::t,
-        "Tab" => Keysym::Tab,
-        "Tabovedot" => Keysym::Tabovedot,
-        "tabovedot" => Keysym::tabovedot,
-        "Tcaron" => Keysym::Tcaron,
-        "tcaron" => Keysym::tcaron,
-        "Tcedilla" => Keysym::Tcedilla,
-        "tcedilla" => Keysym::tcedilla,
-        "telephone" => Keysym::telephone,
-        "telephonerecorder" => Keysym::telephonerecorder,
-        "Terminate_Server" => Keysym::Terminate_Server,
-        "Thai_baht" => Keysym::Thai_baht,
-        "Thai_bobaimai" => Keysym::Thai_bobaimai,
-        "Thai_chochan" => Keysym::Thai_chochan,
-        "Thai_chochang" => Keysym::Thai_chochang,
-        "Thai_choching" => Keysym::Thai_choching,
-        "Thai_chochoe" => Keysym::Thai_chochoe,
-        "Thai_dochada" => Keysym::Thai_dochada,
-        "Thai_dodek" => Keysym::Thai_dodek,
-        "Thai_fofa" => Keysym::Thai_fofa,
-        "Thai_fofan" => Keysym::Thai_fofan,
-        "Thai_hohip" => Keysym::Thai_hohip,
-        "Thai_honokhuk" => Keysym::Thai_honokhuk,
-        "Thai_khokhai" => Keysym::Thai_khokhai,
-        "Thai_khokhon" => Keysym::Thai_khokhon,
-        "Thai_khokhuat" => Keysym::Thai_khokhuat,
-        "Thai_khokhwai" => Keysym::Thai_khokhwai,
-        "Thai_khorakhang" => Keysym::Thai_khorakhang,
-        "Thai_kokai" => Keysym::Thai_kokai,
-        "Thai_lakkhangyao" => Keysym::Thai_lakkhangyao,
-        "Thai_lekchet" => Keysym::Thai_lekchet,
-        "Thai_lekha" => Keysym::Thai_lekha,
-        "Thai_lekhok" => Keysym::Thai_lekhok,
-        "Thai_lekkao" => Keysym::Thai_lekkao,
-        "Thai_leknung" => Keysym::Thai_leknung,
-        "Thai_lekpaet" => Keysym::Thai_lekpaet,
-        "Thai_leksam" => Keysym::Thai_leksam,
-        "Thai_leksi" => Keysym::Thai_leksi,
-        "Thai_leksong" => Keysym::Thai_leksong,
-        "Thai_leksun" => Keysym::Thai_leksun,
-        "Thai_lochula" => Keysym::Thai_lochula,
-        "Thai_loling" => Keysym::Thai_loling,
-        "Thai_lu" => Keysym::Thai_lu,
-        "Thai_maichattawa" => Keysym::Thai_maichattawa,
-        "Thai_maiek" => Keysym::Thai_maiek,
-        "Thai_maihanakat" => Keysym::Thai_maihanakat,
-        "Thai_maihanakat_maitho" => Keysym::Thai_maihanakat_maitho,
-        "Thai_maitaikhu" => Keysym::Thai_maitaikhu,
-        "Thai_maitho" => Keysym::Thai_maitho,
-        "Thai_maitri" => Keysym::Thai_maitri,
-        "Thai_maiyamok" => Keysym::Thai_maiyamok,
-        "Thai_moma" => Keysym::Thai_moma,
-        "Thai_ngongu" => Keysym::Thai_ngongu,
-        "Thai_nikhahit" => Keysym::Thai_nikhahit,
-        "Thai_nonen" => Keysym::Thai_nonen,
-        "Thai_nonu" => Keysym::Thai_nonu,
-        "Thai_oang" => Keysym::Thai_oang,
-        "Thai_paiyannoi" => Keysym::Thai_paiyannoi,
-        "Thai_phinthu" => Keysym::Thai_phinthu,
-        "Thai_phophan" => Keysym::Thai_phophan,
-        "Thai_phophung" => Keysym::Thai_phophung,
-        "Thai_phosamphao" => Keysym::Thai_phosamphao,
-        "Thai_popla" => Keysym::Thai_popla,
-        "Thai_rorua" => Keysym::Thai_rorua,
-        "Thai_ru" => Keysym::Thai_ru,
-        "Thai_saraa" => Keysym::Thai_saraa,
-        "Thai_saraaa" => Keysym::Thai_saraaa,
-        "Thai_saraae" => Keysym::Thai_saraae,
-        "Thai_saraaimaimalai" => Keysym::Thai_saraaimaimalai,
-        "Thai_saraaimaimuan" => Keysym::Thai_saraaimaimuan,
-        "Thai_saraam" => Keysym::Thai_saraam,
-        "Thai_sarae" => Keysym::Thai_sarae,
-        "Thai_sarai" => Keysym::Thai_sarai,
-        "Thai_saraii" => Keysym::Thai_saraii,
-        "Thai_sarao" => Keysym::Thai_sarao,
-        "Thai_sarau" => Keysym::Thai_sarau,
-        "Thai_saraue" => Keysym::Thai_saraue,
-        "Thai_sarauee" => Keysym::Thai_sarauee,
-        "Thai_sarauu" => Keysym::Thai_sarauu,
-        "Thai_sorusi" => Keysym::Thai_sorusi,
-        "Thai_sosala" => Keysym::Thai_sosala,
-        "Thai_soso" => Keysym::Thai_soso,
-        "Thai_sosua" => Keysym::Thai_sosua,
-        "Thai_thanthakhat" => Keysym::Thai_thanthakhat,
-        "Thai_thonangmontho" => Keysym::Thai_thonangmontho,
-        "Thai_thophuthao" => Keysym::Thai_thophuthao,
-        "Thai_thothahan" => Keysym::Thai_thothahan,
-        "Thai_thothan" => Keysym::Thai_thothan,
-        "Thai_thothong" => Keysym::Thai_thothong,
-        "Thai_thothung" => Keysym::Thai_thothung,
-        "Thai_topatak" => Keysym::Thai_topatak,
-        "Thai_totao" => Keysym::Thai_totao,
-        "Thai_wowaen" => Keysym::Thai_wowaen,
-        "Thai_yoyak" => Keysym::Thai_yoyak,
-        "Thai_yoying" => Keysym::Thai_yoying,
-        "therefore" => Keysym::therefore,
-        "thinspace" => Keysym::thinspace,
-        "THORN" => Keysym::THORN,
-        "Thorn" => Keysym::Thorn,
-        "thorn" => Keysym::thorn,
-        "threeeighths" => Keysym::threeeighths,
-        "threefifths" => Keysym::threefifths,
-        "threequarters" => Keysym::threequarters,
-        "threesubscript" => Keysym::threesubscript,
-        "threesuperior" => Keysym::threesuperior,
-        "tintegral" => Keysym::tintegral,
-        "topintegral" => Keysym::topintegral,
-        "topleftparens" => Keysym::topleftparens,
-        "topleftradical" => Keysym::topleftradical,
-        "topleftsqbracket" => Keysym::topleftsqbracket,
-        "topleftsummation" => Keysym::topleftsummation,
-        "toprightparens" => Keysym::toprightparens,
-        "toprightsqbracket" => Keysym::toprightsqbracket,
-        "toprightsummation" => Keysym::toprightsummation,
-        "topt" => Keysym::topt,
-        "topvertsummationconnector" => Keysym::topvertsummationconnector,
-        "Touroku" => Keysym::Touroku,
-        "trademark" => Keysym::trademark,
-        "trademarkincircle" => Keysym::trademarkincircle,
-        "Tslash" => Keysym::Tslash,
-        "tslash" => Keysym::tslash,
-        "twofifths" => Keysym::twofifths,
-        "twosubscript" => Keysym::twosubscript,
-        "twosuperior" => Keysym::twosuperior,
-        "twothirds" => Keysym::twothirds,
-        "U" => Keysym::U,
-        "u" => Keysym::u,
-        "Uacute" => Keysym::Uacute,
-        "uacute" => Keysym::uacute,
-        "Ubelowdot" => Keysym::Ubelowdot,
-        "ubelowdot" => Keysym::ubelowdot,
-        "Ubreve" => Keysym::Ubreve,
-        "ubreve" => Keysym::ubreve,
-        "Ucircumflex" => Keysym::Ucircumflex,
-        "ucircumflex" => Keysym::ucircumflex,
-        "Udiaeresis" => Keysym::Udiaeresis,
-        "udiaeresis" => Keysym::udiaeresis,
-        "Udoubleacute" => Keysym::Udoubleacute,
-        "udoubleacute" => Keysym::udoubleacute,
-        "Ugrave" => Keysym::Ugrave,
-        "ugrave" => Keysym::ugrave,
-        "Uhook" => Keysym::Uhook,
-        "uhook" => Keysym::uhook,
-        "Uhorn" => Keysym::Uhorn,
-        "uhorn" => Keysym::uhorn,
-        "Uhornacute" => Keysym::Uhornacute,
-        "uhornacute" => Keysym::uhornacute,
-        "Uhornbelowdot" => Keysym::Uhornbelowdot,
-        "uhornbelowdot" => Keysym::uhornbelowdot,
-        "Uhorngrave" => Keysym::Uhorngrave,
-        "uhorngrave" => Keysym::uhorngrave,
-        "Uhornhook" => Keysym::Uhornhook,
-        "uhornhook" => Keysym::uhornhook,
-        "Uhorntilde" => Keysym::Uhorntilde,
-        "uhorntilde" => Keysym::uhorntilde,
-        "Ukrainian_GHE_WITH_UPTURN" => Keysym::Ukrainian_GHE_WITH_UPTURN,
-        "Ukrainian_ghe_with_upturn" => Keysym::Ukrainian_ghe_with_upturn,
-        "Ukrainian_I" => Keysym::Ukrainian_I,
-        "Ukrainian_i" => Keysym::Ukrainian_i,
-        "Ukrainian_IE" => Keysym::Ukrainian_IE,
-        "Ukrainian_ie" => Keysym::Ukrainian_ie,
-        "Ukrainian_YI" => Keysym::Ukrainian_YI,
-        "Ukrainian_yi" => Keysym::Ukrainian_yi,
-        "Ukranian_I" => Keysym::Ukranian_I,
-        "Ukranian_i" => Keysym::Ukranian_i,
-        "Ukranian_JE" => Keysym::Ukranian_JE,
-        "Ukranian_je" => Keysym::Ukranian_je,
-        "Ukranian_YI" => Keysym::Ukranian_YI,
-        "Ukranian_yi" => Keysym::Ukranian_yi,
-        "Umacron" => Keysym::Umacron,
-        "umacron" => Keysym::umacron,
-        "underbar" => Keysym::underbar,
-        "underscore" => Keysym::underscore,
-        "Undo" => Keysym::Undo,
-        "union" => Keysym::union,
-        "Uogonek" => Keysym::Uogonek,
-        "uogonek" => Keysym::uogonek,
-        "Up" => Keysym::Up,
-        "uparrow" => Keysym::uparrow,
-        "upcaret" => Keysym::upcaret,
-        "upleftcorner" => Keysym::upleftcorner,
-        "uprightcorner" => Keysym::uprightcorner,
-        "upshoe" => Keysym::upshoe,
-        "upstile" => Keysym::upstile,
-        "uptack" => Keysym::uptack,
-        "Uring" => Keysym::Uring,
-        "uring" => Keysym::uring,
-        "User" => Keysym::User,
-        "Utilde" => Keysym::Utilde,
-        "utilde" => Keysym::utilde,
-        "V" => Keysym::V,
-        "v" => Keysym::v,
-        "variation" => Keysym::variation,
-        "vertbar" => Keysym::vertbar,
-        "vertconnector" => Keysym::vertconnector,
-        "voicedsound" => Keysym::voicedsound,
-        "VoidSymbol" => Keysym::VoidSymbol,
-        "vt" => Keysym::vt,
-        "W" => Keysym::W,
-        "w" => Keysym::w,
-        "Wacute" => Keysym::Wacute,
-        "wacute" => Keysym::wacute,
-        "Wcircumflex" => Keysym::Wcircumflex,
-        "wcircumflex" => Keysym::wcircumflex,
-        "Wdiaeresis" => Keysym::Wdiaeresis,
-        "wdiaeresis" => Keysym::wdiaeresis,
-        "Wgrave" => Keysym::Wgrave,
-        "wgrave" => Keysym::wgrave,
-        "WonSign" => Keysym::WonSign,
-        "X" => Keysym::X,
-        "x" => Keysym::x,
-        "Xabovedot" => Keysym::Xabovedot,
-        "xabovedot" => Keysym::xabovedot,
-        "XF8610ChannelsDown" => Keysym::XF86_10ChannelsDown,
-        "XF8610ChannelsUp" => Keysym::XF86_10ChannelsUp,
-        "XF863DMode" => Keysym::XF86_3DMode,
-        "XF86AddFavorite" => Keysym::XF86_AddFavorite,
-        "XF86Addressbook" => Keysym::XF86_Addressbook,
-        "XF86ALSToggle" => Keysym::XF86_ALSToggle,
-        "XF86ApplicationLeft" => Keysym::XF86_ApplicationLeft,
-        "XF86ApplicationRight" => Keysym::XF86_ApplicationRight,
-        "XF86AppSelect" => Keysym::XF86_AppSelect,
-        "XF86AspectRatio" => Keysym::XF86_AspectRatio,
-        "XF86Assistant" => Keysym::XF86_Assistant,
-        "XF86AttendantOff" => Keysym::XF86_AttendantOff,
-        "XF86AttendantOn" => Keysym::XF86_AttendantOn,
-        "XF86AttendantToggle" => Keysym::XF86_AttendantToggle,
-        "XF86Audio" => Keysym::XF86_Audio,
-        "XF86AudioCycleTrack" => Keysym::XF86_AudioCycleTrack,
-        "XF86AudioDesc" => Keysym::XF86_AudioDesc,
-        "XF86AudioForward" => Keysym::XF86_AudioForward,
-        "XF86AudioLowerVolume" => Keysym::XF86_AudioLowerVolume,
-        "XF86AudioMedia" => Keysym::XF86_AudioMedia,
-        "XF86AudioMicMute" => Keysym::XF86_AudioMicMute,
-        "XF86AudioMute" => Keysym::XF86_AudioMute,
-        "XF86AudioNext" => Keysym::XF86_AudioNext,
-        "XF86AudioPause" => Keysym::XF86_AudioPause,
-        "XF86AudioPlay" => Keysym::XF86_AudioPlay,
-        "XF86AudioPreset" => Keysym::XF86_AudioPreset,
-        "XF86AudioPrev" => Keysym::XF86_AudioPrev,
-        "XF86AudioRaiseVolume" => Keysym::XF86_AudioRaiseVolume,
-        "XF86AudioRandomPlay" => Keysym::XF86_AudioRandomPlay,
-        "XF86AudioRecord" => Keysym::XF86_AudioRecord,
-        "XF86AudioRepeat" => Keysym::XF86_AudioRepeat,
-        "XF86AudioRewind" => Keysym::XF86_AudioRewind,
-        "XF86AudioStop" => Keysym::XF86_AudioStop,
-        "XF86AutopilotEngageToggle" => Keysym::XF86_AutopilotEngageToggle,
-        "XF86Away" => Keysym::XF86_Away,
-        "XF86Back" => Keysym::XF86_Back,
-        "XF86BackForward" => Keysym::XF86_BackForward,
-        "XF86Battery" => Keysym::XF86_Battery,
-        "XF86Blue" => Keysym::XF86_Blue,
-        "XF86Bluetooth" => Keysym::XF86_Bluetooth,
-        "XF86Book" => Keysym::XF86_Book,
-        "XF86Break" => Keysym::XF86_Break,
-        "XF86BrightnessAdjust" => Keysym::XF86_BrightnessAdjust,
-        "XF86BrightnessAuto" => Keysym::XF86_BrightnessAuto,
-        "XF86BrightnessMax" => Keysym::XF86_BrightnessMax,
-        "XF86BrightnessMin" => Keysym::XF86_BrightnessMin,
-        "XF86Buttonconfig" => Keysym::XF86_Buttonconfig,
-        "XF86Calculater" => Keysym::XF86_Calculater,
-        "XF86Calculator" => Keysym::XF86_Calculator,
-        "XF86Calendar" => Keysym::XF86_Calendar,
-        "XF86CameraAccessDisable" => Keysym::XF86_CameraAccessDisable,
-        "XF86CameraAccessEnable" => Keysym::XF86_CameraAccessEnable,
-        "XF86CameraAccessToggle" => Keysym::XF86_CameraAccessToggle,
-        "XF86CameraDown" => Keysym::XF86_CameraDown,
-        "XF86CameraFocus" => Keysym::XF86_CameraFocus,
-        "XF86CameraLeft" => Keysym::XF86_CameraLeft,
-        "XF86CameraRight" => Keysym::XF86_CameraRight,
-        "XF86CameraUp" => Keysym::XF86_CameraUp,
-        "XF86CameraZoomIn" => Keysym::XF86_CameraZoomIn,
-        "XF86CameraZoomOut" => Keysym::XF86_CameraZoomOut,
-        "XF86CD" => Keysym::XF86_CD,
-        "XF86ChannelDown" => Keysym::XF86_ChannelDown,
-        "XF86ChannelUp" => Keysym::XF86_ChannelUp,
-        "XF86Clear" => Keysym::XF86_Clear,
-        "XF86ClearGrab" => Keysym::XF86_ClearGrab,
-        "XF86ClearvuSonar" => Keysym::XF86_ClearvuSonar,
-        "XF86Close" => Keysym::XF86_Close,
-        "XF86Community" => Keysym::XF86_Community,
-        "XF86ContextMenu" => Keysym::XF86_ContextMenu,
-        "XF86ContrastAdjust" => Keysym::XF86_ContrastAdjust,
-        "XF86ControlPanel" => Keysym::XF86_ControlPanel,
-        "XF86Copy" => Keysym::XF86_Copy,
-        "XF86Cut" => Keysym::XF86_Cut,
-        "XF86CycleAngle" => Keysym::XF86_CycleAngle,
-        "XF86Data" => Keysym::XF86_Data,
-        "XF86Database" => Keysym::XF86_Database,
-        "XF86Dictate" => Keysym::XF86_Dictate,
-        "XF86Display" => Keysym::XF86_Display,
-        "XF86DisplayOff" => Keysym::XF86_DisplayOff,
-        "XF86DisplayToggle" => Keysym::XF86_DisplayToggle,
-        "XF86Documents" => Keysym::XF86_Documents,
-        "XF86DOS" => Keysym::XF86_DOS,
-        "XF86DualRangeRadar" => Keysym::XF86_DualRangeRadar,
-        "XF86DVD" => Keysym::XF86_DVD,
-        "XF86Editor" => Keysym::XF86_Editor,
-        "XF86Eject" => Keysym::XF86_Eject,
-        "XF86EmojiPicker" => Keysym::XF86_EmojiPicker,
-        "XF86Excel" => Keysym::XF86_Excel,
-        "XF86Explorer" => Keysym::XF86_Explorer,
-        "XF86FastReverse" => Keysym::XF86_FastReverse,
-        "XF86Favorites" => Keysym::XF86_Favorites,
-        "XF86Finance" => Keysym::XF86_Finance,
-        "XF86FishingChart" => Keysym::XF86_FishingChart,
-        "XF86Fn" => Keysym::XF86_Fn,
-        "XF86Fn_Esc" => Keysym::XF86_Fn_Esc,
-        "XF86FnRightShift" => Keysym::XF86_FnRightShift,
-        "XF86Forward" => Keysym::XF86_Forward,
-        "XF86FrameBack" => Keysym::XF86_FrameBack,
-        "XF86FrameForward" => Keysym::XF86_FrameForward,
-        "XF86FullScreen" => Keysym::XF86_FullScreen,
-        "XF86Game" => Keysym::XF86_Game,
-        "XF86Go" => Keysym::XF86_Go,
-        "XF86GraphicsEditor" => Keysym::XF86_GraphicsEditor,
-        "XF86Green" => Keysym::XF86_Green,
-        "XF86HangupPhone" => Keysym::XF86_HangupPhone,
-        "XF86Hibernate" => Keysym::XF86_Hibernate,
-        "XF86History" => Keysym::XF86_History,
-        "XF86HomePage" => Keysym::XF86_HomePage,
-        "XF86HotLinks" => Keysym::XF86_HotLinks,
-        "XF86Images" => Keysym::XF86_Images,
-        "XF86Info" => Keysym::XF86_Info,
-        "XF86iTouch" => Keysym::XF86_iTouch,
-        "XF86Journal" => Keysym::XF86_Journal,
-        "XF86KbdBrightnessDown" => Keysym::XF86_KbdBrightnessDown,
-        "XF86KbdBrightnessUp" => Keysym::XF86_KbdBrightnessUp,
-        "XF86KbdInputAssistAccept" => Keysym::XF86_KbdInputAssistAccept,
-        "XF86KbdInputAssistCancel" => Keysym::XF86_KbdInputAssistCancel,
-        "XF86KbdInputAssistNext" => Keysym::XF86_KbdInputAssistNext,
-        "XF86KbdInputAssistNextgroup" => Keysym::XF86_KbdInputAssistNextgroup,
-        "XF86KbdInputAssistPrev" => Keysym::XF86_KbdInputAssistPrev,
-        "XF86KbdInputAssistPrevgroup" => Keysym::XF86_KbdInputAssistPrevgroup,
-        "XF86KbdLcdMenu1" => Keysym::XF86_KbdLcdMenu1,
-        "XF86KbdLcdMenu2" => Keysym::XF86_KbdLcdMenu2,
-        "XF86KbdLcdMenu3" => Keysym::XF86_KbdLcdMenu3,
-        "XF86KbdLcdMenu4" => Keysym::XF86_KbdLcdMenu4,
-        "XF86KbdLcdMenu5" => Keysym::XF86_KbdLcdMenu5,
-        "XF86KbdLightOnOff" => Keysym::XF86_KbdLightOnOff,
-        "XF86Keyboard" => Keysym::XF86_Keyboard,
-        "XF86Launch0" => Keysym::XF86_Launch0,
-        "XF86Launch1" => Keysym::XF86_Launch1,
-        "XF86Launch2" => Keysym::XF86_Launch2,
-        "XF86Launch3" => Keysym::XF86_Launch3,
-        "XF86Launch4" => Keysym::XF86_Launch4,
-        "XF86Launch5" => Keysym::XF86_Launch5,
-        "XF86Launch6" => Keysym::XF86_Launch6,
-        "XF86Launch7" => Keysym::XF86_Launch7,
-        "XF86Launch8" => Keysym::XF86_Launch8,
-        "XF86Launch9" => Keysym::XF86_Launch9,
-        "XF86LaunchA" => Keysym::XF86_LaunchA,
-        "XF86LaunchB" => Keysym::XF86_LaunchB,
-        "XF86LaunchC" => Keysym::XF86_LaunchC,
-        "XF86LaunchD" => Keysym::XF86_LaunchD,
-        "XF86LaunchE" => Keysym::XF86_LaunchE,
-        "XF86LaunchF" => Keysym::XF86_LaunchF,
-        "XF86LeftDown" => Keysym::XF86_LeftDown,
-        "XF86LeftUp" => Keysym::XF86_LeftUp,
-        "XF86LightBulb" => Keysym::XF86_LightBulb,
-        "XF86LightsToggle" => Keysym::XF86_LightsToggle,
-        "XF86LogGrabInfo" => Keysym::XF86_LogGrabInfo,
-        "XF86LogOff" => Keysym::XF86_LogOff,
-        "XF86LogWindowTree" => Keysym::XF86_LogWindowTree,
-        "XF86Macro1" => Keysym::XF86_Macro1,
-        "XF86Macro10" => Keysym::XF86_Macro10,
-        "XF86Macro11" => Keysym::XF86_Macro11,
-        "XF86Macro12" => Keysym::XF86_Macro12,
-        "XF86Macro13" => Keysym::XF86_Macro13,
-        "XF86Macro14" => Keysym::XF86_Macro14,
-        "XF86Macro15" => Keysym::XF86_Macro15,
-        "XF86Macro16" => Keysym::XF86_Macro16,
-        "XF86Macro17" => Keysym::XF86_Macro17,
-        "XF86Macro18" => Keysym::XF86_Macro18,
-        "XF86Macro19" => Keysym::XF86_Macro19,
-        "XF86Macro2" => Keysym::XF86_Macro2,
-        "XF86Macro20" => Keysym::XF86_Macro20,
-        "XF86Macro21" => Keysym::XF86_Macro21,
-        "XF86Macro22" => Keysym::XF86_Macro22,
-        "XF86Macro23" => Keysym::XF86_Macro23,
-        "XF86Macro24" => Keysym::XF86_Macro24,
-        "XF86Macro25" => Keysym::XF86_Macro25,
-        "XF86Macro26" => Keysym::XF86_Macro26,
-        "XF86Macro27" => Keysym::XF86_Macro27,
-        "XF86Macro28" => Keysym::XF86_Macro28,
-        "XF86Macro29" => Keysym::XF86_Macro29,
-        "XF86Macro3" => Keysym::XF86_Macro3,
-        "XF86Macro30" => Keysym::XF86_Macro30,
-        "XF86Macro4" => Keysym::XF86_Macro4,
-        "XF86Macro5" => Keysym::XF86_Macro5,
-        "XF86Macro6" => Keysym::XF86_Macro6,
-        "XF86Macro7" => Keysym::XF86_Macro7,
-        "XF86Macro8" => Keysym::XF86_Macro8,
-        "XF86Macro9" => Keysym::XF86_Macro9,
-        "XF86MacroPreset1" => Keysym::XF86_MacroPreset1,
-        "XF86MacroPreset2" => Keysym::XF86_MacroPreset2,
-        "XF86MacroPreset3" => Keysym::XF86_MacroPreset3,
-        "XF86MacroPresetCycle" => Keysym::XF86_MacroPresetCycle,
-        "XF86MacroRecordStart" => Keysym::XF86_MacroRecordStart,
-        "XF86MacroRecordStop" => Keysym::XF86_MacroRecordStop,
-        "XF86Mail" => Keysym::XF86_Mail,
-        "XF86MailForward" => Keysym::XF86_MailForward,
-        "XF86Market" => Keysym::XF86_Market,
-        "XF86MarkWaypoint" => Keysym::XF86_MarkWaypoint,
-        "XF86MediaRepeat" => Keysym::XF86_MediaRepeat,
-        "XF86MediaTopMenu" => Keysym::XF86_MediaTopMenu,
-        "XF86Meeting" => Keysym::XF86_Meeting,
-        "XF86Memo" => Keysym::XF86_Memo,
-        "XF86MenuKB" => Keysym::XF86_MenuKB,
-        "XF86MenuPB" => Keysym::XF86_MenuPB,
-        "XF86Messenger" => Keysym::XF86_Messenger,
-        "XF86ModeLock" => Keysym::XF86_ModeLock,
-        "XF86MonBrightnessCycle" => Keysym::XF86_MonBrightnessCycle,
-        "XF86MonBrightnessDown" => Keysym::XF86_MonBrightnessDown,
-        "XF86MonBrightnessUp" => Keysym::XF86_MonBrightnessUp,
-        "XF86Music" => Keysym::XF86_Music,
-        "XF86MyComputer" => Keysym::XF86_MyComputer,
-        "XF86MySites" => Keysym::XF86_MySites,
-        "XF86NavChart" => Keysym::XF86_NavChart,
-        "XF86NavInfo" => Keysym::XF86_NavInfo,
-        "XF86New" => Keysym::XF86_New,
-        "XF86News" => Keysym::XF86_News,
-        "XF86Next_VMode" => Keysym::XF86_Next_VMode,
-        "XF86NextElement" => Keysym::XF86_NextElement,
-        "XF86NextFavorite" => Keysym::XF86_NextFavorite,
-        "XF86NotificationCenter" => Keysym::XF86_NotificationCenter,
-        "XF86Numeric0" => Keysym::XF86_Numeric0,
-        "XF86Numeric1" => Keysym::XF86_Numeric1,
-        "XF86Numeric11" => Keysym::XF86_Numeric11,
-        "XF86Numeric12" => Keysym::XF86_Numeric12,
-        "XF86Numeric2" => Keysym::XF86_Numeric2,
-        "XF86Numeric3" => Keysym::XF86_Numeric3,
-        "XF86Numeric4" => Keysym::XF86_Numeric4,
-        "XF86Numeric5" => Keysym::XF86_Numeric5,
-        "XF86Numeric6" => Keysym::XF86_Numeric6,
-        "XF86Numeric7" => Keysym::XF86_Numeric7,
-        "XF86Numeric8" => Keysym::XF86_Numeric8,
-        "XF86Numeric9" => Keysym::XF86_Numeric9,
-        "XF86NumericA" => Keysym::XF86_NumericA,
-        "XF86NumericB" => Keysym::XF86_NumericB,
-        "XF86NumericC" => Keysym::XF86_NumericC,
-        "XF86NumericD" => Keysym::XF86_NumericD,
-        "XF86NumericPound" => Keysym::XF86_NumericPound,
-        "XF86NumericStar" => Keysym::XF86_NumericStar,
-        "XF86OfficeHome" => Keysym::XF86_OfficeHome,
-        "XF86OnScreenKeyboard" => Keysym::XF86_OnScreenKeyboard,
-        "XF86Open" => Keysym::XF86_Open,
-        "XF86OpenURL" => Keysym::XF86_OpenURL,
-        "XF86Option" => Keysym::XF86_Option,
-        "XF86Paste" => Keysym::XF86_Paste,
-        "XF86PauseRecord" => Keysym::XF86_PauseRecord,
-        "XF86Phone" => Keysym::XF86_Phone,
-        "XF86PickupPhone" => Keysym::XF86_PickupPhone,
-        "XF86Pictures" => Keysym::XF86_Pictures,
-        "XF86PowerDown" => Keysym::XF86_PowerDown,
-        "XF86PowerOff" => Keysym::XF86_PowerOff,
-        "XF86Presentation" => Keysym::XF86_Presentation,
-        "XF86Prev_VMode" => Keysym::XF86_Prev_VMode,
-        "XF86PreviousElement" => Keysym::XF86_PreviousElement,
-        "XF86PrivacyScreenToggle" => Keysym::XF86_PrivacyScreenToggle,
-        "XF86Q" => Keysym::XF86_Q,
-        "XF86RadarOverlay" => Keysym::XF86_RadarOverlay,
-        "XF86Red" => Keysym::XF86_Red,
-        "XF86Refresh" => Keysym::XF86_Refresh,
-        "XF86Reload" => Keysym::XF86_Reload,
-        "XF86Reply" => Keysym::XF86_Reply,
-        "XF86RFKill" => Keysym::XF86_RFKill,
-        "XF86RightDown" => Keysym::XF86_RightDown,
-        "XF86RightUp" => Keysym::XF86_RightUp,
-        "XF86RockerDown" => Keysym::XF86_RockerDown,
-        "XF86RockerEnter" => Keysym::XF86_RockerEnter,
-        "XF86RockerUp" => Keysym::XF86_RockerUp,
-        "XF86RootMenu" => Keysym::XF86_RootMenu,
-        "XF86RotateWindows" => Keysym::XF86_RotateWindows,
-        "XF86RotationKB" => Keysym::XF86_RotationKB,
-        "XF86RotationLockToggle" => Keysym::XF86_RotationLockToggle,
-        "XF86RotationPB" => Keysym::XF86_RotationPB,
-        "XF86Save" => Keysym::XF86_Save,
-        "XF86ScreenSaver" => Keysym::XF86_ScreenSaver,
-        "XF86Screensaver" => Keysym::XF86_Screensaver,
-        "XF86ScrollClick" => Keysym::XF86_ScrollClick,
-        "XF86ScrollDown" => Keysym::XF86_ScrollDown,
-        "XF86ScrollUp" => Keysym::XF86_ScrollUp,
-        "XF86Search" => Keysym::XF86_Search,
-        "XF86Select" => Keysym::XF86_Select,
-        "XF86SelectiveScreenshot" => Keysym::XF86_SelectiveScreenshot,
-        "XF86Send" => Keysym::XF86_Send,
-        "XF86Shop" => Keysym::XF86_Shop,
-        "XF86SidevuSonar" => Keysym::XF86_SidevuSonar,
-        "XF86SingleRangeRadar" => Keysym::XF86_SingleRangeRadar,
-        "XF86Sleep" => Keysym::XF86_Sleep,
-        "XF86SlowReverse" => Keysym::XF86_SlowReverse,
-        "XF86Sos" => Keysym::XF86_Sos,
-        "XF86Spell" => Keysym::XF86_Spell,
-        "XF86SpellCheck" => Keysym::XF86_SpellCheck,
-        "XF86SplitScreen" => Keysym::XF86_SplitScreen,
-        "XF86Standby" => Keysym::XF86_Standby,
-        "XF86Start" => Keysym::XF86_Start,
-        "XF86Stop" => Keysym::XF86_Stop,
-        "XF86StopRecord" => Keysym::XF86_StopRecord,
-        "XF86Subtitle" => Keysym::XF86_Subtitle,
-        "XF86Support" => Keysym::XF86_Support,
-        "XF86Suspend" => Keysym::XF86_Suspend,
-        "XF86Switch_VT_1" => Keysym::XF86_Switch_VT_1,
-        "XF86Switch_VT_10" => Keysym::XF86_Switch_VT_10,
-        "XF86Switch_VT_11" => Keysym::XF86_Switch_VT_11,
-        "XF86Switch_VT_12" => Keysym::XF86_Switch_VT_12,
-        "XF86Switch_VT_2" => Keysym::XF86_Switch_VT_2,
-        "XF86Switch_VT_3" => Keysym::XF86_Switch_VT_3,
-        "XF86Switch_VT_4" => Keysym::XF86_Switch_VT_4,
-        "XF86Switch_VT_5" => Keysym::XF86_Switch_VT_5,
-        "XF86Switch_VT_6" => Keysym::XF86_Switch_VT_6,
-        "XF86Switch_VT_7" => Keysym::XF86_Switch_VT_7,
-        "XF86Switch_VT_8" => Keysym::XF86_Switch_VT_8,
-        "XF86Switch_VT_9" => Keysym::XF86_Switch_VT_9,
-        "XF86Taskmanager" => Keysym::XF86_Taskmanager,
-        "XF86TaskPane" => Keysym::XF86_TaskPane,
-        "XF86Terminal" => Keysym::XF86_Terminal,
-        "XF86Time" => Keysym::XF86_Time,
-        "XF86ToDoList" => Keysym::XF86_ToDoList,
-        "XF86Tools" => Keysym::XF86_Tools,
-        "XF86TopMenu" => Keysym::XF86_TopMenu,
-        "XF86TouchpadOff" => Keysym::XF86_TouchpadOff,
-        "XF86TouchpadOn" => Keysym::XF86_TouchpadOn,
-        "XF86TouchpadToggle" => Keysym::XF86_TouchpadToggle,
-        "XF86TraditionalSonar" => Keysym::XF86_TraditionalSonar,
-        "XF86Travel" => Keysym::XF86_Travel,
-        "XF86Ungrab" => Keysym::XF86_Ungrab,
-        "XF86Unmute" => Keysym::XF86_Unmute,
-        "XF86User1KB" => Keysym::XF86_User1KB,
-        "XF86User2KB" => Keysym::XF86_User2KB,
-        "XF86UserPB" => Keysym::XF86_UserPB,
-        "XF86UWB" => Keysym::XF86_UWB,
-        "XF86VendorHome" => Keysym::XF86_VendorHome,
-        "XF86Video" => Keysym::XF86_Video,
-        "XF86VideoPhone" => Keysym::XF86_VideoPhone,
-        "XF86View" => Keysym::XF86_View,
-        "XF86VOD" => Keysym::XF86_VOD,
-        "XF86VoiceCommand" => Keysym::XF86_VoiceCommand,
-        "XF86Voicemail" => Keysym::XF86_Voicemail,
-        "XF86WakeUp" => Keysym::XF86_WakeUp,
-        "XF86WebCam" => Keysym::XF86_WebCam,
-        "XF86WheelButton" => Keysym::XF86_WheelButton,
-        "XF86WLAN" => Keysym::XF86_WLAN,
-        "XF86Word" => Keysym::XF86_Word,
-        "XF86WPSButton" => Keysym::XF86_WPSButton,
-        "XF86WWAN" => Keysym::XF86_WWAN,
-        "XF86WWW" => Keysym::XF86_WWW,
-        "XF86Xfer" => Keysym::XF86_Xfer,
-        "XF86Yellow" => Keysym::XF86_Yellow,
-        "XF86ZoomIn" => Keysym::XF86_ZoomIn,
-        "XF86ZoomOut" => Keysym::XF86_ZoomOut,
-        "XF86ZoomReset" => Keysym::XF86_ZoomReset,
-        "Y" => Keysym::Y,
-        "y" => Keysym::y,
-        "Yacute" => Keysym::Yacute,
-        "yacute" => Keysym::yacute,
-        "Ybelowdot" => Keysym::Ybelowdot,
-        "ybelowdot" => Keysym::ybelowdot,
-        "Ycircumflex" => Keysym::Ycircumflex,
-        "ycircumflex" => Keysym::ycircumflex,
-        "Ydiaeresis" => Keysym::Ydiaeresis,
-        "ydiaeresis" => Keysym::ydiaeresis,
-        "yen" => Keysym::yen,
-        "Ygrave" => Keysym::Ygrave,
-        "ygrave" => Keysym::ygrave,
-        "Yhook" => Keysym::Yhook,
-        "yhook" => Keysym::yhook,
-        "Ytilde" => Keysym::Ytilde,
-        "ytilde" => Keysym::ytilde,
-        "Z" => Keysym::Z,
-        "z" => Keysym::z,
-        "Zabovedot" => Keysym::Zabovedot,
-        "zabovedot" => Keysym::zabovedot,
-        "Zacute" => Keysym::Zacute,
-        "zacute" => Keysym::zacute,
-        "Zcaron" => Keysym::Zcaron,
-        "zcaron" => Keysym::zcaron,
-        "Zen_Koho" => Keysym::Zen_Koho,
-        "Zenkaku" => Keysym::Zenkaku,
-        "Zenkaku_Hankaku" => Keysym::Zenkaku_Hankaku,
-        "zerosubscript" => Keysym::zerosubscript,
-        "zerosuperior" => Keysym::zerosuperior,
-        "Zstroke" => Keysym::Zstroke,
-        "zstroke" => Keysym::zstroke,
-
-};
-
-use phf::phf_ordered_map;
-#[allow(unreachable_patterns)]
-pub(crate) static KEYSYM_TO_NAME: [(Keysym, &str); 2575] = [
-    (xkeysym::NO_SYMBOL, "NoSymbol"),
-    (Keysym::space, "space"),
-    (Keysym::exclam, "exclam"),
-    (Keysym::quotedbl, "quotedbl"),
-    (Keysym::numbersign, "numbersign"),
-    (Keysym::dollar, "dollar"),
-    (Keysym::percent, "percent"),
-    (Keysym::ampersand, "ampersand"),
-    (Keysym::apostrophe, "apostrophe"),
-    (Keysym::quoteright, "quoteright"),
-    (Keysym::parenleft, "parenleft"),
-    (Keysym::parenright, "parenright"),
-    (Keysym::asterisk, "asterisk"),
-    (Keysym::plus, "plus"),
-    (Keysym::comma, "comma"),
-    (Keysym::minus, "minus"),
-    (Keysym::period, "period"),
-    (Keysym::slash, "slash"),
-    (Keysym::_0, "0"),
-    (Keysym::_1, "1"),
-    (Keysym::_2, "2"),
-    (Keysym::_3, "3"),
-    (Keysym::_4, "4"),
-    (Keysym::_5, "5"),
-    (Keysym::_6, "6"),
-    (Keysym::_7, "7"),
-    (Keysym::_8, "8"),
-    (Keysym::_9, "9"),
-    (Keysym::colon, "colon"),
-    (Keysym::semicolon, "semicolon"),
-    (Keysym::less, "less"),
-    (Keysym::equal, "equal"),
-    (Keysym::greater, "greater"),
-    (Keysym::question, "question"),
-    (Keysym::at, "at"),
-    (Keysym::A, "A"),
-    (Keysym::B, "B"),
-    (Keysym::C, "C"),
-    (Keysym::D, "D"),
-    (Keysym::E, "E"),
-    (Keysym::F, "F"),
-    (Keysym::G, "G"),
-    (Keysym::H, "H"),
-    (Keysym::I, "I"),
-    (Keysym::J, "J"),
-    (Keysym::K, "K"),
-    (Keysym::L, "L"),
-    (Keysym::M, "M"),
-    (Keysym::N, "N"),
-    (Keysym::O, "O"),
-    (Keysym::P, "P"),
-    (Keysym::Q, "Q"),
-    (Keysym::R, "R"),
-    (Keysym::S, "S"),
-    (Keysym::T, "T"),
-    (Keysym::U, "U"),
-    (Keysym::V, "V"),
-    (Keysym::W, "W"),
-    (Keysym::X, "X"),
-    (Keysym::Y, "Y"),
-    (Keysym::Z, "Z"),
-    (Keysym::bracketleft, "bracketleft"),
-    (Keysym::backslash, "backslash"),
-    (Keysym::bracketright, "bracketright"),
-    (Keysym::asciicircum, "asciicircum"),
-    (Keysym::underscore, "underscore"),
-    (Keysym::grave, "grave"),
-    (Keysym::quoteleft, "quoteleft"),
-    (Keysym::a, "a"),
-    (Keysym::b, "b"),
-    (Keysym::c, "c"),
-    (Keysym::d, "d"),
-    (Keysym::e, "e"),
-    (Keysym::f, "f"),
-    (Keysym::g, "g"),
-    (Keysym::h, "h"),
-    (Keysym::i, "i"),
-    (Keysym::j, "j"),
-    (Keysym::k, "k"),
-    (Keysym::l, "l"),
-    (Keysym::m, "m"),
-    (Keysym::n, "n"),
-    (Keysym::o, "o"),
-    (Keysym::p, "p"),
-    (Keysym::q, "q"),
-    (Keysym::r, "r"),
-    (Keysym::s, "s"),
-    (Keysym::t, "t"),
-    (Keysym::u, "u"),
-    (Keysym::v, "v"),
-    (Keysym::w, "w"),
-    (Keysym::x, "x"),
-    (Keysym::y, "y"),
-    (Keysym::z, "z"),
-    (Keysym::braceleft, "braceleft"),
-    (Keysym::bar, "bar"),
-    (Keysym::braceright, "braceright"),
-    (Keysym::asciitilde, "asciitilde"),
-    (Keysym::nobreakspace, "nobreakspace"),
-    (Keysym::exclamdown, "exclamdown"),
-    (Keysym::cent, "cent"),
-    (Keysym::sterling, "sterling"),
-    (Keysym::currency, "currency"),
-    (Keysym::yen, "yen"),
-    (Keysym::brokenbar, "brokenbar"),
-    (Keysym::section, "section"),
-    (Keysym::diaeresis, "diaeresis"),
-    (Keysym::copyright, "copyright"),
-    (Keysym::ordfeminine, "ordfeminine"),
-    (Keysym::guillemotleft, "guillemotleft"),
-    (Keysym::guillemetleft, "guillemetleft"),
-    (Keysym::notsign, "notsign"),
-    (Keysym::hyphen, "hyphen"),
-    (Keysym::registered, "registered"),
-    (Keysym::macron, "macron"),
-    (Keysym::degree, "degree"),
-    (Keysym::plusminus, "plusminus"),
-    (Keysym::twosuperior, "twosuperior"),
-    (Keysym::threesuperior, "threesuperior"),
-    (Keysym::acute, "acute"),
-    (Keysym::mu, "mu"),
-    (Keysym::paragraph, "paragraph"),
-    (Keysym::periodcentered, "periodcentered"),
-    (Keysym::cedilla, "cedilla"),
-    (Keysym::onesuperior, "onesuperior"),
-    (Keysym::masculine, "masculine"),
-    (Keysym::ordmasculine, "ordmasculine"),
-    (Keysym::guillemotright, "guillemotright"),
-    (Keysym::guillemetright, "guillemetright"),
-    (Keysym::onequarter, "onequarter"),
-    (Keysym::onehalf, "onehalf"),
-    (Keysym::threequarters, "threequarters"),
-    (Keysym::questiondown, "questiondown"),
-    (Keysym::Agrave, "Agrave"),
-    (Keysym::Aacute, "Aacute"),
-    (Keysym::Acircumflex, "Acircumflex"),
-    (Keysym::Atilde, "Atilde"),
-    (Keysym::Adiaeresis, "Adiaeresis"),
-    (Keysym::Aring, "Aring"),
-    (Keysym::AE, "AE"),
-    (Keysym::Ccedilla, "Ccedilla"),
-    (Keysym::Egrave, "Egrave"),
-    (Keysym::Eacute, "Eacute"),
-    (Keysym::Ecircumflex, "Ecircumflex"),
-    (Keysym::Ediaeresis, "Ediaeresis"),
-    (Keysym::Igrave, "Igrave"),
-    (Keysym::Iacute, "Iacute"),
-    (Keysym::Icircumflex, "Icircumflex"),
-    (Keysym::Idiaeresis, "Idiaeresis"),
-    (Keysym::ETH, "ETH"),
-    (Keysym::Eth, "Eth"),
-    (Keysym::Ntilde, "Ntilde"),
-    (Keysym::Ograve, "Ograve"),
-    (Keysym::Oacute, "Oacute"),
-    (Keysym::Ocircumflex, "Ocircumflex"),
-    (Keysym::Otilde, "Otilde"),
-    (Keysym::Odiaeresis, "Odiaeresis"),
-    (Keysym::multiply, "multiply"),
-    (Keysym::Oslash, "Oslash"),
-    (Keysym::Ooblique, "Ooblique"),
-    (Keysym::Ugrave, "Ugrave"),
-    (Keysym::Uacute, "Uacute"),
-    (Keysym::Ucircumflex, "Ucircumflex"),
-    (Keysym::Udiaeresis, "Udiaeresis"),
-    (Keysym::Yacute, "Yacute"),
-    (Keysym::THORN, "THORN"),
-    (Keysym::Thorn, "Thorn"),
-    (Keysym::ssharp, "ssharp"),
-    (Keysym::agrave, "agrave"),
-    (Keysym::aacute, "aacute"),
-    (Keysym::acircumflex, "acircumflex"),
-    (Keysym::atilde, "atilde"),
-    (Keysym::adiaeresis, "adiaeresis"),
-    (Keysym::aring, "aring"),
-    (Keysym::ae, "ae"),
-    (Keysym::ccedilla, "ccedilla"),
-    (Keysym::egrave, "egrave"),
-    (Keysym::eacute, "eacute"),
-    (Keysym::ecircumflex, "ecircumflex"),
-    (Keysym::ediaeresis, "ediaeresis"),
-    (Keysym::igrave, "igrave"),
-    (Keysym::iacute, "iacute"),
-    (Keysym::icircumflex, "icircumflex"),
-    (Keysym::idiaeresis, "idiaeresis"),
-    (Keysym::eth, "eth"),
-    (Keysym::ntilde, "ntilde"),
-    (Keysym::ograve, "ograve"),
-    (Keysym::oacute, "oacute"),
-    (Keysym::ocircumflex, "ocircumflex"),
-    (Keysym::otilde, "otilde"),
-    (Keysym::odiaeresis, "odiaeresis"),
-    (Keysym::division, "division"),
-    (Keysym::oslash, "oslash"),
-    (Keysym::ooblique, "ooblique"),
-    (Keysym::ugrave, "ugrave"),
-    (Keysym::uacute, "uacute"),
-    (Keysym::ucircumflex, "ucircumflex"),
-    (Keysym::udiaeresis, "udiaeresis"),
-    (Keysym::yacute, "yacute"),
-    (Keysym::thorn, "thorn"),
-    (Keysym::ydiaeresis, "ydiaeresis"),
-    (Keysym::Aogonek, "Aogonek"),
-    (Keysym::breve, "breve"),
-    (Keysym::Lstroke, "Lstroke"),
-    (Keysym::Lcaron, "Lcaron"),
-    (Keysym::Sacute, "Sacute"),
-    (Keysym::Scaron, "Scaron"),
-    (Keysym::Scedilla, "Scedilla"),
-    (Keysym::Tcaron, "Tcaron"),
-    (Keysym::Zacute, "Zacute"),
-    (Keysym::Zcaron, "Zcaron"),
-    (Keysym::Zabovedot, "Zabovedot"),
-    (Keysym::aogonek, "aogonek"),
-    (Keysym::ogonek, "ogonek"),
-    (Keysym::lstroke, "lstroke"),
-    (Keysym::lcaron, "lcaron"),
-    (Keysym::sacute, "sacute"),
-    (Keysym::caron, "caron"),
-    (Keysym::scaron, "scaron"),
-    (Keysym::scedilla, "scedilla"),
-    (Keysym::tcaron, "tcaron"),
-    (Keysym::zacute, "zacute"),
-    (Keysym::doubleacute, "doubleacute"),
-    (Keysym::zcaron, "zcaron"),
-    (Keysym::zabovedot, "zabovedot"),
-    (Keysym::Racute, "Racute"),
-    (Keysym::Abreve, "Abreve"),
-    (Keysym::Lacute, "Lacute"),
-    (Keysym::Cacute, "Cacute"),
-    (Keysym::Ccaron, "Ccaron"),
-    (Keysym::Eogonek, "Eogonek"),
-    (Keysym::Ecaron, "Ecaron"),
-    (Keysym::Dcaron, "Dcaron"),
-    (Keysym::Dstroke, "Dstroke"),
-    (Keysym::Nacute, "Nacute"),
-    (Keysym::Ncaron, "Ncaron"),
-    (Keysym::Odoubleacute, "Odoubleacute"),
-    (Keysym::Rcaron, "Rcaron"),
-    (Keysym::Uring, "Uring"),
-    (Keysym::Udoubleacute, "Udoubleacute"),
-    (Keysym::Tcedilla, "Tcedilla"),
-    (Keysym::racute, "racute"),
-    (Keysym::abreve, "abreve"),
-    (Keysym::lacute, "lacute"),
-    (Keysym::cacute, "cacute"),
-    (Keysym::ccaron, "ccaron"),
-    (Keysym::eogonek, "eogonek"),
-    (Keysym::ecaron, "ecaron"),
-    (Keysym::dcaron, "dcaron"),
-    (Keysym::dstroke, "dstroke"),
-    (Keysym::nacute, "nacute"),
-    (Keysym::ncaron, "ncaron"),
-    (Keysym::odoubleacute, "odoubleacute"),
-    (Keysym::rcaron, "rcaron"),
-    (Keysym::uring, "uring"),
-    (Keysym::udoubleacute, "udoubleacute"),
-    (Keysym::tcedilla, "tcedilla"),
-    (Keysym::abovedot, "abovedot"),
-    (Keysym::Hstroke, "Hstroke"),
-    (Keysym::Hcircumflex, "Hcircumflex"),
-    (Keysym::Iabovedot, "Iabovedot"),
-    (Keysym::Gbreve, "Gbreve"),
-    (Keysym::Jcircumflex, "Jcircumflex"),
-    (Keysym::hstroke, "hstroke"),
-    (Keysym::hcircumflex, "hcircumflex"),
-    (Keysym::idotless, "idotless"),
-    (Keysym::gbreve, "gbreve"),
-    (Keysym::jcircumflex, "jcircumflex"),
-    (Keysym::Cabovedot, "Cabovedot"),
-    (Keysym::Ccircumflex, "Ccircumflex"),
-    (Keysym::Gabovedot, "Gabovedot"),
-    (Keysym::Gcircumflex, "Gcircumflex"),
-    (Keysym::Ubreve, "Ubreve"),
-    (Keysym::Scircumflex, "Scircumflex"),
-    (Keysym::cabovedot, "cabovedot"),
-    (Keysym::ccircumflex, "ccircumflex"),
-    (Keysym::gabovedot, "gabovedot"),
-    (Keysym::gcircumflex, "gcircumflex"),
-    (Keysym::ubreve, "ubreve"),
-    (Keysym::scircumflex, "scircumflex"),
-    (Keysym::kra, "kra"),
-    (Keysym::kappa, "kappa"),
-    (Keysym::Rcedilla, "Rcedilla"),
-    (Keysym::Itilde, "Itilde"),
-    (Keysym::Lcedilla, "Lcedilla"),
-    (Keysym::Emacron, "Emacron"),
-    (Keysym::Gcedilla, "Gcedilla"),
-    (Keysym::Tslash, "Tslash"),
-    (Keysym::rcedilla, "rcedilla"),
-    (Keysym::itilde, "itilde"),
-    (Keysym::lcedilla, "lcedilla"),
-    (Keysym::emacron, "emacron"),
-    (Keysym::gcedilla, "gcedilla"),
-    (Keysym::tslash, "tslash"),
-    (Keysym::ENG, "ENG"),
-    (Keysym::eng, "eng"),
-    (Keysym::Amacron, "Amacron"),
-    (Keysym::Iogonek, "Iogonek"),
-    (Keysym::Eabovedot, "Eabovedot"),
-    (Keysym::Imacron, "Imacron"),
-    (Keysym::Ncedilla, "Ncedilla"),
-    (Keysym::Omacron, "Omacron"),
-    (Keysym::Kcedilla, "Kcedilla"),
-    (Keysym::Uogonek, "Uogonek"),
-    (Keysym::Utilde, "Utilde"),
-    (Keysym::Umacron, "Umacron"),
-    (Keysym::amacron, "amacron"),
-    (Keysym::iogonek, "iogonek"),
-    (Keysym::eabovedot, "eabovedot"),
-    (Keysym::imacron, "imacron"),
-    (Keysym::ncedilla, "ncedilla"),
-    (Keysym::omacron, "omacron"),
-    (Keysym::kcedilla, "kcedilla"),
-    (Keysym::uogonek, "uogonek"),
-    (Keysym::utilde, "utilde"),
-    (Keysym::umacron, "umacron"),
-    (Keysym::overline, "overline"),
-    (Keysym::kana_fullstop, "kana_fullstop"),
-    (Keysym::kana_openingbracket, "kana_openingbracket"),
-    (Keysym::kana_closingbracket, "kana_closingbracket"),
-    (Keysym::kana_comma, "kana_comma"),
-    (Keysym::kana_conjunctive, "kana_conjunctive"),
-    (Keysym::kana_middledot, "kana_middledot"),
-    (Keysym::kana_WO, "kana_WO"),
-    (Keysym::kana_a, "kana_a"),
-    (Keysym::kana_i, "kana_i"),
-    (Keysym::kana_u, "kana_u"),
-    (Keysym::kana_e, "kana_e"),
-    (Keysym::kana_o, "kana_o"),
-    (Keysym::kana_ya, "kana_ya"),
-    (Keysym::kana_yu, "kana_yu"),
-    (Keysym::kana_yo, "kana_yo"),
-    (Keysym::kana_tsu, "kana_tsu"),
-    (Keysym::kana_tu, "kana_tu"),
-    (Keysym::prolongedsound, "prolongedsound"),
-    (Keysym::kana_A, "kana_A"),
-    (Keysym::kana_I, "kana_I"),
-    (Keysym::kana_U, "kana_U"),
-    (Keysym::kana_E, "kana_E"),
-    (Keysym::kana_O, "kana_O"),
-    (Keysym::kana_KA, "kana_KA"),
-    (Keysym::kana_KI, "kana_KI"),
-    (Keysym::kana_KU, "kana_KU"),
-    (Keysym::kana_KE, "kana_KE"),
-    (Keysym::kana_KO, "kana_KO"),
-    (Keysym::kana_SA, "kana_SA"),
-    (Keysym::kana_SHI, "kana_SHI"),
-    (Keysym::kana_SU, "kana_SU"),
-    (Keysym::kana_SE, "kana_SE"),
-    (Keysym::kana_SO, "kana_SO"),
-    (Keysym::kana_TA, "kana_TA"),
-    (Keysym::kana_CHI, "kana_CHI"),
-    (Keysym::kana_TI, "kana_TI"),
-    (Keysym::kana_TSU, "kana_TSU"),
-    (Keysym::kana_TU, "kana_TU"),
-    (Keysym::kana_TE, "kana_TE"),
-    (Keysym::kana_TO, "kana_TO"),
-    (Keysym::kana_NA, "kana_NA"),
-    (Keysym::kana_NI, "kana_NI"),
-    (Keysym::kana_NU, "kana_NU"),
-    (Keysym::kana_NE, "kana_NE"),
-    (Keysym::kana_NO, "kana_NO"),
-    (Keysym::kana_HA, "kana_HA"),
-    (Keysym::kana_HI, "kana_HI"),
-    (Keysym::kana_FU, "kana_FU"),
-    (Keysym::kana_HU, "kana_HU"),
-    (Keysym::kana_HE, "kana_HE"),
-    (Keysym::kana_HO, "kana_HO"),
-    (Keysym::kana_MA, "kana_MA"),
-    (Keysym::kana_MI, "kana_MI"),
-    (Keysym::kana_MU, "kana_MU"),
-    (Keysym::kana_ME, "kana_ME"),
-    (Keysym::kana_MO, "kana_MO"),
-    (Keysym::kana_YA, "kana_YA"),
-    (Keysym::kana_YU, "kana_YU"),
-    (Keysym::kana_YO, "kana_YO"),
-    (Keysym::kana_RA, "kana_RA"),
-    (Keysym::kana_RI, "kana_RI"),
-    (Keysym::kana_RU, "kana_RU"),
-    (Keysym::kana_RE, "kana_RE"),
-    (Keysym::kana_RO, "kana_RO"),
-    (Keysym::kana_WA, "kana_WA"),
-    (Keysym::kana_N, "kana_N"),
-    (Keysym::voicedsound, "voicedsound"),
-    (Keysym::semivoicedsound, "semivoicedsound"),
-    (Keysym::Arabic_comma, "Arabic_comma"),
-    (Keysym::Arabic_semicolon, "Arabic_semicolon"),
-    (Keysym::Arabic_question_mark, "Arabic_question_mark"),
-    (Keysym::Arabic_hamza, "Arabic_hamza"),
-    (Keysym::Arabic_maddaonalef, "Arabic_maddaonalef"),
-    (Keysym::Arabic_hamzaonalef, "Arabic_hamzaonalef"),
-    (Keysym::Arabic_hamzaonwaw, "Arabic_hamzaonwaw"),
-    (Keysym::Arabic_hamzaunderalef, "Arabic_hamzaunderalef"),
-    (Keysym::Arabic_hamzaonyeh, "Arabic_hamzaonyeh"),
-    (Keysym::Arabic_alef, "Arabic_alef"),
-    (Keysym::Arabic_beh, "Arabic_beh"),
-    (Keysym::Arabic_tehmarbuta, "Arabic_tehmarbuta"),
-    (Keysym::Arabic_teh, "Arabic_teh"),
-    (Keysym::Arabic_theh, "Arabic_theh"),
-    (Keysym::Arabic_jeem, "Arabic_jeem"),
-    (Keysym::Arabic_hah, "Arabic_hah"),
-    (Keysym::Arabic_khah, "Arabic_khah"),
-    (Keysym::Arabic_dal, "Arabic_dal"),
-    (Keysym::Arabic_thal, "Arabic_thal"),
-    (Keysym::Arabic_ra, "Arabic_ra"),
-    (Keysym::Arabic_zain, "Arabic_zain"),
-    (Keysym::Arabic_seen, "Arabic_seen"),
-    (Keysym::Arabic_sheen, "Arabic_sheen"),
-    (Keysym::Arabic_sad, "Arabic_sad"),
-    (Keysym::Arabic_dad, "Arabic_dad"),
-    (Keysym::Arabic_tah, "Arabic_tah"),
-    (Keysym::Arabic_zah, "Arabic_zah"),
-    (Keysym::Arabic_ain, "Arabic_ain"),
-    (Keysym::Arabic_ghain, "Arabic_ghain"),
-    (Keysym::Arabic_tatweel, "Arabic_tatweel"),
-    (Keysym::Arabic_feh, "Arabic_feh"),
-    (Keysym::Arabic_qaf, "Arabic_qaf"),
-    (Keysym::Arabic_kaf, "Arabic_kaf"),
-    (Keysym::Arabic_lam, "Arabic_lam"),
-    (Keysym::Arabic_meem, "Arabic_meem"),
-    (Keysym::Arabic_noon, "Arabic_noon"),
-    (Keysym::Arabic_ha, "Arabic_ha"),
-    (Keysym::Arabic_heh, "Arabic_heh"),
-    (Keysym::Arabic_waw, "Arabic_waw"),
-    (Keysym::Arabic_alefmaksura, "Arabic_alefmaksura"),
-    (Keysym::Arabic_yeh, "Arabic_yeh"),
-    (Keysym::Arabic_fathatan, "Arabic_fathatan"),
-    (Keysym::Arabic_dammatan, "Arabic_dammatan"),
-    (Keysym::Arabic_kasratan, "Arabic_kasratan"),
-    (Keysym::Arabic_fatha, "Arabic_fatha"),
-    (Keysym::Arabic_damma, "Arabic_damma"),
-    (Keysym::Arabic_kasra, "Arabic_kasra"),
-    (Keysym::Arabic_shadda, "Arabic_shadda"),
-    (Keysym::Arabic_sukun, "Arabic_sukun"),
-    (Keysym::Serbian_dje, "Serbian_dje"),
-    (Keysym::Macedonia_gje, "Macedonia_gje"),
-    (Keysym::Cyrillic_io, "Cyrillic_io"),
-    (Keysym::Ukrainian_ie, "Ukrainian_ie"),
-    (Keysym::Ukranian_je, "Ukranian_je"),
-    (Keysym::Macedonia_dse, "Macedonia_dse"),
-    (Keysym::Ukrainian_i, "Ukrainian_i"),
-    (Keysym::Ukranian_i, "Ukranian_i"),
-    (Keysym::Ukrainian_yi, "Ukrainian_yi"),
-    (Keysym::Ukranian_yi, "Ukranian_yi"),
-    (Keysym::Cyrillic_je, "Cyrillic_je"),
-    (Keysym::Serbian_je, "Serbian_je"),
-    (Keysym::Cyrillic_lje, "Cyrillic_lje"),
-    (Keysym::Serbian_lje, "Serbian_lje"),
-    (Keysym::Cyrillic_nje, "Cyrillic_nje"),
-    (Keysym::Serbian_nje, "Serbian_nje"),
-    (Keysym::Serbian_tshe, "Serbian_tshe"),
-    (Keysym::Macedonia_kje, "Macedonia_kje"),
-    (
-        Keysym::Ukrainian_ghe_with_upturn,
-        "Ukrainian_ghe_with_upturn",
-    ),
-    (Keysym::Byelorussian_shortu, "Byelorussian_shortu"),
-    (Keysym::Cyrillic_dzhe, "Cyrillic_dzhe"),
-    (Keysym::Serbian_dze, "Serbian_dze"),
-    (Keysym::numerosign, "numerosign"),
-    (Keysym::Serbian_DJE, "Serbian_DJE"),
-    (Keysym::Macedonia_GJE, "Macedonia_GJE"),
-    (Keysym::Cyrillic_IO, "Cyrillic_IO"),
-    (Keysym::Ukrainian_IE, "Ukrainian_IE"),
-    (Keysym::Ukranian_JE, "Ukranian_JE"),
-    (Keysym::Macedonia_DSE, "Macedonia_DSE"),
-    (Keysym::Ukrainian_I, "Ukrainian_I"),
-    (Keysym::Ukranian_I, "Ukranian_I"),
-    (Keysym::Ukrainian_YI, "Ukrainian_YI"),
-    (Keysym::Ukranian_YI, "Ukranian_YI"),
-    (Keysym::Cyrillic_JE, "Cyrillic_JE"),
-    (Keysym::Serbian_JE, "Serbian_JE"),
-    (Keysym::Cyrillic_LJE, "Cyrillic_LJE"),
-    (Keysym::Serbian_LJE, "Serbian_LJE"),
-    (Keysym::Cyrillic_NJE, "Cyrillic_NJE"),
-    (Keysym::Serbian_NJE, "Serbian_NJE"),
-    (Keysym::Serbian_TSHE, "Serbian_TSHE"),
-    (Keysym::Macedonia_KJE, "Macedonia_KJE"),
-    (
-        Keysym::Ukrainian_GHE_WITH_UPTURN,
-        "Ukrainian_GHE_WITH_UPTURN",
-    ),
-    (Keysym::Byelorussian_SHORTU, "Byelorussian_SHORTU"),
-    (Keysym::Cyrillic_DZHE, "Cyrillic_DZHE"),
-    (Keysym::Serbian_DZE, "Serbian_DZE"),
-    (Keysym::Cyrillic_yu, "Cyrillic_yu"),
-    (Keysym::Cyrillic_a, "Cyrillic_a"),
-    (Keysym::Cyrillic_be, "Cyrillic_be"),
-    (Keysym::Cyrillic_tse, "Cyrillic_tse"),
-    (Keysym::Cyrillic_de, "Cyrillic_de"),
-    (Keysym::Cyrillic_ie, "Cyrillic_ie"),
-    (Keysym::Cyrillic_ef, "Cyrillic_ef"),
-    (Keysym::Cyrillic_ghe, "Cyrillic_ghe"),
-    (Keysym::Cyrillic_ha, "Cyrillic_ha"),
-    (Keysym::Cyrillic_i, "Cyrillic_i"),
-    (Keysym::Cyrillic_shorti, "Cyrillic_shorti"),
-    (Keysym::Cyrillic_ka, "Cyrillic_ka"),
-    (Keysym::Cyrillic_el, "Cyrillic_el"),
-    (Keysym::Cyrillic_em, "Cyrillic_em"),
-    (Keysym::Cyrillic_en, "Cyrillic_en"),
-    (Keysym::Cyrillic_o, "Cyrillic_o"),
-    (Keysym::Cyrillic_pe, "Cyrillic_pe"),
-    (Keysym::Cyrillic_ya, "Cyrillic_ya"),
-    (Keysym::Cyrillic_er, "Cyrillic_er"),
-    (Keysym::Cyrillic_es, "Cyrillic_es"),
-    (Keysym::Cyrillic_te, "Cyrillic_te"),
-    (Keysym::Cyrillic_u, "Cyrillic_u"),
-    (Keysym::Cyrillic_zhe, "Cyrillic_zhe"),
-    (Keysym::Cyrillic_ve, "Cyrillic_ve"),
-    (Keysym::Cyrillic_softsign, "Cyrillic_softsign"),
-    (Keysym::Cyrillic_yeru, "Cyrillic_yeru"),
-    (Keysym::Cyrillic_ze, "Cyrillic_ze"),
-    (Keysym::Cyrillic_sha, "Cyrillic_sha"),
-    (Keysym::Cyrillic_e, "Cyrillic_e"),
-    (Keysym::Cyrillic_shcha, "Cyrillic_shcha"),
-    (Keysym::Cyrillic_che, "Cyrillic_che"),
-    (Keysym::Cyrillic_hardsign, "Cyrillic_hardsign"),
-    (Keysym::Cyrillic_YU, "Cyrillic_YU"),
-    (Keysym::Cyrillic_A, "Cyrillic_A"),
-    (Keysym::Cyrillic_BE, "Cyrillic_BE"),
-    (Keysym::Cyrillic_TSE, "Cyrillic_TSE"),
-    (Keysym::Cyrillic_DE, "Cyrillic_DE"),
-    (Keysym::Cyrillic_IE, "Cyrillic_IE"),
-    (Keysym::Cyrillic_EF, "Cyrillic_EF"),
-    (Keysym::Cyrillic_GHE, "Cyrillic_GHE"),
-    (Keysym::Cyrillic_HA, "Cyrillic_HA"),
-    (Keysym::Cyrillic_I, "Cyrillic_I"),
-    (Keysym::Cyrillic_SHORTI, "Cyrillic_SHORTI"),
-    (Keysym::Cyrillic_KA, "Cyrillic_KA"),
-    (Keysym::Cyrillic_EL, "Cyrillic_EL"),
-    (Keysym::Cyrillic_EM, "Cyrillic_EM"),
-    (Keysym::Cyrillic_EN, "Cyrillic_EN"),
-    (Keysym::Cyrillic_O, "Cyrillic_O"),
-    (Keysym::Cyrillic_PE, "Cyrillic_PE"),
-    (Keysym::Cyrillic_YA, "Cyrillic_YA"),
-    (Keysym::Cyrillic_ER, "Cyrillic_ER"),
-    (Keysym::Cyrillic_ES, "Cyrillic_ES"),
-    (Keysym::Cyrillic_TE, "Cyrillic_TE"),
-    (Keysym::Cyrillic_U, "Cyrillic_U"),
-    (Keysym::Cyrillic_ZHE, "Cyrillic_ZHE"),
-    (Keysym::Cyrillic_VE, "Cyrillic_VE"),
-    (Keysym::Cyrillic_SOFTSIGN, "Cyrillic_SOFTSIGN"),
-    (Keysym::Cyrillic_YERU, "Cyrillic_YERU"),
-    (Keysym::Cyrillic_ZE, "Cyrillic_ZE"),
-    (Keysym::Cyrillic_SHA, "Cyrillic_SHA"),
-    (Keysym::Cyrillic_E, "Cyrillic_E"),
-    (Keysym::Cyrillic_SHCHA, "Cyrillic_SHCHA"),
-    (Keysym::Cyrillic_CHE, "Cyrillic_CHE"),
-    (Keysym::Cyrillic_HARDSIGN, "Cyrillic_HARDSIGN"),
-    (Keysym::Greek_ALPHAaccent, "Greek_ALPHAaccent"),
-    (Keysym::Greek_EPSILONaccent, "Greek_EPSILONaccent"),
-    (Keysym::Greek_ETAaccent, "Greek_ETAaccent"),
-    (Keysym::Greek_IOTAaccent, "Greek_IOTAaccent"),
-    (Keysym::Greek_IOTAdieresis, "Greek_IOTAdieresis"),
-    (Keysym::Greek_IOTAdiaeresis, "Greek_IOTAdiaeresis"),
-    (Keysym::Greek_OMICRONaccent, "Greek_OMICRONaccent"),
-    (Keysym::Greek_UPSILONaccent, "Greek_UPSILONaccent"),
-    (Keysym::Greek_UPSILONdieresis, "Greek_UPSILONdieresis"),
-    (Keysym::Greek_OMEGAaccent, "Greek_OMEGAaccent"),
-    (Keysym::Greek_accentdieresis, "Greek_accentdieresis"),
-    (Keysym::Greek_horizbar, "Greek_horizbar"),
-    (Keysym::Greek_alphaaccent, "Greek_alphaaccent"),
-    (Keysym::Greek_epsilonaccent, "Greek_epsilonaccent"),
-    (Keysym::Greek_etaaccent, "Greek_etaaccent"),
-    (Keysym::Greek_iotaaccent, "Greek_iotaaccent"),
-    (Keysym::Greek_iotadieresis, "Greek_iotadieresis"),
-    (Keysym::Greek_iotaaccentdieresis, "Greek_iotaaccentdieresis"),
-    (Keysym::Greek_omicronaccent, "Greek_omicronaccent"),
-    (Keysym::Greek_upsilonaccent, "Greek_upsilonaccent"),
-    (Keysym::Greek_upsilondieresis, "Greek_upsilondieresis"),
-    (
-        Keysym::Greek_upsilonaccentdieresis,
-        "Greek_upsilonaccentdieresis",
-    ),
-    (Keysym::Greek_omegaaccent, "Greek_omegaaccent"),
-    (Keysym::Greek_ALPHA, "Greek_ALPHA"),
-    (Keysym::Greek_BETA, "Greek_BETA"),
-    (Keysym::Greek_GAMMA, "Greek_GAMMA"),
-    (Keysym::Greek_DELTA, "Greek_DELTA"),
-    (Keysym::Greek_EPSILON, "Greek_EPSILON"),
-    (Keysym::Greek_ZETA, "Greek_ZETA"),
-    (Keysym::Greek_ETA, "Greek_ETA"),
-    (Keysym::Greek_THETA, "Greek_THETA"),
-    (Keysym::Greek_IOTA, "Greek_IOTA"),
-    (Keysym::Greek_KAPPA, "Greek_KAPPA"),
-    (Keysym::Greek_LAMDA, "Greek_LAMDA"),
-    (Keysym::Greek_LAMBDA, "Greek_LAMBDA"),
-    (Keysym::Greek_MU, "Greek_MU"),
-    (Keysym::Greek_NU, "Greek_NU"),
-    (Keysym::Greek_XI, "Greek_XI"),
-    (Keysym::Greek_OMICRON, "Greek_OMICRON"),
-    (Keysym::Greek_PI, "Greek_PI"),
-    (Keysym::Greek_RHO, "Greek_RHO"),
-    (Keysym::Greek_SIGMA, "Greek_SIGMA"),
-    (Keysym::Greek_TAU, "Greek_TAU"),
-    (Keysym::Greek_UPSILON, "Greek_UPSILON"),
-    (Keysym::Greek_PHI, "Greek_PHI"),
-    (Keysym::Greek_CHI, "Greek_CHI"),
-    (Keysym::Greek_PSI, "Greek_PSI"),
-    (Keysym::Greek_OMEGA, "Greek_OMEGA"),
-    (Keysym::Greek_alpha, "Greek_alpha"),
-    (Keysym::Greek_beta, "Greek_beta"),
-    (Keysym::Greek_gamma, "Greek_gamma"),
-    (Keysym::Greek_delta, "Greek_delta"),
-    (Keysym::Greek_epsilon, "Greek_epsilon"),
-    (Keysym::Greek_zeta, "Greek_zeta"),
-    (Keysym::Greek_eta, "Greek_eta"),
-    (Keysym::Greek_theta, "Greek_theta"),
-    (Keysym::Greek_iota, "Greek_iota"),
-    (Keysym::Greek_kappa, "Greek_kappa"),
-    (Keysym::Greek_lamda, "Greek_lamda"),
-    (Keysym::Greek_lambda, "Greek_lambda"),
-    (Keysym::Greek_mu, "Greek_mu"),
-    (Keysym::Greek_nu, "Greek_nu"),
-    (Keysym::Greek_xi, "Greek_xi"),
-    (Keysym::Greek_omicron, "Greek_omicron"),
-    (Keysym::Greek_pi, "Greek_pi"),
-    (Keysym::Greek_rho, "Greek_rho"),
-    (Keysym::Greek_sigma, "Greek_sigma"),
-    (Keysym::Greek_finalsmallsigma, "Greek_finalsmallsigma"),
-    (Keysym::Greek_tau, "Greek_tau"),
-    (Keysym::Greek_upsilon, "Greek_upsilon"),
-    (Keysym::Greek_phi, "Greek_phi"),
-    (Keysym::Greek_chi, "Greek_chi"),
-    (Keysym::Greek_psi, "Greek_psi"),
-    (Keysym::Greek_omega, "Greek_omega"),
-    (Keysym::leftradical, "leftradical"),
-    (Keysym::topleftradical, "topleftradical"),
-    (Keysym::horizconnector, "horizconnector"),
-    (Keysym::topintegral, "topintegral"),
-    (Keysym::botintegral, "botintegral"),
-    (Keysym::vertconnector, "vertconnector"),
-    (Keysym::topleftsqbracket, "topleftsqbracket"),
-    (Keysym::botleftsqbracket, "botleftsqbracket"),
-    (Keysym::toprightsqbracket, "toprightsqbracket"),
-    (Keysym::botrightsqbracket, "botrightsqbracket"),
-    (Keysym::topleftparens, "topleftparens"),
-    (Keysym::botleftparens, "botleftparens"),
-    (Keysym::toprightparens, "toprightparens"),
-    (Keysym::botrightparens, "botrightparens"),
-    (Keysym::leftmiddlecurlybrace, "leftmiddlecurlybrace"),
-    (Keysym::rightmiddlecurlybrace, "rightmiddlecurlybrace"),
-    (Keysym::topleftsummation, "topleftsummation"),
-    (Keysym::botleftsummation, "botleftsummation"),
-    (
-        Keysym::topvertsummationconnector,
-        "topvertsummationconnector",
-    ),
-    (
-        Keysym::botvertsummationconnector,
-        "botvertsummationconnector",
-    ),
-    (Keysym::toprightsummation, "toprightsummation"),
-    (Keysym::botrightsummation, "botrightsummation"),
-    (Keysym::rightmiddlesummation, "rightmiddlesummation"),
-    (Keysym::lessthanequal, "lessthanequal"),
-    (Keysym::notequal, "notequal"),
-    (Keysym::greaterthanequal, "greaterthanequal"),
-    (Keysym::integral, "integral"),
-    (Keysym::therefore, "therefore"),
-    (Keysym::variation, "variation"),
-    (Keysym::infinity, "infinity"),
-    (Keysym::nabla, "nabla"),
-    (Keysym::approximate, "approximate"),
-    (Keysym::similarequal, "similarequal"),
-    (Keysym::ifonlyif, "ifonlyif"),
-    (Keysym::implies, "implies"),
-    (Keysym::identical, "identical"),
-    (Keysym::radical, "radical"),
-    (Keysym::includedin, "includedin"),
-    (Keysym::includes, "includes"),
-    (Keysym::intersection, "intersection"),
-    (Keysym::union, "union"),
-    (Keysym::logicaland, "logicaland"),
-    (Keysym::logicalor, "logicalor"),
-    (Keysym::partialderivative, "partialderivative"),
-    (Keysym::function, "function"),
-    (Keysym::leftarrow, "leftarrow"),
-    (Keysym::uparrow, "uparrow"),
-    (Keysym::rightarrow, "rightarrow"),
-    (Keysym::downarrow, "downarrow"),
-    (Keysym::blank, "blank"),
-    (Keysym::soliddiamond, "soliddiamond"),
-    (Keysym::checkerboard, "checkerboard"),
-    (Keysym::ht, "ht"),
-    (Keysym::ff, "ff"),
-    (Keysym::cr, "cr"),
-    (Keysym::lf, "lf"),
-    (Keysym::nl, "nl"),
-    (Keysym::vt, "vt"),
-    (Keysym::lowrightcorner, "lowrightcorner"),
-    (Keysym::uprightcorner, "uprightcorner"),
-    (Keysym::upleftcorner, "upleftcorner"),
-    (Keysym::lowleftcorner, "lowleftcorner"),
-    (Keysym::crossinglines, "crossinglines"),
-    (Keysym::horizlinescan1, "horizlinescan1"),
-    (Keysym::horizlinescan3, "horizlinescan3"),
-    (Keysym::horizlinescan5, "horizlinescan5"),
-    (Keysym::horizlinescan7, "horizlinescan7"),
-    (Keysym::horizlinescan9, "horizlinescan9"),
-    (Keysym::leftt, "leftt"),
-    (Keysym::rightt, "rightt"),
-    (Keysym::bott, "bott"),
-    (Keysym::topt, "topt"),
-    (Keysym::vertbar, "vertbar"),
-    (Keysym::emspace, "emspace"),
-    (Keysym::enspace, "enspace"),
-    (Keysym::em3space, "em3space"),
-    (Keysym::em4space, "em4space"),
-    (Keysym::digitspace, "digitspace"),
-    (Keysym::punctspace, "punctspace"),
-    (Keysym::thinspace, "thinspace"),
-    (Keysym::hairspace, "hairspace"),
-    (Keysym::emdash, "emdash"),
-    (Keysym::endash, "endash"),
-    (Keysym::signifblank, "signifblank"),
-    (Keysym::ellipsis, "ellipsis"),
-    (Keysym::doubbaselinedot, "doubbaselinedot"),
-    (Keysym::onethird, "onethird"),
-    (Keysym::twothirds, "twothirds"),
-    (Keysym::onefifth, "onefifth"),
-    (Keysym::twofifths, "twofifths"),
-    (Keysym::threefifths, "threefifths"),
-    (Keysym::fourfifths, "fourfifths"),
-    (Keysym::onesixth, "onesixth"),
-    (Keysym::fivesixths, "fivesixths"),
-    (Keysym::careof, "careof"),
-    (Keysym::figdash, "figdash"),
-    (Keysym::leftanglebracket, "leftanglebracket"),
-    (Keysym::decimalpoint, "decimalpoint"),
-    (Keysym::rightanglebracket, "rightanglebracket"),
-    (Keysym::marker, "marker"),
-    (Keysym::oneeighth, "oneeighth"),
-    (Keysym::threeeighths, "threeeighths"),
-    (Keysym::fiveeighths, "fiveeighths"),
-    (Keysym::seveneighths, "seveneighths"),
-    (Keysym::trademark, "trademark"),
-    (Keysym::signaturemark, "signaturemark"),
-    (Keysym::trademarkincircle, "trademarkincircle"),
-    (Keysym::leftopentriangle, "leftopentriangle"),
-    (Keysym::rightopentriangle, "rightopentriangle"),
-    (Keysym::emopencircle, "emopencircle"),
-    (Keysym::emopenrectangle, "emopenrectangle"),
-    (Keysym::leftsinglequotemark, "leftsinglequotemark"),
-    (Keysym::rightsinglequotemark, "rightsinglequotemark"),
-    (Keysym::leftdoublequotemark, "leftdoublequotemark"),
-    (Keysym::rightdoublequotemark, "rightdoublequotemark"),
-    (Keysym::prescription, "prescription"),
-    (Keysym::permille, "permille"),
-    (Keysym::minutes, "minutes"),
-    (Keysym::seconds, "seconds"),
-    (Keysym::latincross, "latincross"),
-    (Keysym::hexagram, "hexagram"),
-    (Keysym::filledrectbullet, "filledrectbullet"),
-    (Keysym::filledlefttribullet, "filledlefttribullet"),
-    (Keysym::filledrighttribullet, "filledrighttribullet"),
-    (Keysym::emfilledcircle, "emfilledcircle"),
-    (Keysym::emfilledrect, "emfilledrect"),
-    (Keysym::enopencircbullet, "enopencircbullet"),
-    (Keysym::enopensquarebullet, "enopensquarebullet"),
-    (Keysym::openrectbullet, "openrectbullet"),
-    (Keysym::opentribulletup, "opentribulletup"),
-    (Keysym::opentribulletdown, "opentribulletdown"),
-    (Keysym::openstar, "openstar"),
-    (Keysym::enfilledcircbullet, "enfilledcircbullet"),
-    (Keysym::enfilledsqbullet, "enfilledsqbullet"),
-    (Keysym::filledtribulletup, "filledtribulletup"),
-    (Keysym::filledtribulletdown, "filledtribulletdown"),
-    (Keysym::leftpointer, "leftpointer"),
-    (Keysym::rightpointer, "rightpointer"),
-    (Keysym::club, "club"),
-    (Keysym::diamond, "diamond"),
-    (Keysym::heart, "heart"),
-    (Keysym::maltesecross, "maltesecross"),
-    (Keysym::dagger, "dagger"),
-    (Keysym::doubledagger, "doubledagger"),
-    (Keysym::checkmark, "checkmark"),
-    (Keysym::ballotcross, "ballotcross"),
-    (Keysym::musicalsharp, "musicalsharp"),
-    (Keysym::musicalflat, "musicalflat"),
-    (Keysym::malesymbol, "malesymbol"),
-    (Keysym::femalesymbol, "femalesymbol"),
-    (Keysym::telephone, "telephone"),
-    (Keysym::telephonerecorder, "telephonerecorder"),
-    (Keysym::phonographcopyright, "phonographcopyright"),
-    (Keysym::caret, "caret"),
-    (Keysym::singlelowquotemark, "singlelowquotemark"),
-    (Keysym::doublelowquotemark, "doublelowquotemark"),
-    (Keysym::cursor, "cursor"),
-    (Keysym::leftcaret, "leftcaret"),
-    (Keysym::rightcaret, "rightcaret"),
-    (Keysym::downcaret, "downcaret"),
-    (Keysym::upcaret, "upcaret"),
-    (Keysym::overbar, "overbar"),
-    (Keysym::downtack, "downtack"),
-    (Keysym::upshoe, "upshoe"),
-    (Keysym::downstile, "downstile"),
-    (Keysym::underbar, "underbar"),
-    (Keysym::jot, "jot"),
-    (Keysym::quad, "quad"),
-    (Keysym::uptack, "uptack"),
-    (Keysym::circle, "circle"),
-    (Keysym::upstile, "upstile"),
-    (Keysym::downshoe, "downshoe"),
-    (Keysym::rightshoe, "rightshoe"),
-    (Keysym::leftshoe, "leftshoe"),
-    (Keysym::lefttack, "lefttack"),
-    (Keysym::righttack, "righttack"),
-    (Keysym::hebrew_doublelowline, "hebrew_doublelowline"),
-    (Keysym::hebrew_aleph, "hebrew_aleph"),
-    (Keysym::hebrew_bet, "hebrew_bet"),
-    (Keysym::hebrew_beth, "hebrew_beth"),
-    (Keysym::hebrew_gimel, "hebrew_gimel"),
-    (Keysym::hebrew_gimmel, "hebrew_gimmel"),
-    (Keysym::hebrew_dalet, "hebrew_dalet"),
-    (Keysym::hebrew_daleth, "hebrew_daleth"),
-    (Keysym::hebrew_he, "hebrew_he"),
-    (Keysym::hebrew_waw, "hebrew_waw"),
-    (Keysym::hebrew_zain, "hebrew_zain"),
-    (Keysym::hebrew_zayin, "hebrew_zayin"),
-    (Keysym::hebrew_chet, "hebrew_chet"),
-    (Keysym::hebrew_het, "hebrew_het"),
-    (Keysym::hebrew_tet, "hebrew_tet"),
-    (Keysym::hebrew_teth, "hebrew_teth"),
-    (Keysym::hebrew_yod, "hebrew_yod"),
-    (Keysym::hebrew_finalkaph, "hebrew_finalkaph"),
-    (Keysym::hebrew_kaph, "hebrew_kaph"),
-    (Keysym::hebrew_lamed, "hebrew_lamed"),
-    (Keysym::hebrew_finalmem, "hebrew_finalmem"),
-    (Keysym::hebrew_mem, "hebrew_mem"),
-    (Keysym::hebrew_finalnun, "hebrew_finalnun"),
-    (Keysym::hebrew_nun, "hebrew_nun"),
-    (Keysym::hebrew_samech, "hebrew_samech"),
-    (Keysym::hebrew_samekh, "hebrew_samekh"),
-    (Keysym::hebrew_ayin, "hebrew_ayin"),
-    (Keysym::hebrew_finalpe, "hebrew_finalpe"),
-    (Keysym::hebrew_pe, "hebrew_pe"),
-    (Keysym::hebrew_finalzade, "hebrew_finalzade"),
-    (Keysym::hebrew_finalzadi, "hebrew_finalzadi"),
-    (Keysym::hebrew_zade, "hebrew_zade"),
-    (Keysym::hebrew_zadi, "hebrew_zadi"),
-    (Keysym::hebrew_qoph, "hebrew_qoph"),
-    (Keysym::hebrew_kuf, "hebrew_kuf"),
-    (Keysym::hebrew_resh, "hebrew_resh"),
-    (Keysym::hebrew_shin, "hebrew_shin"),
-    (Keysym::hebrew_taw, "hebrew_taw"),
-    (Keysym::hebrew_taf, "hebrew_taf"),
-    (Keysym::Thai_kokai, "Thai_kokai"),
-    (Keysym::Thai_khokhai, "Thai_khokhai"),
-    (Keysym::Thai_khokhuat, "Thai_khokhuat"),
-    (Keysym::Thai_khokhwai, "Thai_khokhwai"),
-    (Keysym::Thai_khokhon, "Thai_khokhon"),
-    (Keysym::Thai_khorakhang, "Thai_khorakhang"),
-    (Keysym::Thai_ngongu, "Thai_ngongu"),
-    (Keysym::Thai_chochan, "Thai_chochan"),
-    (Keysym::Thai_choching, "Thai_choching"),
-    (Keysym::Thai_chochang, "Thai_chochang"),
-    (Keysym::Thai_soso, "Thai_soso"),
-    (Keysym::Thai_chochoe, "Thai_chochoe"),
-    (Keysym::Thai_yoying, "Thai_yoying"),
-    (Keysym::Thai_dochada, "Thai_dochada"),
-    (Keysym::Thai_topatak, "Thai_topatak"),
-    (Keysym::Thai_thothan, "Thai_thothan"),
-    (Keysym::Thai_thonangmontho, "Thai_thonangmontho"),
-    (Keysym::Thai_thophuthao, "Thai_thophuthao"),
-    (Keysym::Thai_nonen, "Thai_nonen"),
-    (Keysym::Thai_dodek, "Thai_dodek"),
-    (Keysym::Thai_totao, "Thai_totao"),
-    (Keysym::Thai_thothung, "Thai_thothung"),
-    (Keysym::Thai_thothahan, "Thai_thothahan"),
-    (Keysym::Thai_thothong, "Thai_thothong"),
-    (Keysym::Thai_nonu, "Thai_nonu"),
-    (Keysym::Thai_bobaimai, "Thai_bobaimai"),
-    (Keysym::Thai_popla, "Thai_popla"),
-    (Keysym::Thai_phophung, "Thai_phophung"),
-    (Keysym::Thai_fofa, "Thai_fofa"),
-    (Keysym::Thai_phophan, "Thai_phophan"),
-    (Keysym::Thai_fofan, "Thai_fofan"),
-    (Keysym::Thai_phosamphao, "Thai_phosamphao"),
-    (Keysym::Thai_moma, "Thai_moma"),
-    (Keysym::Thai_yoyak, "Thai_yoyak"),
-    (Keysym::Thai_rorua, "Thai_rorua"),
-    (Keysym::Thai_ru, "Thai_ru"),
-    (Keysym::Thai_loling, "Thai_loling"),
-    (Keysym::Thai_lu, "Thai_lu"),
-    (Keysym::Thai_wowaen, "Thai_wowaen"),
-    (Keysym::Thai_sosala, "Thai_sosala"),
-    (Keysym::Thai_sorusi, "Thai_sorusi"),
-    (Keysym::Thai_sosua, "Thai_sosua"),
-    (Keysym::Thai_hohip, "Thai_hohip"),
-    (Keysym::Thai_lochula, "Thai_lochula"),
-    (Keysym::Thai_oang, "Thai_oang"),
-    (Keysym::Thai_honokhuk, "Thai_honokhuk"),
-    (Keysym::Thai_paiyannoi, "Thai_paiyannoi"),
-    (Keysym::Thai_saraa, "Thai_saraa"),
-    (Keysym::Thai_maihanakat, "Thai_maihanakat"),
-    (Keysym::Thai_saraaa, "Thai_saraaa"),
-    (Keysym::Thai_saraam, "Thai_saraam"),
-    (Keysym::Thai_sarai, "Thai_sarai"),
-    (Keysym::Thai_saraii, "Thai_saraii"),
-    (Keysym::Thai_saraue, "Thai_saraue"),
-    (Keysym::Thai_sarauee, "Thai_sarauee"),
-    (Keysym::Thai_sarau, "Thai_sarau"),
-    (Keysym::Thai_sarauu, "Thai_sarauu"),
-    (Keysym::Thai_phinthu, "Thai_phinthu"),
-    (Keysym::Thai_maihanakat_maitho, "Thai_maihanakat_maitho"),
-    (Keysym::Thai_baht, "Thai_baht"),
-    (Keysym::Thai_sarae, "Thai_sarae"),
-    (Keysym::Thai_saraae, "Thai_saraae"),
-    (Keysym::Thai_sarao, "Thai_sarao"),
-    (Keysym::Thai_saraaimaimuan, "Thai_saraaimaimuan"),
-    (Keysym::Thai_saraaimaimalai, "Thai_saraaimaimalai"),
-    (Keysym::Thai_lakkhangyao, "Thai_lakkhangyao"),
-    (Keysym::Thai_maiyamok, "Thai_maiyamok"),
-    (Keysym::Thai_maitaikhu, "Thai_maitaikhu"),
-    (Keysym::Thai_maiek, "Thai_maiek"),
-    (Keysym::Thai_maitho, "Thai_maitho"),
-    (Keysym::Thai_maitri, "Thai_maitri"),
-    (Keysym::Thai_maichattawa, "Thai_maichattawa"),
-    (Keysym::Thai_thanthakhat, "Thai_thanthakhat"),
-    (Keysym::Thai_nikhahit, "Thai_nikhahit"),
-    (Keysym::Thai_leksun, "Thai_leksun"),
-    (Keysym::Thai_leknung, "Thai_leknung"),
-    (Keysym::Thai_leksong, "Thai_leksong"),
-    (Keysym::Thai_leksam, "Thai_leksam"),
-    (Keysym::Thai_leksi, "Thai_leksi"),
-    (Keysym::Thai_lekha, "Thai_lekha"),
-    (Keysym::Thai_lekhok, "Thai_lekhok"),
-    (Keysym::Thai_lekchet, "Thai_lekchet"),
-    (Keysym::Thai_lekpaet, "Thai_lekpaet"),
-    (Keysym::Thai_lekkao, "Thai_lekkao"),
-    (Keysym::Hangul_Kiyeog, "Hangul_Kiyeog"),
-    (Keysym::Hangul_SsangKiyeog, "Hangul_SsangKiyeog"),
-    (Keysym::Hangul_KiyeogSios, "Hangul_KiyeogSios"),
-    (Keysym::Hangul_Nieun, "Hangul_Nieun"),
-    (Keysym::Hangul_NieunJieuj, "Hangul_NieunJieuj"),
-    (Keysym::Hangul_NieunHieuh, "Hangul_NieunHieuh"),
-    (Keysym::Hangul_Dikeud, "Hangul_Dikeud"),
-    (Keysym::Hangul_SsangDikeud, "Hangul_SsangDikeud"),
-    (Keysym::Hangul_Rieul, "Hangul_Rieul"),
-    (Keysym::Hangul_RieulKiyeog, "Hangul_RieulKiyeog"),
-    (Keysym::Hangul_RieulMieum, "Hangul_RieulMieum"),
-    (Keysym::Hangul_RieulPieub, "Hangul_RieulPieub"),
-    (Keysym::Hangul_RieulSios, "Hangul_RieulSios"),
-    (Keysym::Hangul_RieulTieut, "Hangul_RieulTieut"),
-    (Keysym::Hangul_RieulPhieuf, "Hangul_RieulPhieuf"),
-    (Keysym::Hangul_RieulHieuh, "Hangul_RieulHieuh"),
-    (Keysym::Hangul_Mieum, "Hangul_Mieum"),
-    (Keysym::Hangul_Pieub, "Hangul_Pieub"),
-    (Keysym::Hangul_SsangPieub, "Hangul_SsangPieub"),
-    (Keysym::Hangul_PieubSios, "Hangul_PieubSios"),
-    (Keysym::Hangul_Sios, "Hangul_Sios"),
-    (Keysym::Hangul_SsangSios, "Hangul_SsangSios"),
-    (Keysym::Hangul_Ieung, "Hangul_Ieung"),
-    (Keysym::Hangul_Jieuj, "Hangul_Jieuj"),
-    (Keysym::Hangul_SsangJieuj, "Hangul_SsangJieuj"),
-    (Keysym::Hangul_Cieuc, "Hangul_Cieuc"),
-    (Keysym::Hangul_Khieuq, "Hangul_Khieuq"),
-    (Keysym::Hangul_Tieut, "Hangul_Tieut"),
-    (Keysym::Hangul_Phieuf, "Hangul_Phieuf"),
-    (Keysym::Hangul_Hieuh, "Hangul_Hieuh"),
-    (Keysym::Hangul_A, "Hangul_A"),
-    (Keysym::Hangul_AE, "Hangul_AE"),
-    (Keysym::Hangul_YA, "Hangul_YA"),
-    (Keysym::Hangul_YAE, "Hangul_YAE"),
-    (Keysym::Hangul_EO, "Hangul_EO"),
-    (Keysym::Hangul_E, "Hangul_E"),
-    (Keysym::Hangul_YEO, "Hangul_YEO"),
-    (Keysym::Hangul_YE, "Hangul_YE"),
-    (Keysym::Hangul_O, "Hangul_O"),
-    (Keysym::Hangul_WA, "Hangul_WA"),
-    (Keysym::Hangul_WAE, "Hangul_WAE"),
-    (Keysym::Hangul_OE, "Hangul_OE"),
-    (Keysym::Hangul_YO, "Hangul_YO"),
-    (Keysym::Hangul_U, "Hangul_U"),
-    (Keysym::Hangul_WEO, "Hangul_WEO"),
-    (Keysym::Hangul_WE, "Hangul_WE"),
-    (Keysym::Hangul_WI, "Hangul_WI"),
-    (Keysym::Hangul_YU, "Hangul_YU"),
-    (Keysym::Hangul_EU, "Hangul_EU"),
-    (Keysym::Hangul_YI, "Hangul_YI"),
-    (Keysym::Hangul_I, "Hangul_I"),
-    (Keysym::Hangul_J_Kiyeog, "Hangul_J_Kiyeog"),
-    (Keysym::Hangul_J_SsangKiyeog, "Hangul_J_SsangKiyeog"),
-    (Keysym::Hangul_J_KiyeogSios, "Hangul_J_KiyeogSios"),
-    (Keysym::Hangul_J_Nieun, "Hangul_J_Nieun"),
-    (Keysym::Hangul_J_NieunJieuj, "Hangul_J_NieunJieuj"),
-    (Keysym::Hangul_J_NieunHieuh, "Hangul_J_NieunHieuh"),
-    (Keysym::Hangul_J_Dikeud, "Hangul_J_Dikeud"),
-    (Keysym::Hangul_J_Rieul, "Hangul_J_Rieul"),
-    (Keysym::Hangul_J_RieulKiyeog, "Hangul_J_RieulKiyeog"),
-    (Keysym::Hangul_J_RieulMieum, "Hangul_J_RieulMieum"),
-    (Keysym::Hangul_J_RieulPieub, "Hangul_J_RieulPieub"),
-    (Keysym::Hangul_J_RieulSios, "Hangul_J_RieulSios"),
-    (Keysym::Hangul_J_RieulTieut, "Hangul_J_RieulTieut"),
-    (Keysym::Hangul_J_RieulPhieuf, "Hangul_J_RieulPhieuf"),
-    (Keysym::Hangul_J_RieulHieuh, "Hangul_J_RieulHieuh"),
-    (Keysym::Hangul_J_Mieum, "Hangul_J_Mieum"),
-    (Keysym::Hangul_J_Pieub, "Hangul_J_Pieub"),
-    (Keysym::Hangul_J_PieubSios, "Hangul_J_PieubSios"),
-    (Keysym::Hangul_J_Sios, "Hangul_J_Sios"),
-    (Keysym::Hangul_J_SsangSios, "Hangul_J_SsangSios"),
-    (Keysym::Hangul_J_Ieung, "Hangul_J_Ieung"),
-    (Keysym::Hangul_J_Jieuj, "Hangul_J_Jieuj"),
-    (Keysym::Hangul_J_Cieuc, "Hangul_J_Cieuc"),
-    (Keysym::Hangul_J_Khieuq, "Hangul_J_Khieuq"),
-    (Keysym::Hangul_J_Tieut, "Hangul_J_Tieut"),
-    (Keysym::Hangul_J_Phieuf, "Hangul_J_Phieuf"),
-    (Keysym::Hangul_J_Hieuh, "Hangul_J_Hieuh"),
-    (Keysym::Hangul_RieulYeorinHieuh, "Hangul_RieulYeorinHieuh"),
-    (Keysym::Hangul_SunkyeongeumMieum, "Hangul_SunkyeongeumMieum"),
-    (Keysym::Hangul_SunkyeongeumPieub, "Hangul_SunkyeongeumPieub"),
-    (Keysym::Hangul_PanSios, "Hangul_PanSios"),
-    (Keysym::Hangul_KkogjiDalrinIeung, "Hangul_KkogjiDalrinIeung"),
-    (
-        Keysym::Hangul_SunkyeongeumPhieuf,
-        "Hangul_SunkyeongeumPhieuf",
-    ),
-    (Keysym::Hangul_YeorinHieuh, "Hangul_YeorinHieuh"),
-    (Keysym::Hangul_AraeA, "Hangul_AraeA"),
-    (Keysym::Hangul_AraeAE, "Hangul_AraeAE"),
-    (Keysym::Hangul_J_PanSios, "Hangul_J_PanSios"),
-    (
-        Keysym::Hangul_J_KkogjiDalrinIeung,
-        "Hangul_J_KkogjiDalrinIeung",
-    ),
-    (Keysym::Hangul_J_YeorinHieuh, "Hangul_J_YeorinHieuh"),
-    (Keysym::Korean_Won, "Korean_Won"),
-    (Keysym::OE, "OE"),
-    (Keysym::oe, "oe"),
-    (Keysym::Ydiaeresis, "Ydiaeresis"),
-    (Keysym::EuroSign, "EuroSign"),
-    (Keysym::_3270_Duplicate, "3270_Duplicate"),
-    (Keysym::_3270_FieldMark, "3270_FieldMark"),
-    (Keysym::_3270_Right2, "3270_Right2"),
-    (Keysym::_3270_Left2, "3270_Left2"),
-    (Keysym::_3270_BackTab, "3270_BackTab"),
-    (Keysym::_3270_EraseEOF, "3270_EraseEOF"),
-    (Keysym::_3270_EraseInput, "3270_EraseInput"),
-    (Keysym::_3270_Reset, "3270_Reset"),
-    (Keysym::_3270_Quit, "3270_Quit"),
-    (Keysym::_3270_PA1, "3270_PA1"),
-    (Keysym::_3270_PA2, "3270_PA2"),
-    (Keysym::_3270_PA3, "3270_PA3"),
-    (Keysym::_3270_Test, "3270_Test"),
-    (Keysym::_3270_Attn, "3270_Attn"),
-    (Keysym::_3270_CursorBlink, "3270_CursorBlink"),
-    (Keysym::_3270_AltCursor, "3270_AltCursor"),
-    (Keysym::_3270_KeyClick, "3270_KeyClick"),
-    (Keysym::_3270_Jump, "3270_Jump"),
-    (Keysym::_3270_Ident, "3270_Ident"),
-    (Keysym::_3270_Rule, "3270_Rule"),
-    (Keysym::_3270_Copy, "3270_Copy"),
-    (Keysym::_3270_Play, "3270_Play"),
-    (Keysym::_3270_Setup, "3270_Setup"),
-    (Keysym::_3270_Record, "3270_Record"),
-    (Keysym::_3270_ChangeScreen, "3270_ChangeScreen"),
-    (Keysym::_3270_DeleteWord, "3270_DeleteWord"),
-    (Keysym::_3270_ExSelect, "3270_ExSelect"),
-    (Keysym::_3270_CursorSelect, "3270_CursorSelect"),
-    (Keysym::_3270_PrintScreen, "3270_PrintScreen"),
-    (Keysym::_3270_Enter, "3270_Enter"),
-    (Keysym::ISO_Lock, "ISO_Lock"),
-    (Keysym::ISO_Level2_Latch, "ISO_Level2_Latch"),
-    (Keysym::ISO_Level3_Shift, "ISO_Level3_Shift"),
-    (Keysym::ISO_Level3_Latch, "ISO_Level3_Latch"),
-    (Keysym::ISO_Level3_Lock, "ISO_Level3_Lock"),
-    (Keysym::ISO_Group_Latch, "ISO_Group_Latch"),
-    (Keysym::ISO_Group_Lock, "ISO_Group_Lock"),
-    (Keysym::ISO_Next_Group, "ISO_Next_Group"),
-    (Keysym::ISO_Next_Group_Lock, "ISO_Next_Group_Lock"),
-    (Keysym::ISO_Prev_Group, "ISO_Prev_Group"),
-    (Keysym::ISO_Prev_Group_Lock, "ISO_Prev_Group_Lock"),
-    (Keysym::ISO_First_Group, "ISO_First_Group"),
-    (Keysym::ISO_First_Group_Lock, "ISO_First_Group_Lock"),
-    (Keysym::ISO_Last_Group, "ISO_Last_Group"),
-    (Keysym::ISO_Last_Group_Lock, "ISO_Last_Group_Lock"),
-    (Keysym::ISO_Level5_Shift, "ISO_Level5_Shift"),
-    (Keysym::ISO_Level5_Latch, "ISO_Level5_Latch"),
-    (Keysym::ISO_Level5_Lock, "ISO_Level5_Lock"),
-    (Keysym::ISO_Left_Tab, "ISO_Left_Tab"),
-    (Keysym::ISO_Move_Line_Up, "ISO_Move_Line_Up"),
-    (Keysym::ISO_Move_Line_Down, "ISO_Move_Line_Down"),
-    (Keysym::ISO_Partial_Line_Up, "ISO_Partial_Line_Up"),
-    (Keysym::ISO_Partial_Line_Down, "ISO_Partial_Line_Down"),
-    (Keysym::ISO_Partial_Space_Left, "ISO_Partial_Space_Left"),
-    (Keysym::ISO_Partial_Space_Right, "ISO_Partial_Space_Right"),
-    (Keysym::ISO_Set_Margin_Left, "ISO_Set_Margin_Left"),
-    (Keysym::ISO_Set_Margin_Right, "ISO_Set_Margin_Right"),
-    (Keysym::ISO_Release_Margin_Left, "ISO_Release_Margin_Left"),
-    (Keysym::ISO_Release_Margin_Right, "ISO_Release_Margin_Right"),
-    (Keysym::ISO_Release_Both_Margins, "ISO_Release_Both_Margins"),
-    (Keysym::ISO_Fast_Cursor_Left, "ISO_Fast_Cursor_Left"),
-    (Keysym::ISO_Fast_Cursor_Right, "ISO_Fast_Cursor_Right"),
-    (Keysym::ISO_Fast_Cursor_Up, "ISO_Fast_Cursor_Up"),
-    (Keysym::ISO_Fast_Cursor_Down, "ISO_Fast_Cursor_Down"),
-    (Keysym::ISO_Continuous_Underline, "ISO_Continuous_Underline"),
-    (
-        Keysym::ISO_Discontinuous_Underline,
-        "ISO_Discontinuous_Underline",
-    ),
-    (Keysym::ISO_Emphasize, "ISO_Emphasize"),
-    (Keysym::ISO_Center_Object, "ISO_Center_Object"),
-    (Keysym::ISO_Enter, "ISO_Enter"),
-    (Keysym::dead_grave, "dead_grave"),
-    (Keysym::dead_acute, "dead_acute"),
-    (Keysym::dead_circumflex, "dead_circumflex"),
-    (Keysym::dead_tilde, "dead_tilde"),
-    (Keysym::dead_perispomeni, "dead_perispomeni"),
-    (Keysym::dead_macron, "dead_macron"),
-    (Keysym::dead_breve, "dead_breve"),
-    (Keysym::dead_abovedot, "dead_abovedot"),
-    (Keysym::dead_diaeresis, "dead_diaeresis"),
-    (Keysym::dead_abovering, "dead_abovering"),
-    (Keysym::dead_doubleacute, "dead_doubleacute"),
-    (Keysym::dead_caron, "dead_caron"),
-    (Keysym::dead_cedilla, "dead_cedilla"),
-    (Keysym::dead_ogonek, "dead_ogonek"),
-    (Keysym::dead_iota, "dead_iota"),
-    (Keysym::dead_voiced_sound, "dead_voiced_sound"),
-    (Keysym::dead_semivoiced_sound, "dead_semivoiced_sound"),
-    (Keysym::dead_belowdot, "dead_belowdot"),
-    (Keysym::dead_hook, "dead_hook"),
-    (Keysym::dead_horn, "dead_horn"),
-    (Keysym::dead_stroke, "dead_stroke"),
-    (Keysym::dead_abovecomma, "dead_abovecomma"),
-    (Keysym::dead_psili, "dead_psili"),
-    (Keysym::dead_abovereversedcomma, "dead_abovereversedcomma"),
-    (Keysym::dead_dasia, "dead_dasia"),
-    (Keysym::dead_doublegrave, "dead_doublegrave"),
-    (Keysym::dead_belowring, "dead_belowring"),
-    (Keysym::dead_belowmacron, "dead_belowmacron"),
-    (Keysym::dead_belowcircumflex, "dead_belowcircumflex"),
-    (Keysym::dead_belowtilde, "dead_belowtilde"),
-    (Keysym::dead_belowbreve, "dead_belowbreve"),
-    (Keysym::dead_belowdiaeresis, "dead_belowdiaeresis"),
-    (Keysym::dead_invertedbreve, "dead_invertedbreve"),
-    (Keysym::dead_belowcomma, "dead_belowcomma"),
-    (Keysym::dead_currency, "dead_currency"),
-    (Keysym::AccessX_Enable, "AccessX_Enable"),
-    (Keysym::AccessX_Feedback_Enable, "AccessX_Feedback_Enable"),
-    (Keysym::RepeatKeys_Enable, "RepeatKeys_Enable"),
-    (Keysym::SlowKeys_Enable, "SlowKeys_Enable"),
-    (Keysym::BounceKeys_Enable, "BounceKeys_Enable"),
-    (Keysym::StickyKeys_Enable, "StickyKeys_Enable"),
-    (Keysym::MouseKeys_Enable, "MouseKeys_Enable"),
-    (Keysym::MouseKeys_Accel_Enable, "MouseKeys_Accel_Enable"),
-    (Keysym::Overlay1_Enable, "Overlay1_Enable"),
-    (Keysym::Overlay2_Enable, "Overlay2_Enable"),
-    (Keysym::AudibleBell_Enable, "AudibleBell_Enable"),
-    (Keysym::dead_a, "dead_a"),
-    (Keysym::dead_A, "dead_A"),
-    (Keysym::dead_e, "dead_e"),
-    (Keysym::dead_E, "dead_E"),
-    (Keysym::dead_i, "dead_i"),
-    (Keysym::dead_I, "dead_I"),
-    (Keysym::dead_o, "dead_o"),
-    (Keysym::dead_O, "dead_O"),
-    (Keysym::dead_u, "dead_u"),
-    (Keysym::dead_U, "dead_U"),
-    (Keysym::dead_small_schwa, "dead_small_schwa"),
-    (Keysym::dead_schwa, "dead_schwa"),
-    (Keysym::dead_capital_schwa, "dead_capital_schwa"),
-    (Keysym::dead_SCHWA, "dead_SCHWA"),
-    (Keysym::dead_greek, "dead_greek"),
-    (Keysym::dead_hamza, "dead_hamza"),
-    (Keysym::dead_lowline, "dead_lowline"),
-    (Keysym::dead_aboveverticalline, "dead_aboveverticalline"),
-    (Keysym::dead_belowverticalline, "dead_belowverticalline"),
-    (Keysym::dead_longsolidusoverlay, "dead_longsolidusoverlay"),
-    (Keysym::ch, "ch"),
-    (Keysym::Ch, "Ch"),
-    (Keysym::CH, "CH"),
-    (Keysym::c_h, "c_h"),
-    (Keysym::C_h, "C_h"),
-    (Keysym::C_H, "C_H"),
-    (Keysym::First_Virtual_Screen, "First_Virtual_Screen"),
-    (Keysym::Prev_Virtual_Screen, "Prev_Virtual_Screen"),
-    (Keysym::Next_Virtual_Screen, "Next_Virtual_Screen"),
-    (Keysym::Last_Virtual_Screen, "Last_Virtual_Screen"),
-    (Keysym::Terminate_Server, "Terminate_Server"),
-    (Keysym::Pointer_Left, "Pointer_Left"),
-    (Keysym::Pointer_Right, "Pointer_Right"),
-    (Keysym::Pointer_Up, "Pointer_Up"),
-    (Keysym::Pointer_Down, "Pointer_Down"),
-    (Keysym::Pointer_UpLeft, "Pointer_UpLeft"),
-    (Keysym::Pointer_UpRight, "Pointer_UpRight"),
-    (Keysym::Pointer_DownLeft, "Pointer_DownLeft"),
-    (Keysym::Pointer_DownRight, "Pointer_DownRight"),
-    (Keysym::Pointer_Button_Dflt, "Pointer_Button_Dflt"),
-    (Keysym::Pointer_Button1, "Pointer_Button1"),
-    (Keysym::Pointer_Button2, "Pointer_Button2"),
-    (Keysym::Pointer_Button3, "Pointer_Button3"),
-    (Keysym::Pointer_Button4, "Pointer_Button4"),
-    (Keysym::Pointer_Button5, "Pointer_Button5"),
-    (Keysym::Pointer_DblClick_Dflt, "Pointer_DblClick_Dflt"),
-    (Keysym::Pointer_DblClick1, "Pointer_DblClick1"),
-    (Keysym::Pointer_DblClick2, "Pointer_DblClick2"),
-    (Keysym::Pointer_DblClick3, "Pointer_DblClick3"),
-    (Keysym::Pointer_DblClick4, "Pointer_DblClick4"),
-    (Keysym::Pointer_DblClick5, "Pointer_DblClick5"),
-    (Keysym::Pointer_Drag_Dflt, "Pointer_Drag_Dflt"),
-    (Keysym::Pointer_Drag1, "Pointer_Drag1"),
-    (Keysym::Pointer_Drag2, "Pointer_Drag2"),
-    (Keysym::Pointer_Drag3, "Pointer_Drag3"),
-    (Keysym::Pointer_Drag4, "Pointer_Drag4"),
-    (Keysym::Pointer_EnableKeys, "Pointer_EnableKeys"),
-    (Keysym::Pointer_Accelerate, "Pointer_Accelerate"),
-    (Keysym::Pointer_DfltBtnNext, "Pointer_DfltBtnNext"),
-    (Keysym::Pointer_DfltBtnPrev, "Pointer_DfltBtnPrev"),
-    (Keysym::Pointer_Drag5, "Pointer_Drag5"),
-    (Keysym::BackSpace, "BackSpace"),
-    (Keysym::Tab, "Tab"),
-    (Keysym::Linefeed, "Linefeed"),
-    (Keysym::Clear, "Clear"),
-    (Keysym::Return, "Return"),
-    (Keysym::Pause, "Pause"),
-    (Keysym::Scroll_Lock, "Scroll_Lock"),
-    (Keysym::Sys_Req, "Sys_Req"),
-    (Keysym::Escape, "Escape"),
-    (Keysym::Multi_key, "Multi_key"),
-    (Keysym::SUN_Compose, "SunCompose"),
-    (Keysym::Kanji, "Kanji"),
-    (Keysym::Muhenkan, "Muhenkan"),
-    (Keysym::Henkan_Mode, "Henkan_Mode"),
-    (Keysym::Henkan, "Henkan"),
-    (Keysym::Romaji, "Romaji"),
-    (Keysym::Hiragana, "Hiragana"),
-    (Keysym::Katakana, "Katakana"),
-    (Keysym::Hiragana_Katakana, "Hiragana_Katakana"),
-    (Keysym::Zenkaku, "Zenkaku"),
-    (Keysym::Hankaku, "Hankaku"),
-    (Keysym::Zenkaku_Hankaku, "Zenkaku_Hankaku"),
-    (Keysym::Touroku, "Touroku"),
-    (Keysym::Massyo, "Massyo"),
-    (Keysym::Kana_Lock, "Kana_Lock"),
-    (Keysym::Kana_Shift, "Kana_Shift"),
-    (Keysym::Eisu_Shift, "Eisu_Shift"),
-    (Keysym::Eisu_toggle, "Eisu_toggle"),
-    (Keysym::Hangul, "Hangul"),
-    (Keysym::Hangul_Start, "Hangul_Start"),
-    (Keysym::Hangul_End, "Hangul_End"),
-    (Keysym::Hangul_Hanja, "Hangul_Hanja"),
-    (Keysym::Hangul_Jamo, "Hangul_Jamo"),
-    (Keysym::Hangul_Romaja, "Hangul_Romaja"),
-    (Keysym::Codeinput, "Codeinput"),
-    (Keysym::Kanji_Bangou, "Kanji_Bangou"),
-    (Keysym::Hangul_Codeinput, "Hangul_Codeinput"),
-    (Keysym::Hangul_Jeonja, "Hangul_Jeonja"),
-    (Keysym::Hangul_Banja, "Hangul_Banja"),
-    (Keysym::Hangul_PreHanja, "Hangul_PreHanja"),
-    (Keysym::Hangul_PostHanja, "Hangul_PostHanja"),
-    (Keysym::SingleCandidate, "SingleCandidate"),
-    (Keysym::Hangul_SingleCandidate, "Hangul_SingleCandidate"),
-    (Keysym::MultipleCandidate, "MultipleCandidate"),
-    (Keysym::Zen_Koho, "Zen_Koho"),
-    (Keysym::Hangul_MultipleCandidate, "Hangul_MultipleCandidate"),
-    (Keysym::PreviousCandidate, "PreviousCandidate"),
-    (Keysym::Mae_Koho, "Mae_Koho"),
-    (Keysym::Hangul_PreviousCandidate, "Hangul_PreviousCandidate"),
-    (Keysym::Hangul_Special, "Hangul_Special"),
-    (Keysym::Home, "Home"),
-    (Keysym::Left, "Left"),
-    (Keysym::Up, "Up"),
-    (Keysym::Right, "Right"),
-    (Keysym::Down, "Down"),
-    (Keysym::Prior, "Prior"),
-    (Keysym::Page_Up, "Page_Up"),
-    (Keysym::SUN_PageUp, "SunPageUp"),
-    (Keysym::Next, "Next"),
-    (Keysym::Page_Down, "Page_Down"),
-    (Keysym::SUN_PageDown, "SunPageDown"),
-    (Keysym::End, "End"),
-    (Keysym::Begin, "Begin"),
-    (Keysym::Select, "Select"),
-    (Keysym::Print, "Print"),
-    (Keysym::SUN_Print_Screen, "SunPrint_Screen"),
-    (Keysym::Execute, "Execute"),
-    (Keysym::Insert, "Insert"),
-    (Keysym::Undo, "Undo"),
-    (Keysym::SUN_Undo, "SunUndo"),
-    (Keysym::Redo, "Redo"),
-    (Keysym::SUN_Again, "SunAgain"),
-    (Keysym::Menu, "Menu"),
-    (Keysym::Find, "Find"),
-    (Keysym::SUN_Find, "SunFind"),
-    (Keysym::Cancel, "Cancel"),
-    (Keysym::SUN_Stop, "SunStop"),
-    (Keysym::Help, "Help"),
-    (Keysym::Break, "Break"),
-    (Keysym::Mode_switch, "Mode_switch"),
-    (Keysym::script_switch, "script_switch"),
-    (Keysym::ISO_Group_Shift, "ISO_Group_Shift"),
-    (Keysym::kana_switch, "kana_switch"),
-    (Keysym::Arabic_switch, "Arabic_switch"),
-    (Keysym::Greek_switch, "Greek_switch"),
-    (Keysym::Hebrew_switch, "Hebrew_switch"),
-    (Keysym::Hangul_switch, "Hangul_switch"),
-    (Keysym::SUN_AltGraph, "SunAltGraph"),
-    (Keysym::Num_Lock, "Num_Lock"),
-    (Keysym::KP_Space, "KP_Space"),
-    (Keysym::KP_Tab, "KP_Tab"),
-    (Keysym::KP_Enter, "KP_Enter"),
-    (Keysym::KP_F1, "KP_F1"),
-    (Keysym::KP_F2, "KP_F2"),
-    (Keysym::KP_F3, "KP_F3"),
-    (Keysym::KP_F4, "KP_F4"),
-    (Keysym::KP_Home, "KP_Home"),
-    (Keysym::KP_Left, "KP_Left"),
-    (Keysym::KP_Up, "KP_Up"),
-    (Keysym::KP_Right, "KP_Right"),
-    (Keysym::KP_Down, "KP_Down"),
-    (Keysym::KP_Prior, "KP_Prior"),
-    (Keysym::KP_Page_Up, "KP_Page_Up"),
-    (Keysym::KP_Next, "KP_Next"),
-    (Keysym::KP_Page_Down, "KP_Page_Down"),
-    (Keysym::KP_End, "KP_End"),
-    (Keysym::KP_Begin, "KP_Begin"),
-    (Keysym::KP_Insert, "KP_Insert"),
-    (Keysym::KP_Delete, "KP_Delete"),
-    (Keysym::KP_Multiply, "KP_Multiply"),
-    (Keysym::KP_Add, "KP_Add"),
-    (Keysym::KP_Separator, "KP_Separator"),
-    (Keysym::KP_Subtract, "KP_Subtract"),
-    (Keysym::KP_Decimal, "KP_Decimal"),
-    (Keysym::KP_Divide, "KP_Divide"),
-    (Keysym::KP_0, "KP_0"),
-    (Keysym::KP_1, "KP_1"),
-    (Keysym::KP_2, "KP_2"),
-    (Keysym::KP_3, "KP_3"),
-    (Keysym::KP_4, "KP_4"),
-    (Keysym::KP_5, "KP_5"),
-    (Keysym::KP_6, "KP_6"),
-    (Keysym::KP_7, "KP_7"),
-    (Keysym::KP_8, "KP_8"),
-    (Keysym::KP_9, "KP_9"),
-    (Keysym::KP_Equal, "KP_Equal"),
-    (Keysym::F1, "F1"),
-    (Keysym::F2, "F2"),
-    (Keysym::F3, "F3"),
-    (Keysym::F4, "F4"),
-    (Keysym::F5, "F5"),
-    (Keysym::F6, "F6"),
-    (Keysym::F7, "F7"),
-    (Keysym::F8, "F8"),
-    (Keysym::F9, "F9"),
-    (Keysym::F10, "F10"),
-    (Keysym::F11, "F11"),
-    (Keysym::L1, "L1"),
-    (Keysym::F12, "F12"),
-    (Keysym::L2, "L2"),
-    (Keysym::F13, "F13"),
-    (Keysym::L3, "L3"),
-    (Keysym::F14, "F14"),
-    (Keysym::L4, "L4"),
-    (Keysym::F15, "F15"),
-    (Keysym::L5, "L5"),
-    (Keysym::F16, "F16"),
-    (Keysym::L6, "L6"),
-    (Keysym::F17, "F17"),
-    (Keysym::L7, "L7"),
-    (Keysym::F18, "F18"),
-    (Keysym::L8, "L8"),
-    (Keysym::F19, "F19"),
-    (Keysym::L9, "L9"),
-    (Keysym::F20, "F20"),
-    (Keysym::L10, "L10"),
-    (Keysym::F21, "F21"),
-    (Keysym::R1, "R1"),
-    (Keysym::F22, "F22"),
-    (Keysym::R2, "R2"),
-    (Keysym::F23, "F23"),
-    (Keysym::R3, "R3"),
-    (Keysym::F24, "F24"),
-    (Keysym::R4, "R4"),
-    (Keysym::F25, "F25"),
-    (Keysym::R5, "R5"),
-    (Keysym::F26, "F26"),
-    (Keysym::R6, "R6"),
-    (Keysym::F27, "F27"),
-    (Keysym::R7, "R7"),
-    (Keysym::F28, "F28"),
-    (Keysym::R8, "R8"),
-    (Keysym::F29, "F29"),
-    (Keysym::R9, "R9"),
-    (Keysym::F30, "F30"),
-    (Keysym::R10, "R10"),
-    (Keysym::F31, "F31"),
-    (Keysym::R11, "R11"),
-    (Keysym::F32, "F32"),
-    (Keysym::R12, "R12"),
-    (Keysym::F33, "F33"),
-    (Keysym::R13, "R13"),
-    (Keysym::F34, "F34"),
-    (Keysym::R14, "R14"),
-    (Keysym::F35, "F35"),
-    (Keysym::R15, "R15"),
-    (Keysym::Shift_L, "Shift_L"),
-    (Keysym::Shift_R, "Shift_R"),
-    (Keysym::Control_L, "Control_L"),
-    (Keysym::Control_R, "Control_R"),
-    (Keysym::Caps_Lock, "Caps_Lock"),
-    (Keysym::Shift_Lock, "Shift_Lock"),
-    (Keysym::Meta_L, "Meta_L"),
-    (Keysym::Meta_R, "Meta_R"),
-    (Keysym::Alt_L, "Alt_L"),
-    (Keysym::Alt_R, "Alt_R"),
-    (Keysym::Super_L, "Super_L"),
-    (Keysym::Super_R, "Super_R"),
-    (Keysym::Hyper_L, "Hyper_L"),
-    (Keysym::Hyper_R, "Hyper_R"),
-    (Keysym::braille_dot_1, "braille_dot_1"),
-    (Keysym::braille_dot_2, "braille_dot_2"),
-    (Keysym::braille_dot_3, "braille_dot_3"),
-    (Keysym::braille_dot_4, "braille_dot_4"),
-    (Keysym::braille_dot_5, "braille_dot_5"),
-    (Keysym::braille_dot_6, "braille_dot_6"),
-    (Keysym::braille_dot_7, "braille_dot_7"),
-    (Keysym::braille_dot_8, "braille_dot_8"),
-    (Keysym::braille_dot_9, "braille_dot_9"),
-    (Keysym::braille_dot_10, "braille_dot_10"),
-    (Keysym::Delete, "Delete"),
-    (Keysym::VoidSymbol, "VoidSymbol"),
-    (Keysym::Ibreve, "Ibreve"),
-    (Keysym::ibreve, "ibreve"),
-    (Keysym::Wcircumflex, "Wcircumflex"),
-    (Keysym::wcircumflex, "wcircumflex"),
-    (Keysym::Ycircumflex, "Ycircumflex"),
-    (Keysym::ycircumflex, "ycircumflex"),
-    (Keysym::SCHWA, "SCHWA"),
-    (Keysym::Obarred, "Obarred"),
-    (Keysym::Ohorn, "Ohorn"),
-    (Keysym::ohorn, "ohorn"),
-    (Keysym::Uhorn, "Uhorn"),
-    (Keysym::uhorn, "uhorn"),
-    (Keysym::Zstroke, "Zstroke"),
-    (Keysym::zstroke, "zstroke"),
-    (Keysym::EZH, "EZH"),
-    (Keysym::Ocaron, "Ocaron"),
-    (Keysym::ocaron, "ocaron"),
-    (Keysym::Gcaron, "Gcaron"),
-    (Keysym::gcaron, "gcaron"),
-    (Keysym::schwa, "schwa"),
-    (Keysym::obarred, "obarred"),
-    (Keysym::ezh, "ezh"),
-    (Keysym::combining_grave, "combining_grave"),
-    (Keysym::combining_acute, "combining_acute"),
-    (Keysym::combining_tilde, "combining_tilde"),
-    (Keysym::combining_hook, "combining_hook"),
-    (Keysym::combining_belowdot, "combining_belowdot"),
-    (Keysym::Cyrillic_GHE_bar, "Cyrillic_GHE_bar"),
-    (Keysym::Cyrillic_ghe_bar, "Cyrillic_ghe_bar"),
-    (Keysym::Cyrillic_ZHE_descender, "Cyrillic_ZHE_descender"),
-    (Keysym::Cyrillic_zhe_descender, "Cyrillic_zhe_descender"),
-    (Keysym::Cyrillic_KA_descender, "Cyrillic_KA_descender"),
-    (Keysym::Cyrillic_ka_descender, "Cyrillic_ka_descender"),
-    (Keysym::Cyrillic_KA_vertstroke, "Cyrillic_KA_vertstroke"),
-    (Keysym::Cyrillic_ka_vertstroke, "Cyrillic_ka_vertstroke"),
-    (Keysym::Cyrillic_EN_descender, "Cyrillic_EN_descender"),
-    (Keysym::Cyrillic_en_descender, "Cyrillic_en_descender"),
-    (Keysym::Cyrillic_U_straight, "Cyrillic_U_straight"),
-    (Keysym::Cyrillic_u_straight, "Cyrillic_u_straight"),
-    (Keysym::Cyrillic_U_straight_bar, "Cyrillic_U_straight_bar"),
-    (Keysym::Cyrillic_u_straight_bar, "Cyrillic_u_straight_bar"),
-    (Keysym::Cyrillic_HA_descender, "Cyrillic_HA_descender"),
-    (Keysym::Cyrillic_ha_descender, "Cyrillic_ha_descender"),
-    (Keysym::Cyrillic_CHE_descender, "Cyrillic_CHE_descender"),
-    (Keysym::Cyrillic_che_descender, "Cyrillic_che_descender"),
-    (Keysym::Cyrillic_CHE_vertstroke, "Cyrillic_CHE_vertstroke"),
-    (Keysym::Cyrillic_che_vertstroke, "Cyrillic_che_vertstroke"),
-    (Keysym::Cyrillic_SHHA, "Cyrillic_SHHA"),
-    (Keysym::Cyrillic_shha, "Cyrillic_shha"),
-    (Keysym::Cyrillic_SCHWA, "Cyrillic_SCHWA"),
-    (Keysym::Cyrillic_schwa, "Cyrillic_schwa"),
-    (Keysym::Cyrillic_I_macron, "Cyrillic_I_macron"),
-    (Keysym::Cyrillic_i_macron, "Cyrillic_i_macron"),
-    (Keysym::Cyrillic_O_bar, "Cyrillic_O_bar"),
-    (Keysym::Cyrillic_o_bar, "Cyrillic_o_bar"),
-    (Keysym::Cyrillic_U_macron, "Cyrillic_U_macron"),
-    (Keysym::Cyrillic_u_macron, "Cyrillic_u_macron"),
-    (Keysym::Armenian_AYB, "Armenian_AYB"),
-    (Keysym::Armenian_BEN, "Armenian_BEN"),
-    (Keysym::Armenian_GIM, "Armenian_GIM"),
-    (Keysym::Armenian_DA, "Armenian_DA"),
-    (Keysym::Armenian_YECH, "Armenian_YECH"),
-    (Keysym::Armenian_ZA, "Armenian_ZA"),
-    (Keysym::Armenian_E, "Armenian_E"),
-    (Keysym::Armenian_AT, "Armenian_AT"),
-    (Keysym::Armenian_TO, "Armenian_TO"),
-    (Keysym::Armenian_ZHE, "Armenian_ZHE"),
-    (Keysym::Armenian_INI, "Armenian_INI"),
-    (Keysym::Armenian_LYUN, "Armenian_LYUN"),
-    (Keysym::Armenian_KHE, "Armenian_KHE"),
-    (Keysym::Armenian_TSA, "Armenian_TSA"),
-    (Keysym::Armenian_KEN, "Armenian_KEN"),
-    (Keysym::Armenian_HO, "Armenian_HO"),
-    (Keysym::Armenian_DZA, "Armenian_DZA"),
-    (Keysym::Armenian_GHAT, "Armenian_GHAT"),
-    (Keysym::Armenian_TCHE, "Armenian_TCHE"),
-    (Keysym::Armenian_MEN, "Armenian_MEN"),
-    (Keysym::Armenian_HI, "Armenian_HI"),
-    (Keysym::Armenian_NU, "Armenian_NU"),
-    (Keysym::Armenian_SHA, "Armenian_SHA"),
-    (Keysym::Armenian_VO, "Armenian_VO"),
-    (Keysym::Armenian_CHA, "Armenian_CHA"),
-    (Keysym::Armenian_PE, "Armenian_PE"),
-    (Keysym::Armenian_JE, "Armenian_JE"),
-    (Keysym::Armenian_RA, "Armenian_RA"),
-    (Keysym::Armenian_SE, "Armenian_SE"),
-    (Keysym::Armenian_VEV, "Armenian_VEV"),
-    (Keysym::Armenian_TYUN, "Armenian_TYUN"),
-    (Keysym::Armenian_RE, "Armenian_RE"),
-    (Keysym::Armenian_TSO, "Armenian_TSO"),
-    (Keysym::Armenian_VYUN, "Armenian_VYUN"),
-    (Keysym::Armenian_PYUR, "Armenian_PYUR"),
-    (Keysym::Armenian_KE, "Armenian_KE"),
-    (Keysym::Armenian_O, "Armenian_O"),
-    (Keysym::Armenian_FE, "Armenian_FE"),
-    (Keysym::Armenian_apostrophe, "Armenian_apostrophe"),
-    (Keysym::Armenian_accent, "Armenian_accent"),
-    (Keysym::Armenian_shesht, "Armenian_shesht"),
-    (Keysym::Armenian_exclam, "Armenian_exclam"),
-    (Keysym::Armenian_amanak, "Armenian_amanak"),
-    (Keysym::Armenian_separation_mark, "Armenian_separation_mark"),
-    (Keysym::Armenian_but, "Armenian_but"),
-    (Keysym::Armenian_question, "Armenian_question"),
-    (Keysym::Armenian_paruyk, "Armenian_paruyk"),
-    (Keysym::Armenian_ayb, "Armenian_ayb"),
-    (Keysym::Armenian_ben, "Armenian_ben"),
-    (Keysym::Armenian_gim, "Armenian_gim"),
-    (Keysym::Armenian_da, "Armenian_da"),
-    (Keysym::Armenian_yech, "Armenian_yech"),
-    (Keysym::Armenian_za, "Armenian_za"),
-    (Keysym::Armenian_e, "Armenian_e"),
-    (Keysym::Armenian_at, "Armenian_at"),
-    (Keysym::Armenian_to, "Armenian_to"),
-    (Keysym::Armenian_zhe, "Armenian_zhe"),
-    (Keysym::Armenian_ini, "Armenian_ini"),
-    (Keysym::Armenian_lyun, "Armenian_lyun"),
-    (Keysym::Armenian_khe, "Armenian_khe"),
-    (Keysym::Armenian_tsa, "Armenian_tsa"),
-    (Keysym::Armenian_ken, "Armenian_ken"),
-    (Keysym::Armenian_ho, "Armenian_ho"),
-    (Keysym::Armenian_dza, "Armenian_dza"),
-    (Keysym::Armenian_ghat, "Armenian_ghat"),
-    (Keysym::Armenian_tche, "Armenian_tche"),
-    (Keysym::Armenian_men, "Armenian_men"),
-    (Keysym::Armenian_hi, "Armenian_hi"),
-    (Keysym::Armenian_nu, "Armenian_nu"),
-    (Keysym::Armenian_sha, "Armenian_sha"),
-    (Keysym::Armenian_vo, "Armenian_vo"),
-    (Keysym::Armenian_cha, "Armenian_cha"),
-    (Keysym::Armenian_pe, "Armenian_pe"),
-    (Keysym::Armenian_je, "Armenian_je"),
-    (Keysym::Armenian_ra, "Armenian_ra"),
-    (Keysym::Armenian_se, "Armenian_se"),
-    (Keysym::Armenian_vev, "Armenian_vev"),
-    (Keysym::Armenian_tyun, "Armenian_tyun"),
-    (Keysym::Armenian_re, "Armenian_re"),
-    (Keysym::Armenian_tso, "Armenian_tso"),
-    (Keysym::Armenian_vyun, "Armenian_vyun"),
-    (Keysym::Armenian_pyur, "Armenian_pyur"),
-    (Keysym::Armenian_ke, "Armenian_ke"),
-    (Keysym::Armenian_o, "Armenian_o"),
-    (Keysym::Armenian_fe, "Armenian_fe"),
-    (Keysym::Armenian_ligature_ew, "Armenian_ligature_ew"),
-    (Keysym::Armenian_full_stop, "Armenian_full_stop"),
-    (Keysym::Armenian_verjaket, "Armenian_verjaket"),
-    (Keysym::Armenian_hyphen, "Armenian_hyphen"),
-    (Keysym::Armenian_yentamna, "Armenian_yentamna"),
-    (Keysym::Arabic_madda_above, "Arabic_madda_above"),
-    (Keysym::Arabic_hamza_above, "Arabic_hamza_above"),
-    (Keysym::Arabic_hamza_below, "Arabic_hamza_below"),
-    (Keysym::Arabic_0, "Arabic_0"),
-    (Keysym::Arabic_1, "Arabic_1"),
-    (Keysym::Arabic_2, "Arabic_2"),
-    (Keysym::Arabic_3, "Arabic_3"),
-    (Keysym::Arabic_4, "Arabic_4"),
-    (Keysym::Arabic_5, "Arabic_5"),
-    (Keysym::Arabic_6, "Arabic_6"),
-    (Keysym::Arabic_7, "Arabic_7"),
-    (Keysym::Arabic_8, "Arabic_8"),
-    (Keysym::Arabic_9, "Arabic_9"),
-    (Keysym::Arabic_percent, "Arabic_percent"),
-    (Keysym::Arabic_superscript_alef, "Arabic_superscript_alef"),
-    (Keysym::Arabic_tteh, "Arabic_tteh"),
-    (Keysym::Arabic_peh, "Arabic_peh"),
-    (Keysym::Arabic_tcheh, "Arabic_tcheh"),
-    (Keysym::Arabic_ddal, "Arabic_ddal"),
-    (Keysym::Arabic_rreh, "Arabic_rreh"),
-    (Keysym::Arabic_jeh, "Arabic_jeh"),
-    (Keysym::Arabic_veh, "Arabic_veh"),
-    (Keysym::Arabic_keheh, "Arabic_keheh"),
-    (Keysym::Arabic_gaf, "Arabic_gaf"),
-    (Keysym::Arabic_noon_ghunna, "Arabic_noon_ghunna"),
-    (Keysym::Arabic_heh_doachashmee, "Arabic_heh_doachashmee"),
-    (Keysym::Arabic_heh_goal, "Arabic_heh_goal"),
-    (Keysym::Farsi_yeh, "Farsi_yeh"),
-    (Keysym::Arabic_farsi_yeh, "Arabic_farsi_yeh"),
-    (Keysym::Arabic_yeh_baree, "Arabic_yeh_baree"),
-    (Keysym::Arabic_fullstop, "Arabic_fullstop"),
-    (Keysym::Farsi_0, "Farsi_0"),
-    (Keysym::Farsi_1, "Farsi_1"),
-    (Keysym::Farsi_2, "Farsi_2"),
-    (Keysym::Farsi_3, "Farsi_3"),
-    (Keysym::Farsi_4, "Farsi_4"),
-    (Keysym::Farsi_5, "Farsi_5"),
-    (Keysym::Farsi_6, "Farsi_6"),
-    (Keysym::Farsi_7, "Farsi_7"),
-    (Keysym::Farsi_8, "Farsi_8"),
-    (Keysym::Farsi_9, "Farsi_9"),
-    (Keysym::Sinh_ng, "Sinh_ng"),
-    (Keysym::Sinh_h2, "Sinh_h2"),
-    (Keysym::Sinh_a, "Sinh_a"),
-    (Keysym::Sinh_aa, "Sinh_aa"),
-    (Keysym::Sinh_ae, "Sinh_ae"),
-    (Keysym::Sinh_aee, "Sinh_aee"),
-    (Keysym::Sinh_i, "Sinh_i"),
-    (Keysym::Sinh_ii, "Sinh_ii"),
-    (Keysym::Sinh_u, "Sinh_u"),
-    (Keysym::Sinh_uu, "Sinh_uu"),
-    (Keysym::Sinh_ri, "Sinh_ri"),
-    (Keysym::Sinh_rii, "Sinh_rii"),
-    (Keysym::Sinh_lu, "Sinh_lu"),
-    (Keysym::Sinh_luu, "Sinh_luu"),
-    (Keysym::Sinh_e, "Sinh_e"),
-    (Keysym::Sinh_ee, "Sinh_ee"),
-    (Keysym::Sinh_ai, "Sinh_ai"),
-    (Keysym::Sinh_o, "Sinh_o"),
-    (Keysym::Sinh_oo, "Sinh_oo"),
-    (Keysym::Sinh_au, "Sinh_au"),
-    (Keysym::Sinh_ka, "Sinh_ka"),
-    (Keysym::Sinh_kha, "Sinh_kha"),
-    (Keysym::Sinh_ga, "Sinh_ga"),
-    (Keysym::Sinh_gha, "Sinh_gha"),
-    (Keysym::Sinh_ng2, "Sinh_ng2"),
-    (Keysym::Sinh_nga, "Sinh_nga"),
-    (Keysym::Sinh_ca, "Sinh_ca"),
-    (Keysym::Sinh_cha, "Sinh_cha"),
-    (Keysym::Sinh_ja, "Sinh_ja"),
-    (Keysym::Sinh_jha, "Sinh_jha"),
-    (Keysym::Sinh_nya, "Sinh_nya"),
-    (Keysym::Sinh_jnya, "Sinh_jnya"),
-    (Keysym::Sinh_nja, "Sinh_nja"),
-    (Keysym::Sinh_tta, "Sinh_tta"),
-    (Keysym::Sinh_ttha, "Sinh_ttha"),
-    (Keysym::Sinh_dda, "Sinh_dda"),
-    (Keysym::Sinh_ddha, "Sinh_ddha"),
-    (Keysym::Sinh_nna, "Sinh_nna"),
-    (Keysym::Sinh_ndda, "Sinh_ndda"),
-    (Keysym::Sinh_tha, "Sinh_tha"),
-    (Keysym::Sinh_thha, "Sinh_thha"),
-    (Keysym::Sinh_dha, "Sinh_dha"),
-    (Keysym::Sinh_dhha, "Sinh_dhha"),
-    (Keysym::Sinh_na, "Sinh_na"),
-    (Keysym::Sinh_ndha, "Sinh_ndha"),
-    (Keysym::Sinh_pa, "Sinh_pa"),
-    (Keysym::Sinh_pha, "Sinh_pha"),
-    (Keysym::Sinh_ba, "Sinh_ba"),
-    (Keysym::Sinh_bha, "Sinh_bha"),
-    (Keysym::Sinh_ma, "Sinh_ma"),
-    (Keysym::Sinh_mba, "Sinh_mba"),
-    (Keysym::Sinh_ya, "Sinh_ya"),
-    (Keysym::Sinh_ra, "Sinh_ra"),
-    (Keysym::Sinh_la, "Sinh_la"),
-    (Keysym::Sinh_va, "Sinh_va"),
-    (Keysym::Sinh_sha, "Sinh_sha"),
-    (Keysym::Sinh_ssha, "Sinh_ssha"),
-    (Keysym::Sinh_sa, "Sinh_sa"),
-    (Keysym::Sinh_ha, "Sinh_ha"),
-    (Keysym::Sinh_lla, "Sinh_lla"),
-    (Keysym::Sinh_fa, "Sinh_fa"),
-    (Keysym::Sinh_al, "Sinh_al"),
-    (Keysym::Sinh_aa2, "Sinh_aa2"),
-    (Keysym::Sinh_ae2, "Sinh_ae2"),
-    (Keysym::Sinh_aee2, "Sinh_aee2"),
-    (Keysym::Sinh_i2, "Sinh_i2"),
-    (Keysym::Sinh_ii2, "Sinh_ii2"),
-    (Keysym::Sinh_u2, "Sinh_u2"),
-    (Keysym::Sinh_uu2, "Sinh_uu2"),
-    (Keysym::Sinh_ru2, "Sinh_ru2"),
-    (Keysym::Sinh_e2, "Sinh_e2"),
-    (Keysym::Sinh_ee2, "Sinh_ee2"),
-    (Keysym::Sinh_ai2, "Sinh_ai2"),
-    (Keysym::Sinh_o2, "Sinh_o2"),
-    (Keysym::Sinh_oo2, "Sinh_oo2"),
-    (Keysym::Sinh_au2, "Sinh_au2"),
-    (Keysym::Sinh_lu2, "Sinh_lu2"),
-    (Keysym::Sinh_ruu2, "Sinh_ruu2"),
-    (Keysym::Sinh_luu2, "Sinh_luu2"),
-    (Keysym::Sinh_kunddaliya, "Sinh_kunddaliya"),
-    (Keysym::Georgian_an, "Georgian_an"),
-    (Keysym::Georgian_ban, "Georgian_ban"),
-    (Keysym::Georgian_gan, "Georgian_gan"),
-    (Keysym::Georgian_don, "Georgian_don"),
-    (Keysym::Georgian_en, "Georgian_en"),
-    (Keysym::Georgian_vin, "Georgian_vin"),
-    (Keysym::Georgian_zen, "Georgian_zen"),
-    (Keysym::Georgian_tan, "Georgian_tan"),
-    (Keysym::Georgian_in, "Georgian_in"),
-    (Keysym::Georgian_kan, "Georgian_kan"),
-    (Keysym::Georgian_las, "Georgian_las"),
-    (Keysym::Georgian_man, "Georgian_man"),
-    (Keysym::Georgian_nar, "Georgian_nar"),
-    (Keysym::Georgian_on, "Georgian_on"),
-    (Keysym::Georgian_par, "Georgian_par"),
-    (Keysym::Georgian_zhar, "Georgian_zhar"),
-    (Keysym::Georgian_rae, "Georgian_rae"),
-    (Keysym::Georgian_san, "Georgian_san"),
-    (Keysym::Georgian_tar, "Georgian_tar"),
-    (Keysym::Georgian_un, "Georgian_un"),
-    (Keysym::Georgian_phar, "Georgian_phar"),
-    (Keysym::Georgian_khar, "Georgian_khar"),
-    (Keysym::Georgian_ghan, "Georgian_ghan"),
-    (Keysym::Georgian_qar, "Georgian_qar"),
-    (Keysym::Georgian_shin, "Georgian_shin"),
-    (Keysym::Georgian_chin, "Georgian_chin"),
-    (Keysym::Georgian_can, "Georgian_can"),
-    (Keysym::Georgian_jil, "Georgian_jil"),
-    (Keysym::Georgian_cil, "Georgian_cil"),
-    (Keysym::Georgian_char, "Georgian_char"),
-    (Keysym::Georgian_xan, "Georgian_xan"),
-    (Keysym::Georgian_jhan, "Georgian_jhan"),
-    (Keysym::Georgian_hae, "Georgian_hae"),
-    (Keysym::Georgian_he, "Georgian_he"),
-    (Keysym::Georgian_hie, "Georgian_hie"),
-    (Keysym::Georgian_we, "Georgian_we"),
-    (Keysym::Georgian_har, "Georgian_har"),
-    (Keysym::Georgian_hoe, "Georgian_hoe"),
-    (Keysym::Georgian_fi, "Georgian_fi"),
-    (Keysym::Babovedot, "Babovedot"),
-    (Keysym::babovedot, "babovedot"),
-    (Keysym::Dabovedot, "Dabovedot"),
-    (Keysym::dabovedot, "dabovedot"),
-    (Keysym::Fabovedot, "Fabovedot"),
-    (Keysym::fabovedot, "fabovedot"),
-    (Keysym::Lbelowdot, "Lbelowdot"),
-    (Keysym::lbelowdot, "lbelowdot"),
-    (Keysym::Mabovedot, "Mabovedot"),
-    (Keysym::mabovedot, "mabovedot"),
-    (Keysym::Pabovedot, "Pabovedot"),
-    (Keysym::pabovedot, "pabovedot"),
-    (Keysym::Sabovedot, "Sabovedot"),
-    (Keysym::sabovedot, "sabovedot"),
-    (Keysym::Tabovedot, "Tabovedot"),
-    (Keysym::tabovedot, "tabovedot"),
-    (Keysym::Wgrave, "Wgrave"),
-    (Keysym::wgrave, "wgrave"),
-    (Keysym::Wacute, "Wacute"),
-    (Keysym::wacute, "wacute"),
-    (Keysym::Wdiaeresis, "Wdiaeresis"),
-    (Keysym::wdiaeresis, "wdiaeresis"),
-    (Keysym::Xabovedot, "Xabovedot"),
-    (Keysym::xabovedot, "xabovedot"),
-    (Keysym::Abelowdot, "Abelowdot"),
-    (Keysym::abelowdot, "abelowdot"),
-    (Keysym::Ahook, "Ahook"),
-    (Keysym::ahook, "ahook"),
-    (Keysym::Acircumflexacute, "Acircumflexacute"),
-    (Keysym::acircumflexacute, "acircumflexacute"),
-    (Keysym::Acircumflexgrave, "Acircumflexgrave"),
-    (Keysym::acircumflexgrave, "acircumflexgrave"),
-    (Keysym::Acircumflexhook, "Acircumflexhook"),
-    (Keysym::acircumflexhook, "acircumflexhook"),
-    (Keysym::Acircumflextilde, "Acircumflextilde"),
-    (Keysym::acircumflextilde, "acircumflextilde"),
-    (Keysym::Acircumflexbelowdot, "Acircumflexbelowdot"),
-    (Keysym::acircumflexbelowdot, "acircumflexbelowdot"),
-    (Keysym::Abreveacute, "Abreveacute"),
-    (Keysym::abreveacute, "abreveacute"),
-    (Keysym::Abrevegrave, "Abrevegrave"),
-    (Keysym::abrevegrave, "abrevegrave"),
-    (Keysym::Abrevehook, "Abrevehook"),
-    (Keysym::abrevehook, "abrevehook"),
-    (Keysym::Abrevetilde, "Abrevetilde"),
-    (Keysym::abrevetilde, "abrevetilde"),
-    (Keysym::Abrevebelowdot, "Abrevebelowdot"),
-    (Keysym::abrevebelowdot, "abrevebelowdot"),
-    (Keysym::Ebelowdot, "Ebelowdot"),
-    (Keysym::ebelowdot, "ebelowdot"),
-    (Keysym::Ehook, "Ehook"),
-    (Keysym::ehook, "ehook"),
-    (Keysym::Etilde, "Etilde"),
-    (Keysym::etilde, "etilde"),
-    (Keysym::Ecircumflexacute, "Ecircumflexacute"),
-    (Keysym::ecircumflexacute, "ecircumflexacute"),
-    (Keysym::Ecircumflexgrave, "Ecircumflexgrave"),
-    (Keysym::ecircumflexgrave, "ecircumflexgrave"),
-    (Keysym::Ecircumflexhook, "Ecircumflexhook"),
-    (Keysym::ecircumflexhook, "ecircumflexhook"),
-    (Keysym::Ecircumflextilde, "Ecircumflextilde"),
-    (Keysym::ecircumflextilde, "ecircumflextilde"),
-    (Keysym::Ecircumflexbelowdot, "Ecircumflexbelowdot"),
-    (Keysym::ecircumflexbelowdot, "ecircumflexbelowdot"),
-    (Keysym::Ihook, "Ihook"),
-    (Keysym::ihook, "ihook"),
-    (Keysym::Ibelowdot, "Ibelowdot"),
-    (Keysym::ibelowdot, "ibelowdot"),
-    (Keysym::Obelowdot, "Obelowdot"),
-    (Keysym::obelowdot, "obelowdot"),
-    (Keysym::Ohook, "Ohook"),
-    (Keysym::ohook, "ohook"),
-    (Keysym::Ocircumflexacute, "Ocircumflexacute"),
-    (Keysym::ocircumflexacute, "ocircumflexacute"),
-    (Keysym::Ocircumflexgrave, "Ocircumflexgrave"),
-    (Keysym::ocircumflexgrave, "ocircumflexgrave"),
-    (Keysym::Ocircumflexhook, "Ocircumflexhook"),
-    (Keysym::ocircumflexhook, "ocircumflexhook"),
-    (Keysym::Ocircumflextilde, "Ocircumflextilde"),
-    (Keysym::ocircumflextilde, "ocircumflextilde"),
-    (Keysym::Ocircumflexbelowdot, "Ocircumflexbelowdot"),
-    (Keysym::ocircumflexbelowdot, "ocircumflexbelowdot"),
-    (Keysym::Ohornacute, "Ohornacute"),
-    (Keysym::ohornacute, "ohornacute"),
-    (Keysym::Ohorngrave, "Ohorngrave"),
-    (Keysym::ohorngrave, "ohorngrave"),
-    (Keysym::Ohornhook, "Ohornhook"),
-    (Keysym::ohornhook, "ohornhook"),
-    (Keysym::Ohorntilde, "Ohorntilde"),
-    (Keysym::ohorntilde, "ohorntilde"),
-    (Keysym::Ohornbelowdot, "Ohornbelowdot"),
-    (Keysym::ohornbelowdot, "ohornbelowdot"),
-    (Keysym::Ubelowdot, "Ubelowdot"),
-    (Keysym::ubelowdot, "ubelowdot"),
-    (Keysym::Uhook, "Uhook"),
-    (Keysym::uhook, "uhook"),
-    (Keysym::Uhornacute, "Uhornacute"),
-    (Keysym::uhornacute, "uhornacute"),
-    (Keysym::Uhorngrave, "Uhorngrave"),
-    (Keysym::uhorngrave, "uhorngrave"),
-    (Keysym::Uhornhook, "Uhornhook"),
-    (Keysym::uhornhook, "uhornhook"),
-    (Keysym::Uhorntilde, "Uhorntilde"),
-    (Keysym::uhorntilde, "uhorntilde"),
-    (Keysym::Uhornbelowdot, "Uhornbelowdot"),
-    (Keysym::uhornbelowdot, "uhornbelowdot"),
-    (Keysym::Ygrave, "Ygrave"),
-    (Keysym::ygrave, "ygrave"),
-    (Keysym::Ybelowdot, "Ybelowdot"),
-    (Keysym::ybelowdot, "ybelowdot"),
-    (Keysym::Yhook, "Yhook"),
-    (Keysym::yhook, "yhook"),
-    (Keysym::Ytilde, "Ytilde"),
-    (Keysym::ytilde, "ytilde"),
-    (Keysym::zerosuperior, "zerosuperior"),
-    (Keysym::foursuperior, "foursuperior"),
-    (Keysym::fivesuperior, "fivesuperior"),
-    (Keysym::sixsuperior, "sixsuperior"),
-    (Keysym::sevensuperior, "sevensuperior"),
-    (Keysym::eightsuperior, "eightsuperior"),
-    (Keysym::ninesuperior, "ninesuperior"),
-    (Keysym::zerosubscript, "zerosubscript"),
-    (Keysym::onesubscript, "onesubscript"),
-    (Keysym::twosubscript, "twosubscript"),
-    (Keysym::threesubscript, "threesubscript"),
-    (Keysym::foursubscript, "foursubscript"),
-    (Keysym::fivesubscript, "fivesubscript"),
-    (Keysym::sixsubscript, "sixsubscript"),
-    (Keysym::sevensubscript, "sevensubscript"),
-    (Keysym::eightsubscript, "eightsubscript"),
-    (Keysym::ninesubscript, "ninesubscript"),
-    (Keysym::EcuSign, "EcuSign"),
-    (Keysym::ColonSign, "ColonSign"),
-    (Keysym::CruzeiroSign, "CruzeiroSign"),
-    (Keysym::FFrancSign, "FFrancSign"),
-    (Keysym::LiraSign, "LiraSign"),
-    (Keysym::MillSign, "MillSign"),
-    (Keysym::NairaSign, "NairaSign"),
-    (Keysym::PesetaSign, "PesetaSign"),
-    (Keysym::RupeeSign, "RupeeSign"),
-    (Keysym::WonSign, "WonSign"),
-    (Keysym::NewSheqelSign, "NewSheqelSign"),
-    (Keysym::DongSign, "DongSign"),
-    (Keysym::partdifferential, "partdifferential"),
-    (Keysym::emptyset, "emptyset"),
-    (Keysym::elementof, "elementof"),
-    (Keysym::notelementof, "notelementof"),
-    (Keysym::containsas, "containsas"),
-    (Keysym::squareroot, "squareroot"),
-    (Keysym::cuberoot, "cuberoot"),
-    (Keysym::fourthroot, "fourthroot"),
-    (Keysym::dintegral, "dintegral"),
-    (Keysym::tintegral, "tintegral"),
-    (Keysym::because, "because"),
-    (Keysym::notapproxeq, "notapproxeq"),
-    (Keysym::approxeq, "approxeq"),
-    (Keysym::notidentical, "notidentical"),
-    (Keysym::stricteq, "stricteq"),
-    (Keysym::braille_blank, "braille_blank"),
-    (Keysym::braille_dots_1, "braille_dots_1"),
-    (Keysym::braille_dots_2, "braille_dots_2"),
-    (Keysym::braille_dots_12, "braille_dots_12"),
-    (Keysym::braille_dots_3, "braille_dots_3"),
-    (Keysym::braille_dots_13, "braille_dots_13"),
-    (Keysym::braille_dots_23, "braille_dots_23"),
-    (Keysym::braille_dots_123, "braille_dots_123"),
-    (Keysym::braille_dots_4, "braille_dots_4"),
-    (Keysym::braille_dots_14, "braille_dots_14"),
-    (Keysym::braille_dots_24, "braille_dots_24"),
-    (Keysym::braille_dots_124, "braille_dots_124"),
-    (Keysym::braille_dots_34, "braille_dots_34"),
-    (Keysym::braille_dots_134, "braille_dots_134"),
-    (Keysym::braille_dots_234, "braille_dots_234"),
-    (Keysym::braille_dots_1234, "braille_dots_1234"),
-    (Keysym::braille_dots_5, "braille_dots_5"),
-    (Keysym::braille_dots_15, "braille_dots_15"),
-    (Keysym::braille_dots_25, "braille_dots_25"),
-    (Keysym::braille_dots_125, "braille_dots_125"),
-    (Keysym::braille_dots_35, "braille_dots_35"),
-    (Keysym::braille_dots_135, "braille_dots_135"),
-    (Keysym::braille_dots_235, "braille_dots_235"),
-    (Keysym::braille_dots_1235, "braille_dots_1235"),
-    (Keysym::braille_dots_45, "braille_dots_45"),
-    (Keysym::braille_dots_145, "braille_dots_145"),
-    (Keysym::braille_dots_245, "braille_dots_245"),
-    (Keysym::braille_dots_1245, "braille_dots_1245"),
-    (Keysym::braille_dots_345, "braille_dots_345"),
-    (Keysym::braille_dots_1345, "braille_dots_1345"),
-    (Keysym::braille_dots_2345, "braille_dots_2345"),
-    (Keysym::braille_dots_12345, "braille_dots_12345"),
-    (Keysym::braille_dots_6, "braille_dots_6"),
-    (Keysym::braille_dots_16, "braille_dots_16"),
-    (Keysym::braille_dots_26, "braille_dots_26"),
-    (Keysym::braille_dots_126, "braille_dots_126"),
-    (Keysym::braille_dots_36, "braille_dots_36"),
-    (Keysym::braille_dots_136, "braille_dots_136"),
-    (Keysym::braille_dots_236, "braille_dots_236"),
-    (Keysym::braille_dots_1236, "braille_dots_1236"),
-    (Keysym::braille_dots_46, "braille_dots_46"),
-    (Keysym::braille_dots_146, "braille_dots_146"),
-    (Keysym::braille_dots_246, "braille_dots_246"),
-    (Keysym::braille_dots_1246, "braille_dots_1246"),
-    (Keysym::braille_dots_346, "braille_dots_346"),
-    (Keysym::braille_dots_1346, "braille_dots_1346"),
-    (Keysym::braille_dots_2346, "braille_dots_2346"),
-    (Keysym::braille_dots_12346, "braille_dots_12346"),
-    (Keysym::braille_dots_56, "braille_dots_56"),
-    (Keysym::braille_dots_156, "braille_dots_156"),
-    (Keysym::braille_dots_256, "braille_dots_256"),
-    (Keysym::braille_dots_1256, "braille_dots_1256"),
-    (Keysym::braille_dots_356, "braille_dots_356"),
-    (Keysym::braille_dots_1356, "braille_dots_1356"),
-    (Keysym::braille_dots_2356, "braille_dots_2356"),
-    (Keysym::braille_dots_12356, "braille_dots_12356"),
-    (Keysym::braille_dots_456, "braille_dots_456"),
-    (Keysym::braille_dots_1456, "braille_dots_1456"),
-    (Keysym::braille_dots_2456, "braille_dots_2456"),
-    (Keysym::braille_dots_12456, "braille_dots_12456"),
-    (Keysym::braille_dots_3456, "braille_dots_3456"),
-    (Keysym::braille_dots_13456, "braille_dots_13456"),
-    (Keysym::braille_dots_23456, "braille_dots_23456"),
-    (Keysym::braille_dots_123456, "braille_dots_123456"),
-    (Keysym::braille_dots_7, "braille_dots_7"),
-    (Keysym::braille_dots_17, "braille_dots_17"),
-    (Keysym::braille_dots_27, "braille_dots_27"),
-    (Keysym::braille_dots_127, "braille_dots_127"),
-    (Keysym::braille_dots_37, "braille_dots_37"),
-    (Keysym::braille_dots_137, "braille_dots_137"),
-    (Keysym::braille_dots_237, "braille_dots_237"),
-    (Keysym::braille_dots_1237, "braille_dots_1237"),
-    (Keysym::braille_dots_47, "braille_dots_47"),
-    (Keysym::braille_dots_147, "braille_dots_147"),
-    (Keysym::braille_dots_247, "braille_dots_247"),
-    (Keysym::braille_dots_1247, "braille_dots_1247"),
-    (Keysym::braille_dots_347, "braille_dots_347"),
-    (Keysym::braille_dots_1347, "braille_dots_1347"),
-    (Keysym::braille_dots_2347, "braille_dots_2347"),
-    (Keysym::braille_dots_12347, "braille_dots_12347"),
-    (Keysym::braille_dots_57, "braille_dots_57"),
-    (Keysym::braille_dots_157, "braille_dots_157"),
-    (Keysym::braille_dots_257, "braille_dots_257"),
-    (Keysym::braille_dots_1257, "braille_dots_1257"),
-    (Keysym::braille_dots_357, "braille_dots_357"),
-    (Keysym::braille_dots_1357, "braille_dots_1357"),
-    (Keysym::braille_dots_2357, "braille_dots_2357"),
-    (Keysym::braille_dots_12357, "braille_dots_12357"),
-    (Keysym::braille_dots_457, "braille_dots_457"),
-    (Keysym::braille_dots_1457, "braille_dots_1457"),
-    (Keysym::braille_dots_2457, "braille_dots_2457"),
-    (Keysym::braille_dots_12457, "braille_dots_12457"),
-    (Keysym::braille_dots_3457, "braille_dots_3457"),
-    (Keysym::braille_dots_13457, "braille_dots_13457"),
-    (Keysym::braille_dots_23457, "braille_dots_23457"),
-    (Keysym::braille_dots_123457, "braille_dots_123457"),
-    (Keysym::braille_dots_67, "braille_dots_67"),
-    (Keysym::braille_dots_167, "braille_dots_167"),
-    (Keysym::braille_dots_267, "braille_dots_267"),
-    (Keysym::braille_dots_1267, "braille_dots_1267"),
-    (Keysym::braille_dots_367, "braille_dots_367"),
-    (Keysym::braille_dots_1367, "braille_dots_1367"),
-    (Keysym::braille_dots_2367, "braille_dots_2367"),
-    (Keysym::braille_dots_12367, "braille_dots_12367"),
-    (Keysym::braille_dots_467, "braille_dots_467"),
-    (Keysym::braille_dots_1467, "braille_dots_1467"),
-    (Keysym::braille_dots_2467, "braille_dots_2467"),
-    (Keysym::braille_dots_12467, "braille_dots_12467"),
-    (Keysym::braille_dots_3467, "braille_dots_3467"),
-    (Keysym::braille_dots_13467, "braille_dots_13467"),
-    (Keysym::braille_dots_23467, "braille_dots_23467"),
-    (Keysym::braille_dots_123467, "braille_dots_123467"),
-    (Keysym::braille_dots_567, "braille_dots_567"),
-    (Keysym::braille_dots_1567, "braille_dots_1567"),
-    (Keysym::braille_dots_2567, "braille_dots_2567"),
-    (Keysym::braille_dots_12567, "braille_dots_12567"),
-    (Keysym::braille_dots_3567, "braille_dots_3567"),
-    (Keysym::braille_dots_13567, "braille_dots_13567"),
-    (Keysym::braille_dots_23567, "braille_dots_23567"),
-    (Keysym::braille_dots_123567, "braille_dots_123567"),
-    (Keysym::braille_dots_4567, "braille_dots_4567"),
-    (Keysym::braille_dots_14567, "braille_dots_14567"),
-    (Keysym::braille_dots_24567, "braille_dots_24567"),
-    (Keysym::braille_dots_124567, "braille_dots_124567"),
-    (Keysym::braille_dots_34567, "braille_dots_34567"),
-    (Keysym::braille_dots_134567, "braille_dots_134567"),
-    (Keysym::braille_dots_234567, "braille_dots_234567"),
-    (Keysym::braille_dots_1234567, "braille_dots_1234567"),
-    (Keysym::braille_dots_8, "braille_dots_8"),
-    (Keysym::braille_dots_18, "braille_dots_18"),
-    (Keysym::braille_dots_28, "braille_dots_28"),
-    (Keysym::braille_dots_128, "braille_dots_128"),
-    (Keysym::braille_dots_38, "braille_dots_38"),
-    (Keysym::braille_dots_138, "braille_dots_138"),
-    (Keysym::braille_dots_238, "braille_dots_238"),
-    (Keysym::braille_dots_1238, "braille_dots_1238"),
-    (Keysym::braille_dots_48, "braille_dots_48"),
-    (Keysym::braille_dots_148, "braille_dots_148"),
-    (Keysym::braille_dots_248, "braille_dots_248"),
-    (Keysym::braille_dots_1248, "braille_dots_1248"),
-    (Keysym::braille_dots_348, "braille_dots_348"),
-    (Keysym::braille_dots_1348, "braille_dots_1348"),
-    (Keysym::braille_dots_2348, "braille_dots_2348"),
-    (Keysym::braille_dots_12348, "braille_dots_12348"),
-    (Keysym::braille_dots_58, "braille_dots_58"),
-    (Keysym::braille_dots_158, "braille_dots_158"),
-    (Keysym::braille_dots_258, "braille_dots_258"),
-    (Keysym::braille_dots_1258, "braille_dots_1258"),
-    (Keysym::braille_dots_358, "braille_dots_358"),
-    (Keysym::braille_dots_1358, "braille_dots_1358"),
-    (Keysym::braille_dots_2358, "braille_dots_2358"),
-    (Keysym::braille_dots_12358, "braille_dots_12358"),
-    (Keysym::braille_dots_458, "braille_dots_458"),
-    (Keysym::braille_dots_1458, "braille_dots_1458"),
-    (Keysym::braille_dots_2458, "braille_dots_2458"),
-    (Keysym::braille_dots_12458, "braille_dots_12458"),
-    (Keysym::braille_dots_3458, "braille_dots_3458"),
-    (Keysym::braille_dots_13458, "braille_dots_13458"),
-    (Keysym::braille_dots_23458, "braille_dots_23458"),
-    (Keysym::braille_dots_123458, "braille_dots_123458"),
-    (Keysym::braille_dots_68, "braille_dots_68"),
-    (Keysym::braille_dots_168, "braille_dots_168"),
-    (Keysym::braille_dots_268, "braille_dots_268"),
-    (Keysym::braille_dots_1268, "braille_dots_1268"),
-    (Keysym::braille_dots_368, "braille_dots_368"),
-    (Keysym::braille_dots_1368, "braille_dots_1368"),
-    (Keysym::braille_dots_2368, "braille_dots_2368"),
-    (Keysym::braille_dots_12368, "braille_dots_12368"),
-    (Keysym::braille_dots_468, "braille_dots_468"),
-    (Keysym::braille_dots_1468, "braille_dots_1468"),
-    (Keysym::braille_dots_2468, "braille_dots_2468"),
-    (Keysym::braille_dots_12468, "braille_dots_12468"),
-    (Keysym::braille_dots_3468, "braille_dots_3468"),
-    (Keysym::braille_dots_13468, "braille_dots_13468"),
-    (Keysym::braille_dots_23468, "braille_dots_23468"),
-    (Keysym::braille_dots_123468, "braille_dots_123468"),
-    (Keysym::braille_dots_568, "braille_dots_568"),
-    (Keysym::braille_dots_1568, "braille_dots_1568"),
-    (Keysym::braille_dots_2568, "braille_dots_2568"),
-    (Keysym::braille_dots_12568, "braille_dots_12568"),
-    (Keysym::braille_dots_3568, "braille_dots_3568"),
-    (Keysym::braille_dots_13568, "braille_dots_13568"),
-    (Keysym::braille_dots_23568, "braille_dots_23568"),
-    (Keysym::braille_dots_123568, "braille_dots_123568"),
-    (Keysym::braille_dots_4568, "braille_dots_4568"),
-    (Keysym::braille_dots_14568, "braille_dots_14568"),
-    (Keysym::braille_dots_24568, "braille_dots_24568"),
-    (Keysym::braille_dots_124568, "braille_dots_124568"),
-    (Keysym::braille_dots_34568, "braille_dots_34568"),
-    (Keysym::braille_dots_134568, "braille_dots_134568"),
-    (Keysym::braille_dots_234568, "braille_dots_234568"),
-    (Keysym::braille_dots_1234568, "braille_dots_1234568"),
-    (Keysym::braille_dots_78, "braille_dots_78"),
-    (Keysym::braille_dots_178, "braille_dots_178"),
-    (Keysym::braille_dots_278, "braille_dots_278"),
-    (Keysym::braille_dots_1278, "braille_dots_1278"),
-    (Keysym::braille_dots_378, "braille_dots_378"),
-    (Keysym::braille_dots_1378, "braille_dots_1378"),
-    (Keysym::braille_dots_2378, "braille_dots_2378"),
-    (Keysym::braille_dots_12378, "braille_dots_12378"),
-    (Keysym::braille_dots_478, "braille_dots_478"),
-    (Keysym::braille_dots_1478, "braille_dots_1478"),
-    (Keysym::braille_dots_2478, "braille_dots_2478"),
-    (Keysym::braille_dots_12478, "braille_dots_12478"),
-    (Keysym::braille_dots_3478, "braille_dots_3478"),
-    (Keysym::braille_dots_13478, "braille_dots_13478"),
-    (Keysym::braille_dots_23478, "braille_dots_23478"),
-    (Keysym::braille_dots_123478, "braille_dots_123478"),
-    (Keysym::braille_dots_578, "braille_dots_578"),
-    (Keysym::braille_dots_1578, "braille_dots_1578"),
-    (Keysym::braille_dots_2578, "braille_dots_2578"),
-    (Keysym::braille_dots_12578, "braille_dots_12578"),
-    (Keysym::braille_dots_3578, "braille_dots_3578"),
-    (Keysym::braille_dots_13578, "braille_dots_13578"),
-    (Keysym::braille_dots_23578, "braille_dots_23578"),
-    (Keysym::braille_dots_123578, "braille_dots_123578"),
-    (Keysym::braille_dots_4578, "braille_dots_4578"),
-    (Keysym::braille_dots_14578, "braille_dots_14578"),
-    (Keysym::braille_dots_24578, "braille_dots_24578"),
-    (Keysym::braille_dots_124578, "braille_dots_124578"),
-    (Keysym::braille_dots_34578, "braille_dots_34578"),
-    (Keysym::braille_dots_134578, "braille_dots_134578"),
-    (Keysym::braille_dots_234578, "braille_dots_234578"),
-    (Keysym::braille_dots_1234578, "braille_dots_1234578"),
-    (Keysym::braille_dots_678, "braille_dots_678"),
-    (Keysym::braille_dots_1678, "braille_dots_1678"),
-    (Keysym::braille_dots_2678, "braille_dots_2678"),
-    (Keysym::braille_dots_12678, "braille_dots_12678"),
-    (Keysym::braille_dots_3678, "braille_dots_3678"),
-    (Keysym::braille_dots_13678, "braille_dots_13678"),
-    (Keysym::braille_dots_23678, "braille_dots_23678"),
-    (Keysym::braille_dots_123678, "braille_dots_123678"),
-    (Keysym::braille_dots_4678, "braille_dots_4678"),
-    (Keysym::braille_dots_14678, "braille_dots_14678"),
-    (Keysym::braille_dots_24678, "braille_dots_24678"),
-    (Keysym::braille_dots_124678, "braille_dots_124678"),
-    (Keysym::braille_dots_34678, "braille_dots_34678"),
-    (Keysym::braille_dots_134678, "braille_dots_134678"),
-    (Keysym::braille_dots_234678, "braille_dots_234678"),
-    (Keysym::braille_dots_1234678, "braille_dots_1234678"),
-    (Keysym::braille_dots_5678, "braille_dots_5678"),
-    (Keysym::braille_dots_15678, "braille_dots_15678"),
-    (Keysym::braille_dots_25678, "braille_dots_25678"),
-    (Keysym::braille_dots_125678, "braille_dots_125678"),
-    (Keysym::braille_dots_35678, "braille_dots_35678"),
-    (Keysym::braille_dots_135678, "braille_dots_135678"),
-    (Keysym::braille_dots_235678, "braille_dots_235678"),
-    (Keysym::braille_dots_1235678, "braille_dots_1235678"),
-    (Keysym::braille_dots_45678, "braille_dots_45678"),
-    (Keysym::braille_dots_145678, "braille_dots_145678"),
-    (Keysym::braille_dots_245678, "braille_dots_245678"),
-    (Keysym::braille_dots_1245678, "braille_dots_1245678"),
-    (Keysym::braille_dots_345678, "braille_dots_345678"),
-    (Keysym::braille_dots_1345678, "braille_dots_1345678"),
-    (Keysym::braille_dots_2345678, "braille_dots_2345678"),
-    (Keysym::braille_dots_12345678, "braille_dots_12345678"),
-    (Keysym::HP_mute_acute, "hpmute_acute"),
-    (Keysym::mute_acute, "mute_acute"),
-    (Keysym::HP_mute_grave, "hpmute_grave"),
-    (Keysym::mute_grave, "mute_grave"),
-    (Keysym::HP_mute_asciicircum, "hpmute_asciicircum"),
-    (Keysym::mute_asciicircum, "mute_asciicircum"),
-    (Keysym::HP_mute_diaeresis, "hpmute_diaeresis"),
-    (Keysym::mute_diaeresis, "mute_diaeresis"),
-    (Keysym::HP_mute_asciitilde, "hpmute_asciitilde"),
-    (Keysym::mute_asciitilde, "mute_asciitilde"),
-    (Keysym::HP_lira, "hplira"),
-    (Keysym::lira, "lira"),
-    (Keysym::HP_guilder, "hpguilder"),
-    (Keysym::guilder, "guilder"),
-    (Keysym::HP_IO, "hpIO"),
-    (Keysym::IO, "IO"),
-    (Keysym::HP_longminus, "hplongminus"),
-    (Keysym::longminus, "longminus"),
-    (Keysym::HP_block, "hpblock"),
-    (Keysym::block, "block"),
-    (Keysym::D_diaeresis, "Ddiaeresis"),
-    (Keysym::D_acute_accent, "Dacute_accent"),
-    (Keysym::D_cedilla_accent, "Dcedilla_accent"),
-    (Keysym::D_circumflex_accent, "Dcircumflex_accent"),
-    (Keysym::D_grave_accent, "Dgrave_accent"),
-    (Keysym::D_tilde, "Dtilde"),
-    (Keysym::D_ring_accent, "Dring_accent"),
-    (Keysym::D_Remove, "DRemove"),
-    (Keysym::HP_Modelock1, "hpModelock1"),
-    (Keysym::HP_Modelock2, "hpModelock2"),
-    (Keysym::HP_Reset, "hpReset"),
-    (Keysym::Reset, "Reset"),
-    (Keysym::HP_System, "hpSystem"),
-    (Keysym::System, "System"),
-    (Keysym::HP_User, "hpUser"),
-    (Keysym::User, "User"),
-    (Keysym::HP_ClearLine, "hpClearLine"),
-    (Keysym::ClearLine, "ClearLine"),
-    (Keysym::HP_InsertLine, "hpInsertLine"),
-    (Keysym::InsertLine, "InsertLine"),
-    (Keysym::HP_DeleteLine, "hpDeleteLine"),
-    (Keysym::DeleteLine, "DeleteLine"),
-    (Keysym::HP_InsertChar, "hpInsertChar"),
-    (Keysym::InsertChar, "InsertChar"),
-    (Keysym::HP_DeleteChar, "hpDeleteChar"),
-    (Keysym::DeleteChar, "DeleteChar"),
-    (Keysym::HP_BackTab, "hpBackTab"),
-    (Keysym::BackTab, "BackTab"),
-    (Keysym::HP_KP_BackTab, "hpKP_BackTab"),
-    (Keysym::KP_BackTab, "KP_BackTab"),
-    (Keysym::Ext16bit_L, "Ext16bit_L"),
-    (Keysym::Ext16bit_R, "Ext16bit_R"),
-    (Keysym::OSF_Copy, "osfCopy"),
-    (Keysym::OSF_Cut, "osfCut"),
-    (Keysym::OSF_Paste, "osfPaste"),
-    (Keysym::OSF_BackTab, "osfBackTab"),
-    (Keysym::OSF_BackSpace, "osfBackSpace"),
-    (Keysym::OSF_Clear, "osfClear"),
-    (Keysym::OSF_Escape, "osfEscape"),
-    (Keysym::OSF_AddMode, "osfAddMode"),
-    (Keysym::OSF_PrimaryPaste, "osfPrimaryPaste"),
-    (Keysym::OSF_QuickPaste, "osfQuickPaste"),
-    (Keysym::OSF_PageLeft, "osfPageLeft"),
-    (Keysym::OSF_PageUp, "osfPageUp"),
-    (Keysym::OSF_PageDown, "osfPageDown"),
-    (Keysym::OSF_PageRight, "osfPageRight"),
-    (Keysym::OSF_Activate, "osfActivate"),
-    (Keysym::OSF_MenuBar, "osfMenuBar"),
-    (Keysym::OSF_Left, "osfLeft"),
-    (Keysym::OSF_Up, "osfUp"),
-    (Keysym::OSF_Right, "osfRight"),
-    (Keysym::OSF_Down, "osfDown"),
-    (Keysym::OSF_EndLine, "osfEndLine"),
-    (Keysym::OSF_BeginLine, "osfBeginLine"),
-    (Keysym::OSF_EndData, "osfEndData"),
-    (Keysym::OSF_BeginData, "osfBeginData"),
-    (Keysym::OSF_PrevMenu, "osfPrevMenu"),
-    (Keysym::OSF_NextMenu, "osfNextMenu"),
-    (Keysym::OSF_PrevField, "osfPrevField"),
-    (Keysym::OSF_NextField, "osfNextField"),
-    (Keysym::OSF_Select, "osfSelect"),
-    (Keysym::OSF_Insert, "osfInsert"),
-    (Keysym::OSF_Undo, "osfUndo"),
-    (Keysym::OSF_Menu, "osfMenu"),
-    (Keysym::OSF_Cancel, "osfCancel"),
-    (Keysym::OSF_Help, "osfHelp"),
-    (Keysym::OSF_SelectAll, "osfSelectAll"),
-    (Keysym::OSF_DeselectAll, "osfDeselectAll"),
-    (Keysym::OSF_Reselect, "osfReselect"),
-    (Keysym::OSF_Extend, "osfExtend"),
-    (Keysym::OSF_Restore, "osfRestore"),
-    (Keysym::OSF_Delete, "osfDelete"),
-    (Keysym::SUN_FA_Grave, "SunFA_Grave"),
-    (Keysym::SUN_FA_Circum, "SunFA_Circum"),
-    (Keysym::SUN_FA_Tilde, "SunFA_Tilde"),
-    (Keysym::SUN_FA_Acute, "SunFA_Acute"),
-    (Keysym::SUN_FA_Diaeresis, "SunFA_Diaeresis"),
-    (Keysym::SUN_FA_Cedilla, "SunFA_Cedilla"),
-    (Keysym::SUN_F36, "SunF36"),
-    (Keysym::SUN_F37, "SunF37"),
-    (Keysym::SUN_Sys_Req, "SunSys_Req"),
-    (Keysym::SUN_Props, "SunProps"),
-    (Keysym::SUN_Front, "SunFront"),
-    (Keysym::SUN_Copy, "SunCopy"),
-    (Keysym::SUN_Open, "SunOpen"),
-    (Keysym::SUN_Paste, "SunPaste"),
-    (Keysym::SUN_Cut, "SunCut"),
-    (Keysym::SUN_PowerSwitch, "SunPowerSwitch"),
-    (Keysym::SUN_AudioLowerVolume, "SunAudioLowerVolume"),
-    (Keysym::SUN_AudioMute, "SunAudioMute"),
-    (Keysym::SUN_AudioRaiseVolume, "SunAudioRaiseVolume"),
-    (Keysym::SUN_VideoDegauss, "SunVideoDegauss"),
-    (Keysym::SUN_VideoLowerBrightness, "SunVideoLowerBrightness"),
-    (Keysym::SUN_VideoRaiseBrightness, "SunVideoRaiseBrightness"),
-    (Keysym::SUN_PowerSwitchShift, "SunPowerSwitchShift"),
-    (Keysym::XF86_BrightnessAuto, "XF86BrightnessAuto"),
-    (Keysym::XF86_DisplayOff, "XF86DisplayOff"),
-    (Keysym::XF86_Info, "XF86Info"),
-    (Keysym::XF86_AspectRatio, "XF86AspectRatio"),
-    (Keysym::XF86_DVD, "XF86DVD"),
-    (Keysym::XF86_Audio, "XF86Audio"),
-    (Keysym::XF86_ChannelUp, "XF86ChannelUp"),
-    (Keysym::XF86_ChannelDown, "XF86ChannelDown"),
-    (Keysym::XF86_Break, "XF86Break"),
-    (Keysym::XF86_VideoPhone, "XF86VideoPhone"),
-    (Keysym::XF86_ZoomReset, "XF86ZoomReset"),
-    (Keysym::XF86_Editor, "XF86Editor"),
-    (Keysym::XF86_GraphicsEditor, "XF86GraphicsEditor"),
-    (Keysym::XF86_Presentation, "XF86Presentation"),
-    (Keysym::XF86_Database, "XF86Database"),
-    (Keysym::XF86_Voicemail, "XF86Voicemail"),
-    (Keysym::XF86_Addressbook, "XF86Addressbook"),
-    (Keysym::XF86_DisplayToggle, "XF86DisplayToggle"),
-    (Keysym::XF86_SpellCheck, "XF86SpellCheck"),
-    (Keysym::XF86_ContextMenu, "XF86ContextMenu"),
-    (Keysym::XF86_MediaRepeat, "XF86MediaRepeat"),
-    (Keysym::XF86_10ChannelsUp, "XF8610ChannelsUp"),
-    (Keysym::XF86_10ChannelsDown, "XF8610ChannelsDown"),
-    (Keysym::XF86_Images, "XF86Images"),
-    (Keysym::XF86_NotificationCenter, "XF86NotificationCenter"),
-    (Keysym::XF86_PickupPhone, "XF86PickupPhone"),
-    (Keysym::XF86_HangupPhone, "XF86HangupPhone"),
-    (Keysym::XF86_Fn, "XF86Fn"),
-    (Keysym::XF86_Fn_Esc, "XF86Fn_Esc"),
-    (Keysym::XF86_FnRightShift, "XF86FnRightShift"),
-    (Keysym::XF86_Numeric0, "XF86Numeric0"),
-    (Keysym::XF86_Numeric1, "XF86Numeric1"),
-    (Keysym::XF86_Numeric2, "XF86Numeric2"),
-    (Keysym::XF86_Numeric3, "XF86Numeric3"),
-    (Keysym::XF86_Numeric4, "XF86Numeric4"),
-    (Keysym::XF86_Numeric5, "XF86Numeric5"),
-    (Keysym::XF86_Numeric6, "XF86Numeric6"),
-    (Keysym::XF86_Numeric7, "XF86Numeric7"),
-    (Keysym::XF86_Numeric8, "XF86Numeric8"),
-    (Keysym::XF86_Numeric9, "XF86Numeric9"),
-    (Keysym::XF86_NumericStar, "XF86NumericStar"),
-    (Keysym::XF86_NumericPound, "XF86NumericPound"),
-    (Keysym::XF86_NumericA, "XF86NumericA"),
-    (Keysym::XF86_NumericB, "XF86NumericB"),
-    (Keysym::XF86_NumericC, "XF86NumericC"),
-    (Keysym::XF86_NumericD, "XF86NumericD"),
-    (Keysym::XF86_CameraFocus, "XF86CameraFocus"),
-    (Keysym::XF86_WPSButton, "XF86WPSButton"),
-    (Keysym::XF86_CameraZoomIn, "XF86CameraZoomIn"),
-    (Keysym::XF86_CameraZoomOut, "XF86CameraZoomOut"),
-    (Keysym::XF86_CameraUp, "XF86CameraUp"),
-    (Keysym::XF86_CameraDown, "XF86CameraDown"),
-    (Keysym::XF86_CameraLeft, "XF86CameraLeft"),
-    (Keysym::XF86_CameraRight, "XF86CameraRight"),
-    (Keysym::XF86_AttendantOn, "XF86AttendantOn"),
-    (Keysym::XF86_AttendantOff, "XF86AttendantOff"),
-    (Keysym::XF86_AttendantToggle, "XF86AttendantToggle"),
-    (Keysym::XF86_LightsToggle, "XF86LightsToggle"),
-    (Keysym::XF86_ALSToggle, "XF86ALSToggle"),
-    (Keysym::XF86_Buttonconfig, "XF86Buttonconfig"),
-    (Keysym::XF86_Taskmanager, "XF86Taskmanager"),
-    (Keysym::XF86_Journal, "XF86Journal"),
-    (Keysym::XF86_ControlPanel, "XF86ControlPanel"),
-    (Keysym::XF86_AppSelect, "XF86AppSelect"),
-    (Keysym::XF86_Screensaver, "XF86Screensaver"),
-    (Keysym::XF86_VoiceCommand, "XF86VoiceCommand"),
-    (Keysym::XF86_Assistant, "XF86Assistant"),
-    (Keysym::XF86_EmojiPicker, "XF86EmojiPicker"),
-    (Keysym::XF86_Dictate, "XF86Dictate"),
-    (Keysym::XF86_CameraAccessEnable, "XF86CameraAccessEnable"),
-    (Keysym::XF86_CameraAccessDisable, "XF86CameraAccessDisable"),
-    (Keysym::XF86_CameraAccessToggle, "XF86CameraAccessToggle"),
-    (Keysym::XF86_BrightnessMin, "XF86BrightnessMin"),
-    (Keysym::XF86_BrightnessMax, "XF86BrightnessMax"),
-    (Keysym::XF86_KbdInputAssistPrev, "XF86KbdInputAssistPrev"),
-    (Keysym::XF86_KbdInputAssistNext, "XF86KbdInputAssistNext"),
-    (
-        Keysym::XF86_KbdInputAssistPrevgroup,
-        "XF86KbdInputAssistPrevgroup",
-    ),
-    (
-        Keysym::XF86_KbdInputAssistNextgroup,
-        "XF86KbdInputAssistNextgroup",
-    ),
-    (
-        Keysym::XF86_KbdInputAssistAccept,
-        "XF86KbdInputAssistAccept",
-    ),
-    (
-        Keysym::XF86_KbdInputAssistCancel,
-        "XF86KbdInputAssistCancel",
-    ),
-    (Keysym::XF86_RightUp, "XF86RightUp"),
-    (Keysym::XF86_RightDown, "XF86RightDown"),
-    (Keysym::XF86_LeftUp, "XF86LeftUp"),
-    (Keysym::XF86_LeftDown, "XF86LeftDown"),
-    (Keysym::XF86_RootMenu, "XF86RootMenu"),
-    (Keysym::XF86_MediaTopMenu, "XF86MediaTopMenu"),
-    (Keysym::XF86_Numeric11, "XF86Numeric11"),
-    (Keysym::XF86_Numeric12, "XF86Numeric12"),
-    (Keysym::XF86_AudioDesc, "XF86AudioDesc"),
-    (Keysym::XF86_3DMode, "XF863DMode"),
-    (Keysym::XF86_NextFavorite, "XF86NextFavorite"),
-    (Keysym::XF86_StopRecord, "XF86StopRecord"),
-    (Keysym::XF86_PauseRecord, "XF86PauseRecord"),
-    (Keysym::XF86_VOD, "XF86VOD"),
-    (Keysym::XF86_Unmute, "XF86Unmute"),
-    (Keysym::XF86_FastReverse, "XF86FastReverse"),
-    (Keysym::XF86_SlowReverse, "XF86SlowReverse"),
-    (Keysym::XF86_Data, "XF86Data"),
-    (Keysym::XF86_OnScreenKeyboard, "XF86OnScreenKeyboard"),
-    (Keysym::XF86_PrivacyScreenToggle, "XF86PrivacyScreenToggle"),
-    (Keysym::XF86_SelectiveScreenshot, "XF86SelectiveScreenshot"),
-    (Keysym::XF86_NextElement, "XF86NextElement"),
-    (Keysym::XF86_PreviousElement, "XF86PreviousElement"),
-    (
-        Keysym::XF86_AutopilotEngageToggle,
-        "XF86AutopilotEngageToggle",
-    ),
-    (Keysym::XF86_MarkWaypoint, "XF86MarkWaypoint"),
-    (Keysym::XF86_Sos, "XF86Sos"),
-    (Keysym::XF86_NavChart, "XF86NavChart"),
-    (Keysym::XF86_FishingChart, "XF86FishingChart"),
-    (Keysym::XF86_SingleRangeRadar, "XF86SingleRangeRadar"),
-    (Keysym::XF86_DualRangeRadar, "XF86DualRangeRadar"),
-    (Keysym::XF86_RadarOverlay, "XF86RadarOverlay"),
-    (Keysym::XF86_TraditionalSonar, "XF86TraditionalSonar"),
-    (Keysym::XF86_ClearvuSonar, "XF86ClearvuSonar"),
-    (Keysym::XF86_SidevuSonar, "XF86SidevuSonar"),
-    (Keysym::XF86_NavInfo, "XF86NavInfo"),
-    (Keysym::XF86_Macro1, "XF86Macro1"),
-    (Keysym::XF86_Macro2, "XF86Macro2"),
-    (Keysym::XF86_Macro3, "XF86Macro3"),
-    (Keysym::XF86_Macro4, "XF86Macro4"),
-    (Keysym::XF86_Macro5, "XF86Macro5"),
-    (Keysym::XF86_Macro6, "XF86Macro6"),
-    (Keysym::XF86_Macro7, "XF86Macro7"),
-    (Keysym::XF86_Macro8, "XF86Macro8"),
-    (Keysym::XF86_Macro9, "XF86Macro9"),
-    (Keysym::XF86_Macro10, "XF86Macro10"),
-    (Keysym::XF86_Macro11, "XF86Macro11"),
-    (Keysym::XF86_Macro12, "XF86Macro12"),
-    (Keysym::XF86_Macro13, "XF86Macro13"),
-    (Keysym::XF86_Macro14, "XF86Macro14"),
-    (Keysym::XF86_Macro15, "XF86Macro15"),
-    (Keysym::XF86_Macro16, "XF86Macro16"),
-    (Keysym::XF86_Macro17, "XF86Macro17"),
-    (Keysym::XF86_Macro18, "XF86Macro18"),
-    (Keysym::XF86_Macro19, "XF86Macro19"),
-    (Keysym::XF86_Macro20, "XF86Macro20"),
-    (Keysym::XF86_Macro21, "XF86Macro21"),
-    (Keysym::XF86_Macro22, "XF86Macro22"),
-    (Keysym::XF86_Macro23, "XF86Macro23"),
-    (Keysym::XF86_Macro24, "XF86Macro24"),
-    (Keysym::XF86_Macro25, "XF86Macro25"),
-    (Keysym::XF86_Macro26, "XF86Macro26"),
-    (Keysym::XF86_Macro27, "XF86Macro27"),
-    (Keysym::XF86_Macro28, "XF86Macro28"),
-    (Keysym::XF86_Macro29, "XF86Macro29"),
-    (Keysym::XF86_Macro30, "XF86Macro30"),
-    (Keysym::XF86_MacroRecordStart, "XF86MacroRecordStart"),
-    (Keysym::XF86_MacroRecordStop, "XF86MacroRecordStop"),
-    (Keysym::XF86_MacroPresetCycle, "XF86MacroPresetCycle"),
-    (Keysym::XF86_MacroPreset1, "XF86MacroPreset1"),
-    (Keysym::XF86_MacroPreset2, "XF86MacroPreset2"),
-    (Keysym::XF86_MacroPreset3, "XF86MacroPreset3"),
-    (Keysym::XF86_KbdLcdMenu1, "XF86KbdLcdMenu1"),
-    (Keysym::XF86_KbdLcdMenu2, "XF86KbdLcdMenu2"),
-    (Keysym::XF86_KbdLcdMenu3, "XF86KbdLcdMenu3"),
-    (Keysym::XF86_KbdLcdMenu4, "XF86KbdLcdMenu4"),
-    (Keysym::XF86_KbdLcdMenu5, "XF86KbdLcdMenu5"),
-    (Keysym::XF86_Switch_VT_1, "XF86Switch_VT_1"),
-    (Keysym::XF86_Switch_VT_2, "XF86Switch_VT_2"),
-    (Keysym::XF86_Switch_VT_3, "XF86Switch_VT_3"),
-    (Keysym::XF86_Switch_VT_4, "XF86Switch_VT_4"),
-    (Keysym::XF86_Switch_VT_5, "XF86Switch_VT_5"),
-    (Keysym::XF86_Switch_VT_6, "XF86Switch_VT_6"),
-    (Keysym::XF86_Switch_VT_7, "XF86Switch_VT_7"),
-    (Keysym::XF86_Switch_VT_8, "XF86Switch_VT_8"),
-    (Keysym::XF86_Switch_VT_9, "XF86Switch_VT_9"),
-    (Keysym::XF86_Switch_VT_10, "XF86Switch_VT_10"),
-    (Keysym::XF86_Switch_VT_11, "XF86Switch_VT_11"),
-    (Keysym::XF86_Switch_VT_12, "XF86Switch_VT_12"),
-    (Keysym::XF86_Ungrab, "XF86Ungrab"),
-    (Keysym::XF86_ClearGrab, "XF86ClearGrab"),
-    (Keysym::XF86_Next_VMode, "XF86Next_VMode"),
-    (Keysym::XF86_Prev_VMode, "XF86Prev_VMode"),
-    (Keysym::XF86_LogWindowTree, "XF86LogWindowTree"),
-    (Keysym::XF86_LogGrabInfo, "XF86LogGrabInfo"),
-    (Keysym::XF86_ModeLock, "XF86ModeLock"),
-    (Keysym::XF86_MonBrightnessUp, "XF86MonBrightnessUp"),
-    (Keysym::XF86_MonBrightnessDown, "XF86MonBrightnessDown"),
-    (Keysym::XF86_KbdLightOnOff, "XF86KbdLightOnOff"),
-    (Keysym::XF86_KbdBrightnessUp, "XF86KbdBrightnessUp"),
-    (Keysym::XF86_KbdBrightnessDown, "XF86KbdBrightnessDown"),
-    (Keysym::XF86_MonBrightnessCycle, "XF86MonBrightnessCycle"),
-    (Keysym::XF86_Standby, "XF86Standby"),
-    (Keysym::XF86_AudioLowerVolume, "XF86AudioLowerVolume"),
-    (Keysym::XF86_AudioMute, "XF86AudioMute"),
-    (Keysym::XF86_AudioRaiseVolume, "XF86AudioRaiseVolume"),
-    (Keysym::XF86_AudioPlay, "XF86AudioPlay"),
-    (Keysym::XF86_AudioStop, "XF86AudioStop"),
-    (Keysym::XF86_AudioPrev, "XF86AudioPrev"),
-    (Keysym::XF86_AudioNext, "XF86AudioNext"),
-    (Keysym::XF86_HomePage, "XF86HomePage"),
-    (Keysym::XF86_Mail, "XF86Mail"),
-    (Keysym::XF86_Start, "XF86Start"),
-    (Keysym::XF86_Search, "XF86Search"),
-    (Keysym::XF86_AudioRecord, "XF86AudioRecord"),
-    (Keysym::XF86_Calculator, "XF86Calculator"),
-    (Keysym::XF86_Memo, "XF86Memo"),
-    (Keysym::XF86_ToDoList, "XF86ToDoList"),
-    (Keysym::XF86_Calendar, "XF86Calendar"),
-    (Keysym::XF86_PowerDown, "XF86PowerDown"),
-    (Keysym::XF86_ContrastAdjust, "XF86ContrastAdjust"),
-    (Keysym::XF86_RockerUp, "XF86RockerUp"),
-    (Keysym::XF86_RockerDown, "XF86RockerDown"),
-    (Keysym::XF86_RockerEnter, "XF86RockerEnter"),
-    (Keysym::XF86_Back, "XF86Back"),
-    (Keysym::XF86_Forward, "XF86Forward"),
-    (Keysym::XF86_Stop, "XF86Stop"),
-    (Keysym::XF86_Refresh, "XF86Refresh"),
-    (Keysym::XF86_PowerOff, "XF86PowerOff"),
-    (Keysym::XF86_WakeUp, "XF86WakeUp"),
-    (Keysym::XF86_Eject, "XF86Eject"),
-    (Keysym::XF86_ScreenSaver, "XF86ScreenSaver"),
-    (Keysym::XF86_WWW, "XF86WWW"),
-    (Keysym::XF86_Sleep, "XF86Sleep"),
-    (Keysym::XF86_Favorites, "XF86Favorites"),
-    (Keysym::XF86_AudioPause, "XF86AudioPause"),
-    (Keysym::XF86_AudioMedia, "XF86AudioMedia"),
-    (Keysym::XF86_MyComputer, "XF86MyComputer"),
-    (Keysym::XF86_VendorHome, "XF86VendorHome"),
-    (Keysym::XF86_LightBulb, "XF86LightBulb"),
-    (Keysym::XF86_Shop, "XF86Shop"),
-    (Keysym::XF86_History, "XF86History"),
-    (Keysym::XF86_OpenURL, "XF86OpenURL"),
-    (Keysym::XF86_AddFavorite, "XF86AddFavorite"),
-    (Keysym::XF86_HotLinks, "XF86HotLinks"),
-    (Keysym::XF86_BrightnessAdjust, "XF86BrightnessAdjust"),
-    (Keysym::XF86_Finance, "XF86Finance"),
-    (Keysym::XF86_Community, "XF86Community"),
-    (Keysym::XF86_AudioRewind, "XF86AudioRewind"),
-    (Keysym::XF86_BackForward, "XF86BackForward"),
-    (Keysym::XF86_Launch0, "XF86Launch0"),
-    (Keysym::XF86_Launch1, "XF86Launch1"),
-    (Keysym::XF86_Launch2, "XF86Launch2"),
-    (Keysym::XF86_Launch3, "XF86Launch3"),
-    (Keysym::XF86_Launch4, "XF86Launch4"),
-    (Keysym::XF86_Launch5, "XF86Launch5"),
-    (Keysym::XF86_Launch6, "XF86Launch6"),
-    (Keysym::XF86_Launch7, "XF86Launch7"),
-    (Keysym::XF86_Launch8, "XF86Launch8"),
-    (Keysym::XF86_Launch9, "XF86Launch9"),
-    (Keysym::XF86_LaunchA, "XF86LaunchA"),
-    (Keysym::XF86_LaunchB, "XF86LaunchB"),
-    (Keysym::XF86_LaunchC, "XF86LaunchC"),
-    (Keysym::XF86_LaunchD, "XF86LaunchD"),
-    (Keysym::XF86_LaunchE, "XF86LaunchE"),
-    (Keysym::XF86_LaunchF, "XF86LaunchF"),
-    (Keysym::XF86_ApplicationLeft, "XF86ApplicationLeft"),
-    (Keysym::XF86_ApplicationRight, "XF86ApplicationRight"),
-    (Keysym::XF86_Book, "XF86Book"),
-    (Keysym::XF86_CD, "XF86CD"),
-    (Keysym::XF86_Calculater, "XF86Calculater"),
-    (Keysym::XF86_Clear, "XF86Clear"),
-    (Keysym::XF86_Close, "XF86Close"),
-    (Keysym::XF86_Copy, "XF86Copy"),
-    (Keysym::XF86_Cut, "XF86Cut"),
-    (Keysym::XF86_Display, "XF86Display"),
-    (Keysym::XF86_DOS, "XF86DOS"),
-    (Keysym::XF86_Documents, "XF86Documents"),
-    (Keysym::XF86_Excel, "XF86Excel"),
-    (Keysym::XF86_Explorer, "XF86Explorer"),
-    (Keysym::XF86_Game, "XF86Game"),
-    (Keysym::XF86_Go, "XF86Go"),
-    (Keysym::XF86_iTouch, "XF86iTouch"),
-    (Keysym::XF86_LogOff, "XF86LogOff"),
-    (Keysym::XF86_Market, "XF86Market"),
-    (Keysym::XF86_Meeting, "XF86Meeting"),
-    (Keysym::XF86_MenuKB, "XF86MenuKB"),
-    (Keysym::XF86_MenuPB, "XF86MenuPB"),
-    (Keysym::XF86_MySites, "XF86MySites"),
-    (Keysym::XF86_New, "XF86New"),
-    (Keysym::XF86_News, "XF86News"),
-    (Keysym::XF86_OfficeHome, "XF86OfficeHome"),
-    (Keysym::XF86_Open, "XF86Open"),
-    (Keysym::XF86_Option, "XF86Option"),
-    (Keysym::XF86_Paste, "XF86Paste"),
-    (Keysym::XF86_Phone, "XF86Phone"),
-    (Keysym::XF86_Q, "XF86Q"),
-    (Keysym::XF86_Reply, "XF86Reply"),
-    (Keysym::XF86_Reload, "XF86Reload"),
-    (Keysym::XF86_RotateWindows, "XF86RotateWindows"),
-    (Keysym::XF86_RotationPB, "XF86RotationPB"),
-    (Keysym::XF86_RotationKB, "XF86RotationKB"),
-    (Keysym::XF86_Save, "XF86Save"),
-    (Keysym::XF86_ScrollUp, "XF86ScrollUp"),
-    (Keysym::XF86_ScrollDown, "XF86ScrollDown"),
-    (Keysym::XF86_ScrollClick, "XF86ScrollClick"),
-    (Keysym::XF86_Send, "XF86Send"),
-    (Keysym::XF86_Spell, "XF86Spell"),
-    (Keysym::XF86_SplitScreen, "XF86SplitScreen"),
-    (Keysym::XF86_Support, "XF86Support"),
-    (Keysym::XF86_TaskPane, "XF86TaskPane"),
-    (Keysym::XF86_Terminal, "XF86Terminal"),
-    (Keysym::XF86_Tools, "XF86Tools"),
-    (Keysym::XF86_Travel, "XF86Travel"),
-    (Keysym::XF86_UserPB, "XF86UserPB"),
-    (Keysym::XF86_User1KB, "XF86User1KB"),
-    (Keysym::XF86_User2KB, "XF86User2KB"),
-    (Keysym::XF86_Video, "XF86Video"),
-    (Keysym::XF86_WheelButton, "XF86WheelButton"),
-    (Keysym::XF86_Word, "XF86Word"),
-    (Keysym::XF86_Xfer, "XF86Xfer"),
-    (Keysym::XF86_ZoomIn, "XF86ZoomIn"),
-    (Keysym::XF86_ZoomOut, "XF86ZoomOut"),
-    (Keysym::XF86_Away, "XF86Away"),
-    (Keysym::XF86_Messenger, "XF86Messenger"),
-    (Keysym::XF86_WebCam, "XF86WebCam"),
-    (Keysym::XF86_MailForward, "XF86MailForward"),
-    (Keysym::XF86_Pictures, "XF86Pictures"),
-    (Keysym::XF86_Music, "XF86Music"),
-    (Keysym::XF86_Battery, "XF86Battery"),
-    (Keysym::XF86_Bluetooth, "XF86Bluetooth"),
-    (Keysym::XF86_WLAN, "XF86WLAN"),
-    (Keysym::XF86_UWB, "XF86UWB"),
-    (Keysym::XF86_AudioForward, "XF86AudioForward"),
-    (Keysym::XF86_AudioRepeat, "XF86AudioRepeat"),
-    (Keysym::XF86_AudioRandomPlay, "XF86AudioRandomPlay"),
-    (Keysym::XF86_Subtitle, "XF86Subtitle"),
-    (Keysym::XF86_AudioCycleTrack, "XF86AudioCycleTrack"),
-    (Keysym::XF86_CycleAngle, "XF86CycleAngle"),
-    (Keysym::XF86_FrameBack, "XF86FrameBack"),
-    (Keysym::XF86_FrameForward, "XF86FrameForward"),
-    (Keysym::XF86_Time, "XF86Time"),
-    (Keysym::XF86_Select, "XF86Select"),
-    (Keysym::XF86_View, "XF86View"),
-    (Keysym::XF86_TopMenu, "XF86TopMenu"),
-    (Keysym::XF86_Red, "XF86Red"),
-    (Keysym::XF86_Green, "XF86Green"),
-    (Keysym::XF86_Yellow, "XF86Yellow"),
-    (Keysym::XF86_Blue, "XF86Blue"),
-    (Keysym::XF86_Suspend, "XF86Suspend"),
-    (Keysym::XF86_Hibernate, "XF86Hibernate"),
-    (Keysym::XF86_TouchpadToggle, "XF86TouchpadToggle"),
-    (Keysym::XF86_TouchpadOn, "XF86TouchpadOn"),
-    (Keysym::XF86_TouchpadOff, "XF86TouchpadOff"),
-    (Keysym::XF86_AudioMicMute, "XF86AudioMicMute"),
-    (Keysym::XF86_Keyboard, "XF86Keyboard"),
-    (Keysym::XF86_WWAN, "XF86WWAN"),
-    (Keysym::XF86_RFKill, "XF86RFKill"),
-    (Keysym::XF86_AudioPreset, "XF86AudioPreset"),
-    (Keysym::XF86_RotationLockToggle, "XF86RotationLockToggle"),
-    (Keysym::XF86_FullScreen, "XF86FullScreen"),
-];
+// Generated in build.rs from the xorgproto headers in xorgproto/include/X11.
+include!(concat!(env!("OUT_DIR"), "/keysyms_generated_phf.rs"));
@@ -0,0 +1,196 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use crate::keysyms::keysym_get_name;
+use crate::keysyms_utf::{keysym_dead_to_combining, keysym_to_utf32};
+use xkeysym::Keysym;
+
+/// The style of the labels returned by [keysym_get_label()].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LabelStyle {
+    /// Short labels which fit on a key cap, e.g. `⏎` for `Return`.
+    #[default]
+    Compact,
+    /// Longer labels in plain text, e.g. `Enter` for `Return`.
+    Descriptive,
+}
+
+// (keysym, compact, descriptive)
+const LABELS: &[(Keysym, &str, &str)] = &[
+    // TTY function keys
+    (Keysym::BackSpace, "⌫", "Backspace"),
+    (Keysym::Tab, "⇥", "Tab"),
+    (Keysym::ISO_Left_Tab, "⇤", "Left Tab"),
+    (Keysym::Linefeed, "␊", "Line Feed"),
+    (Keysym::Clear, "⌧", "Clear"),
+    (Keysym::Return, "⏎", "Enter"),
+    (Keysym::Pause, "⎉", "Pause"),
+    (Keysym::Scroll_Lock, "⇳", "Scroll Lock"),
+    (Keysym::Sys_Req, "SysRq", "System Request"),
+    (Keysym::Escape, "⎋", "Escape"),
+    (Keysym::Delete, "⌦", "Delete"),
+    (Keysym::space, "␣", "Space"),
+    (Keysym::nobreakspace, "⍽", "No-Break Space"),
+    // Cursor keys
+    (Keysym::Home, "⇱", "Home"),
+    (Keysym::Left, "←", "Left"),
+    (Keysym::Up, "↑", "Up"),
+    (Keysym::Right, "→", "Right"),
+    (Keysym::Down, "↓", "Down"),
+    (Keysym::Prior, "⇞", "Page Up"),
+    (Keysym::Next, "⇟", "Page Down"),
+    (Keysym::End, "⇲", "End"),
+    // Misc function keys
+    (Keysym::Print, "⎙", "Print Screen"),
+    (Keysym::Insert, "Ins", "Insert"),
+    (Keysym::Undo, "↶", "Undo"),
+    (Keysym::Redo, "↷", "Redo"),
+    (Keysym::Menu, "≣", "Menu"),
+    (Keysym::Find, "Find", "Find"),
+    (Keysym::Help, "Help", "Help"),
+    (Keysym::Break, "Break", "Break"),
+    (Keysym::Multi_key, "⎄", "Compose"),
+    // Modifiers
+    (Keysym::Shift_L, "⇧", "Shift"),
+    (Keysym::Shift_R, "⇧", "Shift"),
+    (Keysym::Control_L, "Ctrl", "Control"),
+    (Keysym::Control_R, "Ctrl", "Control"),
+    (Keysym::Caps_Lock, "⇪", "Caps Lock"),
+    (Keysym::Shift_Lock, "⇪", "Shift Lock"),
+    (Keysym::Meta_L, "Meta", "Meta"),
+    (Keysym::Meta_R, "Meta", "Meta"),
+    (Keysym::Alt_L, "Alt", "Alt"),
+    (Keysym::Alt_R, "Alt", "Alt"),
+    (Keysym::Super_L, "❖", "Super"),
+    (Keysym::Super_R, "❖", "Super"),
+    (Keysym::Hyper_L, "Hyper", "Hyper"),
+    (Keysym::Hyper_R, "Hyper", "Hyper"),
+    (Keysym::Num_Lock, "⇭", "Num Lock"),
+    (Keysym::Mode_switch, "AltGr", "Mode Switch"),
+    (Keysym::ISO_Level3_Shift, "AltGr", "AltGr"),
+    (Keysym::ISO_Level5_Shift, "Lvl5", "Level 5 Shift"),
+    // Keypad
+    (Keysym::KP_Enter, "⌤", "Keypad Enter"),
+    // XF86 keys
+    (Keysym::XF86_AudioLowerVolume, "🔉", "Volume Down"),
+    (Keysym::XF86_AudioMute, "🔇", "Mute"),
+    (Keysym::XF86_AudioRaiseVolume, "🔊", "Volume Up"),
+    (Keysym::XF86_AudioPlay, "⏯", "Play"),
+    (Keysym::XF86_AudioPause, "⏸", "Pause"),
+    (Keysym::XF86_AudioStop, "⏹", "Stop"),
+    (Keysym::XF86_AudioPrev, "⏮", "Previous Track"),
+    (Keysym::XF86_AudioNext, "⏭", "Next Track"),
+    (Keysym::XF86_AudioRecord, "⏺", "Record"),
+    (Keysym::XF86_AudioMicMute, "🎙", "Microphone Mute"),
+    (Keysym::XF86_MonBrightnessUp, "🔆", "Brightness Up"),
+    (Keysym::XF86_MonBrightnessDown, "🔅", "Brightness Down"),
+    (Keysym::XF86_Eject, "⏏", "Eject"),
+    (Keysym::XF86_PowerOff, "⏻", "Power Off"),
+    (Keysym::XF86_Sleep, "⏾", "Sleep"),
+    (Keysym::XF86_Calculator, "🖩", "Calculator"),
+    (Keysym::XF86_Mail, "✉", "Mail"),
+    (Keysym::XF86_Search, "🔍", "Search"),
+    (Keysym::XF86_HomePage, "⌂", "Home Page"),
+    (Keysym::XF86_Back, "⇦", "Back"),
+    (Keysym::XF86_Forward, "⇨", "Forward"),
+    (Keysym::XF86_Refresh, "⟳", "Refresh"),
+];
+
+/// Get a short label for a keysym, suitable for display, e.g. on a key cap.
+///
+/// The label of a printable keysym is its character. Dead keys are shown as their combining
+/// mark on a dotted circle, and common function keys as symbols like `⏎`, `⇧` or `⌫` in the
+/// [LabelStyle::Compact] style. Other keysyms fall back to a label made from their name, e.g.
+/// `Audio Raise Volume` for `XF86AudioRaiseVolume` in the [LabelStyle::Descriptive] style.
+///
+/// Returns `None` for `NoSymbol` and for keysyms without a name.
+pub fn keysym_get_label(keysym: &Keysym, style: LabelStyle) -> Option<String> {
+    if *keysym == Keysym::NoSymbol {
+        return None;
+    }
+
+    if let Some(&(_, compact, descriptive)) = LABELS.iter().find(|(ks, _, _)| ks == keysym) {
+        return Some(
+            match style {
+                LabelStyle::Compact => compact,
+                LabelStyle::Descriptive => descriptive,
+            }
+            .into(),
+        );
+    }
+
+    let name = keysym_get_name(keysym)?;
+
+    if let Some(dead) = name.strip_prefix("dead_") {
+        return Some(match (style, keysym_dead_to_combining(keysym)) {
+            (LabelStyle::Compact, Some(c)) => format!("\u{25CC}{c}"),
+            (LabelStyle::Compact, None) => humanize(dead, true),
+            (LabelStyle::Descriptive, _) => format!("Dead {}", humanize(dead, true)),
+        });
+    }
+
+    let c = keysym_to_utf32(keysym).and_then(char::from_u32);
+    match c {
+        Some(c) if keysym.is_keypad_key() => {
+            return Some(match style {
+                LabelStyle::Compact => c.into(),
+                LabelStyle::Descriptive => format!("Keypad {c}"),
+            });
+        }
+        Some(c) if !c.is_control() && !c.is_whitespace() => return Some(c.into()),
+        _ => {}
+    }
+
+    let name = name.strip_prefix("XF86").unwrap_or(&name);
+    Some(humanize(name, style == LabelStyle::Descriptive))
+}
+
+/// Make a label from a keysym name, e.g. `Sys Req` for `Sys_Req`. With `split_words`, words in
+/// camel case are separated too, e.g. `Audio Raise Volume` for `AudioRaiseVolume`.
+fn humanize(name: &str, split_words: bool) -> String {
+    let mut label = String::with_capacity(name.len());
+    let mut prev: Option<char> = None;
+
+    for c in name.chars() {
+        match c {
+            '_' => label.push(' '),
+            c if split_words
+                && c.is_uppercase()
+                && prev.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) =>
+            {
+                label.push(' ');
+                label.push(c);
+            }
+            c => label.push(c),
+        }
+        prev = Some(c);
+    }
+
+    match label.chars().next() {
+        Some(first) if split_words && first.is_lowercase() => {
+            first.to_uppercase().chain(label.chars().skip(1)).collect()
+        }
+        _ => label,
+    }
+}
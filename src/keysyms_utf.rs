@@ -22,15 +22,123 @@
  * DEALINGS IN THE SOFTWARE.
  *
  */
+use crate::keysyms_generated_phf::KEYSYM_TO_UNICODE;
 use xkeysym::Keysym;
 
 pub fn keysym_to_utf32(keysym: &Keysym) -> Option<u32> {
+    // the mappings declared in the keysym headers
+    if let Ok(idx) = KEYSYM_TO_UNICODE.binary_search_by_key(keysym, |(ks, _)| *ks) {
+        return Some(KEYSYM_TO_UNICODE[idx].1);
+    }
+
     if let Some(c) = keysym.key_char() {
         return Some(c as u32);
     }
 
     None
 }
+
+/// Get the combining character of a dead keysym, e.g. U+0301 COMBINING ACUTE ACCENT for
+/// `dead_acute`.
+pub(crate) fn keysym_dead_to_combining(keysym: &Keysym) -> Option<char> {
+    let c = match *keysym {
+        Keysym::dead_grave => '\u{0300}',
+        Keysym::dead_acute => '\u{0301}',
+        Keysym::dead_circumflex => '\u{0302}',
+        Keysym::dead_tilde => '\u{0303}',
+        Keysym::dead_macron => '\u{0304}',
+        Keysym::dead_breve => '\u{0306}',
+        Keysym::dead_abovedot => '\u{0307}',
+        Keysym::dead_diaeresis => '\u{0308}',
+        Keysym::dead_hook => '\u{0309}',
+        Keysym::dead_abovering => '\u{030A}',
+        Keysym::dead_doubleacute => '\u{030B}',
+        Keysym::dead_caron => '\u{030C}',
+        Keysym::dead_aboveverticalline => '\u{030D}',
+        Keysym::dead_doublegrave => '\u{030F}',
+        Keysym::dead_invertedbreve => '\u{0311}',
+        Keysym::dead_abovecomma => '\u{0313}',
+        Keysym::dead_abovereversedcomma => '\u{0314}',
+        Keysym::dead_horn => '\u{031B}',
+        Keysym::dead_belowdot => '\u{0323}',
+        Keysym::dead_belowdiaeresis => '\u{0324}',
+        Keysym::dead_belowring => '\u{0325}',
+        Keysym::dead_belowcomma => '\u{0326}',
+        Keysym::dead_cedilla => '\u{0327}',
+        Keysym::dead_ogonek => '\u{0328}',
+        Keysym::dead_belowverticalline => '\u{0329}',
+        Keysym::dead_belowcircumflex => '\u{032D}',
+        Keysym::dead_belowbreve => '\u{032E}',
+        Keysym::dead_belowtilde => '\u{0330}',
+        Keysym::dead_belowmacron => '\u{0331}',
+        Keysym::dead_lowline => '\u{0332}',
+        Keysym::dead_longsolidusoverlay => '\u{0338}',
+        Keysym::dead_iota => '\u{0345}',
+        Keysym::dead_voiced_sound => '\u{3099}',
+        Keysym::dead_semivoiced_sound => '\u{309A}',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// Get the spacing form of a dead keysym, e.g. U+00B4 ACUTE ACCENT for `dead_acute`.
+///
+/// Where Unicode has no spacing character for the accent, the combining character is applied to
+/// U+00A0 NO-BREAK SPACE.
+pub(crate) fn keysym_dead_to_spacing(keysym: &Keysym) -> Option<String> {
+    let c = match *keysym {
+        Keysym::dead_grave => '`',
+        Keysym::dead_acute => '\u{00B4}',
+        Keysym::dead_circumflex => '^',
+        Keysym::dead_tilde => '~',
+        Keysym::dead_macron => '\u{00AF}',
+        Keysym::dead_breve => '\u{02D8}',
+        Keysym::dead_abovedot => '\u{02D9}',
+        Keysym::dead_diaeresis => '\u{00A8}',
+        Keysym::dead_abovering => '\u{02DA}',
+        Keysym::dead_doubleacute => '\u{02DD}',
+        Keysym::dead_caron => '\u{02C7}',
+        Keysym::dead_cedilla => '\u{00B8}',
+        Keysym::dead_ogonek => '\u{02DB}',
+        Keysym::dead_iota => '\u{037A}',
+        Keysym::dead_voiced_sound => '\u{309B}',
+        Keysym::dead_semivoiced_sound => '\u{309C}',
+        Keysym::dead_lowline => '_',
+        keysym => {
+            let combining = keysym_dead_to_combining(&keysym)?;
+            return Some(['\u{00A0}', combining].iter().collect());
+        }
+    };
+
+    Some(c.into())
+}
+
+/// Get the keysym corresponding to a Unicode code point.
+///
+/// This is the inverse of [keysym_to_utf32()]. Where a legacy named keysym exists for the code
+/// point, e.g. `Greek_alpha` for U+03B1, it is returned; otherwise the Unicode keysym
+/// `U+XXXX` is returned. Keypad keysyms are never returned, and the control characters
+/// BackSpace, Tab, Linefeed, Clear, Return, Escape and Delete map to their function keysyms.
+///
+/// Returns `None` for surrogates, Unicode noncharacters and values outside the Unicode planes.
+pub fn utf32_to_keysym(ucs: u32) -> Option<Keysym> {
+    let c = char::from_u32(ucs)?;
+
+    match Keysym::from_char(c) {
+        Keysym::NoSymbol => None,
+        keysym => Some(keysym),
+    }
+}
+
+/// Get the keysyms corresponding to each character of a string, as in [utf32_to_keysym()].
+///
+/// Characters without a keysym, i.e. Unicode noncharacters, are skipped.
+pub fn utf8_to_keysym(s: &str) -> Vec<Keysym> {
+    s.chars()
+        .filter_map(|c| utf32_to_keysym(c as u32))
+        .collect()
+}
 /*
  * Copyright © 2012 Intel Corporation
  *
@@ -59,7 +167,7 @@ pub fn keysym_to_utf32(keysym: &Keysym) -> Option<u32> {
 pub fn keysym_to_utf8(keysym: &Keysym) -> Option<Vec<u8>> {
     let mut buf = [0; 4];
 
-    let c = keysym.key_char()?;
+    let c = keysym_to_utf32(keysym).and_then(char::from_u32)?;
 
     // TODO: is this correct?
     if c == '\0' {
@@ -150,9 +150,10 @@
 #![allow(clippy::absurd_extreme_comparisons)]
 #![crate_name = "xkbcommon_rs"]
 #![forbid(unsafe_code)]
+mod keysyms_case;
+mod keysyms_label;
 mod keysyms_utf;
-// generated in project 26
-// TODO: migrate over to build.rs
+// generated in build.rs
 mod keysyms_generated_phf;
 
 mod keysyms;
@@ -246,6 +247,22 @@ pub mod xkb_keymap {
     /// If you need to change it, you must create a new one.
     pub use super::keymap::Keymap;
 
+    pub use super::keymap::lookup::KeyLookup;
+
+    pub use super::keymap::key_types::KeyTypeMapEntry;
+
+    pub use super::keymap::cache::KeymapCache;
+
+    pub use super::keymap::lint::{LintCode, LintFinding};
+
+    pub use super::keymap::diff::{Change, ItemDiff, KeyChange, KeyDiff, KeymapDiff, LayoutDiff};
+
+    #[cfg(feature = "serde")]
+    pub use super::keymap::view::{
+        AliasView, GroupView, InterpretView, KeyTypeEntryView, KeyTypeView, KeyView, KeymapView,
+        LedView, LevelView, ModView, OutOfRangeGroupView,
+    };
+
     pub use super::rust_xkbcommon::CompileFlags;
 
     /// An alternative format that can be used to initialize a [Keymap]. RMLVO = Rules, Model,
@@ -255,6 +272,10 @@ pub mod xkb_keymap {
     pub use super::rust_xkbcommon::RuleNames;
 
     pub use super::rust_xkbcommon::KeymapFormat;
+
+    pub use super::xkbcomp::ast::MergeMode;
+
+    pub use super::xkbcomp::xkbcomp::ComponentNames;
 }
 pub use xkb_keymap::Keymap;
 pub use xkb_keymap::KeymapFormat;
@@ -302,6 +323,17 @@ pub mod xkb_state {
     /// functions in the `client` feature are used.
     pub use super::state::State;
 
+    pub use super::state::ResolvedKey;
+
+    /// The text produced by a key, as returned by [State::key_get_text()].
+    pub use super::state::KeyText;
+
+    #[cfg(any(feature = "client", feature = "server"))]
+    pub use super::state::changes::{StateChanges, StateEvent};
+
+    #[cfg(feature = "server")]
+    pub use super::state::seat::{DeviceId, SeatState};
+
     pub use super::rust_xkbcommon::KeyDirection;
     /// Index of a keyboard layout.
     ///
@@ -365,6 +397,9 @@ pub mod xkb_state {
 
     pub use super::rust_xkbcommon::StateMatch;
 
+    /// Options for the text produced by a key.
+    pub use super::rust_xkbcommon::TextOptions;
+
     pub use super::keymap::XKB_MAX_GROUPS;
 }
 pub use xkb_state::State;
@@ -454,6 +489,24 @@ pub mod keysym {
     ///
     pub use super::keysyms::keysym_get_name;
 
+    /// Get all the names of a keysym, the canonical name first.
+    pub use super::keysyms::keysym_get_names;
+
+    /// Determines whether a keysym is assigned.
+    pub use super::keysyms::keysym_is_assigned;
+
+    /// Determines whether a keysym is deprecated.
+    pub use super::keysyms::keysym_is_deprecated;
+
+    /// Determines whether a keysym name is deprecated.
+    pub use super::keysyms::keysym_name_is_deprecated;
+
+    /// Get the names, Unicode mapping and deprecation status of a keysym.
+    pub use super::keysyms::{keysym_get_info, KeysymInfo};
+
+    /// Iterate over the assigned keysyms.
+    pub use super::keysyms::KeysymIterator;
+
     /// Determines whether a keysym is a keypad symbol.
     pub use super::keysyms::keysym_is_keypad;
 
@@ -466,12 +519,40 @@ pub mod keysym {
     /// Determines whether a keysym is uppercase.
     pub use super::keysyms::keysym_is_upper;
 
+    /// Get the category of a keysym.
+    pub use super::keysyms::{keysym_category, KeysymCategory};
+
+    /// Search keysym names by prefix, or by similarity for suggestions.
+    pub use super::keysyms::{keysym_search_prefix, keysym_suggest};
+
+    /// Get a short label for a keysym, suitable for display.
+    pub use super::keysyms_label::{keysym_get_label, LabelStyle};
+
     /// Converts a keysym to its lowercase representation.
     pub use super::keysyms::keysym_to_lower;
 
     /// Converts a keysym to its uppercase representation.
     pub use super::keysyms::keysym_to_upper;
 
+    /// Converts a keysym to its titlecase representation.
+    pub use super::keysyms_case::keysym_to_title;
+
+    /// Locale-sensitive case mapping of keysyms.
+    #[cfg(feature = "unicode-case")]
+    pub use super::keysyms_case::KeysymCaseMapper;
+
+    /// Get the Unicode code point of a keysym.
+    pub use super::keysyms_utf::keysym_to_utf32;
+
+    /// Get the UTF-8 encoding of the character of a keysym.
+    pub use super::keysyms_utf::keysym_to_utf8;
+
+    /// Get the keysym corresponding to a Unicode code point.
+    pub use super::keysyms_utf::utf32_to_keysym;
+
+    /// Get the keysyms corresponding to the characters of a string.
+    pub use super::keysyms_utf::utf8_to_keysym;
+
     /// The flags for [keysym_from_name()].
     pub use super::rust_xkbcommon::KeysymFlags;
 
@@ -163,6 +163,7 @@ impl Keycode {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RuleNames
 // xkb_rule_names
 {
@@ -456,6 +457,38 @@ pub struct StateMatch: u32 {
     const NON_EXCLUSIVE = (1 << 16);
 }}
 
+bitflags::bitflags! {
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    /// Options for the text produced by a key, as in `State::key_get_text()`.
+    ///
+    /// The default is `NFC` with the `unicode-normalization` feature, and no options otherwise.
+    pub struct TextOptions: u32 {
+        /// Do not apply any options.
+        const NO_FLAGS = 0;
+        /// Normalize the text to Unicode Normalization Form C, e.g. so that `e` followed by
+        /// U+0301 COMBINING ACUTE ACCENT is returned as `é`.
+        ///
+        /// Requires the `unicode-normalization` feature.
+        #[cfg(feature = "unicode-normalization")]
+        const NFC = (1 << 0);
+        /// Produce the spacing form of dead keys, e.g. `´` for `dead_acute`, instead of no text.
+        const SPACING_DEAD_KEYS = (1 << 1);
+    }
+}
+
+impl Default for TextOptions {
+    #[cfg(feature = "unicode-normalization")]
+    fn default() -> Self {
+        TextOptions::NFC
+    }
+
+    #[cfg(not(feature = "unicode-normalization"))]
+    fn default() -> Self {
+        TextOptions::NO_FLAGS
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ConsumedMode {
     /// This is the mode defined in the XKB specification and used by libX11.
     ///
@@ -470,6 +503,21 @@ pub enum ConsumedMode {
     ///
     ///
     Gtk,
+
+    /// This mode approximates shortcut matching on Windows.
+    ///
+    /// `Control`, `Alt` (`Mod1`) and `Super` (`Mod4`) are command modifiers and are never
+    /// consumed. `Shift` and `Lock` are only consumed if they change the class of the produced
+    /// character (letter, digit or other), so that `Control+Shift+<A>` is reported as
+    /// `Control+Shift+a` while `Control+Shift+<1>` is reported as `Control+exclam`.
+    /// Other modifiers, such as `AltGr`, are consumed as in [ConsumedMode::Xkb].
+    Windows,
+
+    /// This mode approximates shortcut matching on macOS.
+    ///
+    /// Same as [ConsumedMode::Windows], except that `Alt` (Option) is a text modifier: it is
+    /// consumed as in [ConsumedMode::Xkb] if it may affect key translation.
+    MacOs,
 }
 
 #[cfg(test)]
@@ -85,6 +85,11 @@ use crate::keymap::*;
 use crate::keysyms::*;
 use crate::rust_xkbcommon::*;
 
+#[cfg(any(feature = "client", feature = "server"))]
+pub(crate) mod changes;
+#[cfg(feature = "server")]
+pub(crate) mod seat;
+
 pub(crate) mod errors {
     use super::*;
     use thiserror::Error;
@@ -136,6 +141,13 @@ pub(crate) mod errors {
 }
 use errors::*;
 
+// Built once rather than for every key; the normalizer is not `Sync`, so it is per thread
+#[cfg(feature = "unicode-normalization")]
+thread_local! {
+    static NFC_NORMALIZER: icu_normalizer::ComposingNormalizer =
+        const { icu_normalizer::ComposingNormalizer::new_nfc() };
+}
+
 impl Filter {
     fn new(action: Action, key: RawKeycode) -> Result<Self, InternalStateError> {
         use ActionType::*;
@@ -210,6 +222,9 @@ impl Filter {
             GroupSet => self.group_set_func(key, direction, inner_state),
 
             GroupLock => self.group_lock_func(key, direction, inner_state),
+
+            #[cfg(feature = "server")]
+            GroupLatch => self.group_latch_func(key, layout, level, direction, inner_state),
         }
     }
 }
@@ -246,6 +261,9 @@ enum FilterFunc {
     ModLock,
     GroupSet,
     GroupLock,
+    // Only for the pending latches of `State::update_latched_locked()`
+    #[cfg(feature = "server")]
+    GroupLatch,
 }
 
 impl TryFrom<ActionType> for FilterFunc {
@@ -315,6 +333,10 @@ pub struct State {
 
     // Keymap contains the context (for now)
     keymap: Keymap,
+
+    // Full case mapping for the Caps transformation, if enabled
+    #[cfg(feature = "unicode-case")]
+    case_mapper: Option<crate::keysyms_case::KeysymCaseMapper>,
 }
 
 #[derive(Clone)]
@@ -505,6 +527,71 @@ impl Filters {
 
         Ok(Some(idx))
     }
+
+    /// Adds a latch filter which is not bound to any key, so that
+    /// modifiers latched out of band are cleared by the next key press.
+    #[cfg(feature = "server")]
+    fn add_pending_latch(&mut self, mods: ModMask) {
+        let action = Action::Mods(ModAction {
+            action_type: ActionType::ModLatch,
+            flags: ActionFlags::empty(),
+            mods: Mods { mods, mask: mods },
+        });
+
+        if let Ok(Some(idx)) = self.add_or_modify_filter(action, XKB_KEYCODE_INVALID) {
+            self.filters[idx]._priv = FilterData::Latch(LatchState::Pending);
+        }
+    }
+
+    /// Adds a layout latch filter which is not bound to any key, so that
+    /// a layout latched out of band is cleared by the next key press.
+    #[cfg(feature = "server")]
+    fn add_pending_group_latch(&mut self, group: i32) {
+        let filter = Filter {
+            action: Action::Group(GroupAction {
+                action_type: ActionType::GroupLatch,
+                flags: ActionFlags::empty(),
+                group: Some(group),
+            }),
+            func: Some(FilterFunc::GroupLatch),
+            key: XKB_KEYCODE_INVALID,
+            refcnt: 1,
+            _priv: FilterData::Latch(LatchState::Pending),
+        };
+
+        match self.filters.iter_mut().find(|f| f.func.is_none()) {
+            Some(empty) => *empty = filter,
+            None => self.filters.push(filter),
+        }
+    }
+
+    /// Cancels the pending layout latch, if any.
+    #[cfg(feature = "server")]
+    fn cancel_pending_group_latch(&mut self) {
+        for filter in self.filters.iter_mut() {
+            if filter.func == Some(FilterFunc::GroupLatch) {
+                filter.func = None;
+            }
+        }
+    }
+
+    /// Cancels the pending latches whose modifiers are all in `cleared`.
+    #[cfg(feature = "server")]
+    fn cancel_pending_latches(&mut self, cleared: ModMask) {
+        for filter in self.filters.iter_mut() {
+            if filter.func != Some(FilterFunc::ModLatch)
+                || !matches!(filter._priv, FilterData::Latch(LatchState::Pending))
+            {
+                continue;
+            }
+
+            if let Action::Mods(ref mod_action) = filter.action {
+                if mod_action.mods.mask & !cleared == 0 {
+                    filter.func = None;
+                }
+            }
+        }
+    }
 }
 
 impl Filter {
@@ -525,6 +612,10 @@ impl Filter {
 
             GroupSet => self.group_set_new(inner_state),
             GroupLock => self.group_lock_new(inner_state),
+
+            // Created already pending, without a key
+            #[cfg(feature = "server")]
+            GroupLatch => Ok(()),
         }
     }
 }
@@ -872,6 +963,31 @@ impl Filter {
     }
 }
 
+impl Filter {
+    /// A pending layout latch is broken by the next key press, as a pending
+    /// modifier latch is.
+    #[cfg(feature = "server")]
+    fn group_latch_func(
+        &mut self,
+        key: &Key,
+        layout: LayoutIndex,
+        level: LevelIndex,
+        direction: KeyDirection,
+        inner_state: &mut InnerState,
+    ) -> Result<FilterResult, InternalStateError> {
+        if direction == KeyDirection::Down
+            && key
+                .get_action(layout, level)
+                .is_none_or(Action::breaks_latch)
+        {
+            inner_state.components.latched_group = 0;
+            self.func = None;
+        }
+
+        Ok(FilterResult::Continue)
+    }
+}
+
 impl Filters {
     /// Applies any relevant filters to the key, first from the list of
     /// filters that are currently active, then if no filter has claimed
@@ -957,9 +1073,22 @@ impl State {
             },
             filters: Filters { filters: vec![] },
             mod_key_count: [0; XKB_MAX_MODS],
+            #[cfg(feature = "unicode-case")]
+            case_mapper: None,
         }
     }
 
+    /// Use full, locale-sensitive case mapping for the Caps transformation of
+    /// [State::key_get_one_sym()], [State::key_get_utf8()] and [State::key_get_utf32()], e.g.
+    /// to get `İ` for `i` with a Turkish layout. Pass `None` to restore the default, simple case
+    /// mapping.
+    ///
+    /// Requires the `unicode-case` feature.
+    #[cfg(feature = "unicode-case")]
+    pub fn set_case_mapper(&mut self, case_mapper: Option<crate::keysyms_case::KeysymCaseMapper>) {
+        self.case_mapper = case_mapper;
+    }
+
     /// Get the keymap which a keyboard state object is using
     ///
     /// Returns the keymap which was passed to [State::new()] when creating this state object.
@@ -1173,6 +1302,92 @@ impl State {
 
         self.inner_state.components.get_changes(&prev_components)
     }
+
+    /// Update the latched and locked state of the modifiers and layout.
+    ///
+    /// This entry point is intended for *server* applications and should not be used by *client*
+    /// applications. It can be used to set the latched and locked modifiers and layout out of band,
+    /// for example from a UI layout switcher, without synthesizing key events.
+    ///
+    /// Only the modifiers in `affect_latched_mods` and `affect_locked_mods` are changed, and the
+    /// layouts are only changed when `Some(_)` is passed. Depressed modifiers and layout are left
+    /// untouched, as are the filters of the keys currently held down.
+    ///
+    /// Modifiers and layouts latched with this function are cleared by the next key press, just
+    /// like a latch triggered by a key. A pending latch whose modifiers are all cleared by this
+    /// function is cancelled, as is a pending layout latch when a new layout is latched.
+    ///
+    /// Returns a mask of state components that have changed as a result of the update. If nothing
+    /// in the state has changed, returns 0.
+    ///
+    /// Corresponds to `xkb_state_update_latched_locked`
+    #[cfg(feature = "server")]
+    pub fn update_latched_locked(
+        &mut self,
+        affect_latched_mods: ModMask,
+        latched_mods: ModMask,
+        latched_layout: Option<i32>,
+        affect_locked_mods: ModMask,
+        locked_mods: ModMask,
+        locked_layout: Option<i32>,
+    ) -> StateComponent {
+        let prev_components = self.inner_state.components.clone();
+
+        // Only include modifiers which exist in the keymap
+        let mask: ModMask = ModMask::MAX >> (XKB_MAX_MODS - self.keymap.num_mods());
+
+        // Update locks
+        let affect_locked_mods = affect_locked_mods & mask;
+        if affect_locked_mods != 0 {
+            let (clear, set) = self.resolve_affected_mods(affect_locked_mods, locked_mods);
+
+            self.inner_state.components.locked_mods &= !clear;
+            self.inner_state.components.locked_mods |= set;
+        }
+        if let Some(layout) = locked_layout {
+            self.inner_state.components.locked_group = layout;
+        }
+
+        // Update latches
+        let affect_latched_mods = affect_latched_mods & mask;
+        if affect_latched_mods != 0 {
+            let (clear, set) = self.resolve_affected_mods(affect_latched_mods, latched_mods);
+
+            self.inner_state.components.latched_mods &= !clear;
+            self.filters.cancel_pending_latches(clear);
+
+            let new_latches = set & !self.inner_state.components.latched_mods;
+            if new_latches != 0 {
+                self.inner_state.components.latched_mods |= new_latches;
+                self.filters.add_pending_latch(new_latches);
+            }
+        }
+        if let Some(layout) = latched_layout {
+            self.filters.cancel_pending_group_latch();
+            self.inner_state.components.latched_group = layout;
+            if layout != 0 {
+                self.filters.add_pending_group_latch(layout);
+            }
+        }
+
+        self.update_derived();
+
+        self.inner_state.components.get_changes(&prev_components)
+    }
+
+    /// Returns the modifiers to clear and the modifiers to set for a
+    /// `(affect, values)` pair, resolved in the same way as [State::update_mask()].
+    #[cfg(feature = "server")]
+    fn resolve_affected_mods(&self, affect: ModMask, values: ModMask) -> (ModMask, ModMask) {
+        let set = values & affect;
+        let clear = affect & !values;
+
+        // Virtual modifiers also affect the real modifiers they map to
+        (
+            clear | self.keymap.mods.mod_mask_get_effective(clear),
+            set | self.keymap.mods.mod_mask_get_effective(set),
+        )
+    }
     /// Updates the state from a set of explicit masks.
     ///
     /// This entry point is intended for *client* applications. *Server* applications should use
@@ -1192,11 +1407,36 @@ impl State {
         base_group: LayoutIndex,
         latched_group: LayoutIndex,
         locked_group: LayoutIndex,
+    ) -> StateComponent {
+        // TODO: is this right?
+        self.set_components(
+            base_mods,
+            latched_mods,
+            locked_mods,
+            base_group as i32,
+            latched_group as i32,
+            locked_group as i32,
+        )
+    }
+
+    /// Sets all the base state components at once, and updates the derived ones.
+    ///
+    /// Shared by [State::update_mask()] and the seat state, which
+    /// computes its components from the state of each device.
+    #[cfg(any(feature = "client", feature = "server"))]
+    fn set_components(
+        &mut self,
+        base_mods: ModMask,
+        latched_mods: ModMask,
+        locked_mods: ModMask,
+        base_group: i32,
+        latched_group: i32,
+        locked_group: i32,
     ) -> StateComponent {
         let prev_components = self.inner_state.components.clone();
 
         // Only include modifiers which exist in the keymap
-        let mask: ModMask = (1 << self.keymap.num_mods()) - 1;
+        let mask: ModMask = ModMask::MAX >> (XKB_MAX_MODS - self.keymap.num_mods());
 
         self.inner_state.components.base_mods = base_mods & mask;
         self.inner_state.components.latched_mods = latched_mods & mask;
@@ -1229,12 +1469,11 @@ impl State {
             .mods
             .mod_mask_get_effective(self.inner_state.components.locked_mods);
 
-        // TODO: is this right?
-        self.inner_state.components.base_group = base_group as i32;
+        self.inner_state.components.base_group = base_group;
 
-        self.inner_state.components.latched_group = latched_group as i32;
+        self.inner_state.components.latched_group = latched_group;
 
-        self.inner_state.components.locked_group = locked_group as i32;
+        self.inner_state.components.locked_group = locked_group;
         self.update_derived();
 
         self.inner_state.components.get_changes(&prev_components)
@@ -1268,31 +1507,26 @@ impl Key {
 }
 impl State {
     fn should_do_caps_transformation(&self, kc: RawKeycode) -> bool {
-        let caps = match self.keymap.mod_get_index(ModName::CAPS.name()) {
-            Some(caps) => caps,
-            None => return false,
-        };
-
-        let is_active = matches!(
-            self.mod_index_is_active(caps, StateComponent::MODS_EFFECTIVE),
-            Ok(true)
-        );
-
-        is_active && self.mod_index_is_consumed(Keycode::from(kc), caps) == Ok(false)
+        self.should_do_transformation(ModName::CAPS.name(), self.key_get_consumed_mods(kc))
     }
 
     fn should_do_ctrl_transformation(&self, kc: RawKeycode) -> bool {
-        let ctrl = match self.keymap.mod_get_index(ModName::CTRL.name()) {
-            Some(ctrl) => ctrl,
+        self.should_do_transformation(ModName::CTRL.name(), self.key_get_consumed_mods(kc))
+    }
+
+    /// Whether the named modifier is active and not in the `consumed` mask of a key.
+    fn should_do_transformation(&self, mod_name: &str, consumed: ModMask) -> bool {
+        let idx = match self.keymap.mod_get_index(mod_name) {
+            Some(idx) => idx,
             None => return false,
         };
 
         let is_active = matches!(
-            self.mod_index_is_active(ctrl, StateComponent::MODS_EFFECTIVE),
+            self.mod_index_is_active(idx, StateComponent::MODS_EFFECTIVE),
             Ok(true)
         );
 
-        is_active && self.mod_index_is_consumed(Keycode::from(kc), ctrl) == Ok(false)
+        is_active && (consumed & (1 << idx)) == 0
     }
 }
 
@@ -1339,12 +1573,21 @@ impl State {
         let sym = syms[0];
 
         if self.should_do_caps_transformation(kc) {
-            return Some(keysym_to_upper(&sym));
+            return Some(self.caps_transform(&sym));
         }
 
         Some(sym)
     }
 
+    fn caps_transform(&self, sym: &Keysym) -> Keysym {
+        #[cfg(feature = "unicode-case")]
+        if let Some(case_mapper) = &self.case_mapper {
+            return case_mapper.to_upper(sym);
+        }
+
+        keysym_to_upper(sym)
+    }
+
     // The caps and ctrl transformations require some special handling,
     // so we cannot simply use `State::get_one_sym()` for them.
     // In particular, if Control is set, we must try very hard to find
@@ -1358,22 +1601,25 @@ impl State {
 
         let layout = key.get_layout(&self.inner_state)?;
 
-        let num_layouts = self
-            .keymap
-            .num_layouts_for_key(Keycode::from(kc))
-            .filter(|n| *n != 0)?;
-
         let level = key.get_level(layout, &self.keymap, &self.inner_state)?;
 
-        let syms = key
-            .get_syms_by_level(layout, level)
-            .ok()
-            .filter(|syms| syms.len() == 1)?;
+        let syms = key.get_syms_by_level(layout, level).ok()?;
 
-        let mut sym = syms[0];
+        let consumed = key.get_consumed(ConsumedMode::Xkb, &self.keymap, &self.inner_state);
+
+        self.one_sym_for_string(key, &syms, consumed)
+    }
+
+    /// Same as [State::get_one_sym_for_string()], but for a key whose
+    /// syms and consumed modifiers are already resolved.
+    fn one_sym_for_string(&self, key: &Key, syms: &[Keysym], consumed: ModMask) -> Option<Keysym> {
+        let mut sym = match syms {
+            [sym] => *sym,
+            _ => return None,
+        };
 
-        if self.should_do_ctrl_transformation(kc) && sym > 127.into() {
-            for i in 0..num_layouts {
+        if self.should_do_transformation(ModName::CTRL.name(), consumed) && sym > 127.into() {
+            for i in 0..key.groups.len() {
                 let level = match key.get_level(i, &self.keymap, &self.inner_state) {
                     Some(level) => level,
                     None => continue,
@@ -1388,8 +1634,8 @@ impl State {
             }
         }
 
-        if self.should_do_caps_transformation(kc) {
-            sym = keysym_to_upper(&sym);
+        if self.should_do_transformation(ModName::CAPS.name(), consumed) {
+            sym = self.caps_transform(&sym);
         }
 
         Some(sym)
@@ -1400,11 +1646,25 @@ impl State {
     ///
     /// This function performs Capitalization and Control keysym transformations.
     pub fn key_get_utf8(&self, kc: impl Into<RawKeycode>) -> Option<Vec<u8>> {
-        let kc = kc.into();
+        let key = self.keymap.xkb_key(kc.into())?;
+
+        let layout = key.get_layout(&self.inner_state)?;
+
+        let level = key.get_level(layout, &self.keymap, &self.inner_state)?;
+
+        let syms = key.get_syms_by_level(layout, level).ok()?;
 
-        let syms = match self.get_one_sym_for_string(kc) {
+        let consumed = key.get_consumed(ConsumedMode::Xkb, &self.keymap, &self.inner_state);
+
+        self.utf8_for_syms(key, &syms, consumed)
+    }
+
+    /// Same as [State::key_get_utf8()], but for a key whose
+    /// syms and consumed modifiers are already resolved.
+    fn utf8_for_syms(&self, key: &Key, syms: &[Keysym], consumed: ModMask) -> Option<Vec<u8>> {
+        let syms = match self.one_sym_for_string(key, syms, consumed) {
             Some(sym) => vec![sym],
-            None => self.key_get_syms(kc),
+            None => syms.to_vec(),
         };
 
         if syms.is_empty() {
@@ -1424,7 +1684,10 @@ impl State {
             return None;
         }
 
-        if utf8.len() == 1 && utf8[0] <= 127 && self.should_do_ctrl_transformation(kc) {
+        if utf8.len() == 1
+            && utf8[0] <= 127
+            && self.should_do_transformation(ModName::CTRL.name(), consumed)
+        {
             let mut buf: [u8; 4] = [0; 4];
             let c: char = xkb_to_control(utf8[0]);
             let utf8_str = c.encode_utf8(&mut buf);
@@ -1435,6 +1698,68 @@ impl State {
         Some(utf8)
     }
 
+    /// Get the text obtained from pressing a particular key in a given keyboard state.
+    ///
+    /// Unlike [State::key_get_utf8()], the text of every keysym of the level is
+    /// concatenated, skipping those which have no text, so that e.g. a level with the
+    /// keysyms `[ e, U0301 ]` produces `é` when normalized, and `e\u{301}` otherwise.
+    /// Normalization requires the `unicode-normalization` feature. Dead keys produce no
+    /// text, unless [TextOptions::SPACING_DEAD_KEYS] is set.
+    ///
+    /// This function performs Capitalization and Control keysym transformations.
+    pub fn key_get_text(&self, kc: impl Into<RawKeycode>, options: TextOptions) -> KeyText {
+        match self.text_for_key(kc.into(), options) {
+            Some(text) => KeyText::Text(text),
+            None => KeyText::NoText,
+        }
+    }
+
+    fn text_for_key(&self, kc: RawKeycode, options: TextOptions) -> Option<String> {
+        let key = self.keymap.xkb_key(kc)?;
+
+        let layout = key.get_layout(&self.inner_state)?;
+
+        let level = key.get_level(layout, &self.keymap, &self.inner_state)?;
+
+        let syms = key.get_syms_by_level(layout, level).ok()?;
+
+        let consumed = key.get_consumed(ConsumedMode::Xkb, &self.keymap, &self.inner_state);
+
+        let syms = match self.one_sym_for_string(key, &syms, consumed) {
+            Some(sym) => vec![sym],
+            None => syms,
+        };
+
+        let mut text = String::new();
+        for sym in &syms {
+            if let Some(c) = crate::keysyms_utf::keysym_to_utf32(sym).and_then(char::from_u32) {
+                text.push(c);
+            } else if options.contains(TextOptions::SPACING_DEAD_KEYS) {
+                if let Some(spacing) = crate::keysyms_utf::keysym_dead_to_spacing(sym) {
+                    text.push_str(&spacing);
+                }
+            }
+        }
+
+        let mut chars = text.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if c.is_ascii() && self.should_do_transformation(ModName::CTRL.name(), consumed) {
+                text = xkb_to_control(c as u8).into();
+            }
+        }
+
+        if text.is_empty() {
+            return None;
+        }
+
+        #[cfg(feature = "unicode-normalization")]
+        if options.contains(TextOptions::NFC) {
+            text = NFC_NORMALIZER.with(|normalizer| normalizer.normalize(&text));
+        }
+
+        Some(text)
+    }
+
     /// Get the Unicode/UTF-32 codepoint obtained from pressing a particular key in a given
     /// keyboard state.
     ///
@@ -1777,10 +2102,88 @@ impl Key {
                     }
                 }
             }
+            ConsumedMode::Windows | ConsumedMode::MacOs => {
+                consumed = _type.mods.mask & !keymap.command_mods(mode);
+                consumed &= !self.class_preserving_mods(group, _type, keymap, inner_state);
+            }
         };
 
         consumed & !preserve
     }
+
+    /// Returns the case modifiers (`Shift` and `Lock`) which may affect
+    /// translation of the key, but do not change the class of its character.
+    fn class_preserving_mods(
+        &self,
+        group: LayoutIndex,
+        _type: &KeyType,
+        keymap: &Keymap,
+        inner_state: &InnerState,
+    ) -> ModMask {
+        let active_mods = inner_state.components.mods & _type.mods.mask;
+
+        let class_for_mods = |mods: ModMask| {
+            let level = _type
+                .get_entry_for_mods(&mods)
+                .map(|entry| entry.level)
+                .unwrap_or(0);
+
+            match self.groups[group].levels.get(level)?.syms.as_slice() {
+                [Some(sym)] => CharClass::of(sym),
+                _ => None,
+            }
+        };
+
+        [ModName::SHIFT, ModName::CAPS]
+            .iter()
+            .filter_map(|name| keymap.mod_get_index(name))
+            .map(|idx| 1 << idx)
+            .filter(|mask| _type.mods.mask & mask != 0)
+            .filter(|mask| {
+                let with = class_for_mods(active_mods | mask);
+                with.is_some() && with == class_for_mods(active_mods & !mask)
+            })
+            .fold(0, |preserved, mask| preserved | mask)
+    }
+}
+
+impl Keymap {
+    /// Returns the modifiers which are never consumed in the given shortcut mode.
+    fn command_mods(&self, mode: ConsumedMode) -> ModMask {
+        let names: &[ModName] = match mode {
+            ConsumedMode::Windows => &[ModName::CTRL, ModName::ALT, ModName::LOGO],
+            ConsumedMode::MacOs => &[ModName::CTRL, ModName::LOGO],
+            ConsumedMode::Xkb | ConsumedMode::Gtk => &[],
+        };
+
+        names
+            .iter()
+            .filter_map(|name| self.mod_get_index(name))
+            .fold(0, |mask, idx| mask | 1 << idx)
+    }
+}
+
+/// The class of the character produced by a keysym, used to decide whether
+/// a case modifier is significant for shortcut matching.
+#[derive(PartialEq)]
+enum CharClass {
+    Letter,
+    Digit,
+    Other,
+}
+
+impl CharClass {
+    fn of(sym: &Keysym) -> Option<Self> {
+        let c = char::from_u32(crate::keysyms_utf::keysym_to_utf32(sym)?)?;
+
+        Some(if c.is_alphabetic() {
+            Self::Letter
+        } else if c.is_numeric() {
+            Self::Digit
+        } else {
+            Self::Other
+        })
+    }
 }
 
 impl State {
@@ -1844,4 +2247,147 @@ impl State {
     pub fn key_get_consumed_mods(&self, kc: impl Into<RawKeycode>) -> ModMask {
         self.key_get_consumed_mods2(kc, ConsumedMode::Xkb)
     }
+
+    /// Get the keysym and modifiers to use when looking up a key binding for a given key.
+    ///
+    /// The keysym is the one the key produces when only the modifiers consumed in the given mode
+    /// are applied, and the modifiers are the effective modifiers which were not consumed.
+    /// For example, with [ConsumedMode::Windows] and `Control+Shift` depressed, `<A>` gives
+    /// `(a, Control+Shift)` and `<1>` gives `(exclam, Control)`.
+    ///
+    /// Locked modifiers such as `Lock` and `NumLock` are included in the returned modifiers;
+    /// callers will usually want to mask them out before matching bindings.
+    ///
+    /// Returns `None` if the key does not exist or does not produce exactly one keysym.
+    pub fn key_get_shortcut_keysym(
+        &self,
+        kc: impl Into<RawKeycode>,
+        mode: ConsumedMode,
+    ) -> Option<(Keysym, ModMask)> {
+        let key = self.keymap.xkb_key(kc.into())?;
+        let layout = key.get_layout(&self.inner_state)?;
+        let _type = self.keymap.types.get(key.groups[layout].key_type)?;
+
+        let consumed = key.get_consumed(mode, &self.keymap, &self.inner_state);
+        let consumed_xkb = key.get_consumed(ConsumedMode::Xkb, &self.keymap, &self.inner_state);
+        let mods = self.inner_state.components.mods;
+
+        // Translate the key without the modifiers that the
+        // mode declines to consume.
+        let level_mods = mods & _type.mods.mask & !(consumed_xkb & !consumed);
+        let level = _type
+            .get_entry_for_mods(&level_mods)
+            .map(|entry| entry.level)
+            .unwrap_or(0);
+
+        match key.groups[layout].levels.get(level)?.syms.as_slice() {
+            [Some(sym)] => Some((*sym, mods & !consumed)),
+            _ => None,
+        }
+    }
+}
+
+/// The state-dependent properties of a key, as returned by [State::resolve_all_keys()].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedKey {
+    /// The keycode of the key.
+    pub keycode: Keycode,
+    /// The effective layout of the key, as in [State::key_get_layout()].
+    pub layout: LayoutIndex,
+    /// The shift level of the key in its effective layout, as in [State::key_get_level()].
+    pub level: LevelIndex,
+    /// The keysyms of the key, as in [State::key_get_syms()].
+    pub syms: Vec<Keysym>,
+    /// The UTF-8 string produced by the key, as in [State::key_get_utf8()].
+    pub utf8: Option<Vec<u8>>,
+    /// The modifiers consumed by the key, as in [State::key_get_consumed_mods2()].
+    pub consumed_mods: ModMask,
+    /// Whether the key repeats, as in [Keymap::key_repeats()].
+    pub repeats: bool,
+}
+
+/// The text produced by a key, as returned by [State::key_get_text()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyText {
+    /// The key produces text.
+    Text(String),
+    /// The key produces no text, e.g. a modifier, a function key or an invalid keycode.
+    NoText,
+}
+
+impl KeyText {
+    /// The text, if any.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            KeyText::Text(text) => Some(text),
+            KeyText::NoText => None,
+        }
+    }
+}
+
+/// The resolution of a key type for the current modifiers,
+/// which is shared by all the keys using the type.
+#[derive(Clone, Copy)]
+struct TypeResolution {
+    level: LevelIndex,
+    preserve: ModMask,
+}
+
+impl KeyType {
+    fn resolve(&self, inner_state: &InnerState) -> TypeResolution {
+        let active_mods = inner_state.components.mods & self.mods.mask;
+
+        match self.get_entry_for_mods(&active_mods) {
+            Some(entry) => TypeResolution {
+                level: entry.level,
+                preserve: entry.preserve.mask,
+            },
+            // If we don't find an explicit match, the default is 0.
+            None => TypeResolution {
+                level: 0,
+                preserve: 0,
+            },
+        }
+    }
+}
+
+impl State {
+    /// Resolve the state-dependent properties of every key in the keymap at once.
+    ///
+    /// This is equivalent to calling [State::key_get_layout()], [State::key_get_level()],
+    /// [State::key_get_syms()], [State::key_get_utf8()] and [State::key_get_consumed_mods2()]
+    /// for every keycode in [Keymap::iter_keycodes()], but the level of each key type is only
+    /// resolved once.
+    ///
+    /// Keys without any layout are skipped.
+    pub fn resolve_all_keys(&self, mode: ConsumedMode) -> impl Iterator<Item = ResolvedKey> + '_ {
+        let mut resolutions: Vec<Option<TypeResolution>> = vec![None; self.keymap.types.len()];
+
+        self.keymap.keys.values().filter_map(move |key| {
+            let layout = key.get_layout(&self.inner_state)?;
+            let type_index = key.groups[layout].key_type;
+            let _type = self.keymap.types.get(type_index)?;
+
+            let resolution =
+                *resolutions[type_index].get_or_insert_with(|| _type.resolve(&self.inner_state));
+
+            let syms = key.get_syms_by_level(layout, resolution.level).ok()?;
+
+            let consumed_xkb = _type.mods.mask & !resolution.preserve;
+            let consumed_mods = match mode {
+                ConsumedMode::Xkb => consumed_xkb,
+                _ => key.get_consumed(mode, &self.keymap, &self.inner_state),
+            };
+
+            Some(ResolvedKey {
+                keycode: key.keycode,
+                layout,
+                level: resolution.level,
+                utf8: self.utf8_for_syms(key, &syms, consumed_xkb),
+                syms,
+                consumed_mods,
+                repeats: key.repeats,
+            })
+        })
+    }
 }
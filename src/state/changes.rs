@@ -0,0 +1,198 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+/// A single change to the keyboard state, as reported by [StateChanges].
+///
+/// Modifiers and LEDs are identified by both their index and their name in the keymap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateEvent {
+    /// The modifier has become depressed.
+    ModPressed { index: ModIndex, name: String },
+    /// The modifier is no longer depressed.
+    ModReleased { index: ModIndex, name: String },
+    /// The modifier has become latched.
+    ModLatched { index: ModIndex, name: String },
+    /// The modifier is no longer latched.
+    ModUnlatched { index: ModIndex, name: String },
+    /// The modifier has become locked.
+    ModLocked { index: ModIndex, name: String },
+    /// The modifier is no longer locked.
+    ModUnlocked { index: ModIndex, name: String },
+    /// The effective layout has changed.
+    LayoutChanged { from: LayoutIndex, to: LayoutIndex },
+    /// The LED has been turned on.
+    LedOn { index: LedIndex, name: String },
+    /// The LED has been turned off.
+    LedOff { index: LedIndex, name: String },
+}
+
+/// The changes resulting from a state update.
+///
+/// Returned by [State::update_key_with_changes()] and [State::update_mask_with_changes()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateChanges {
+    /// The mask of state components that have changed,
+    /// as returned by [State::update_key()] and [State::update_mask()].
+    pub components: StateComponent,
+    /// The individual changes, ordered by modifiers, then layout, then LEDs.
+    pub events: Vec<StateEvent>,
+}
+
+impl StateChanges {
+    /// Returns `true` if nothing in the state has changed.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+/// Builds the event for a modifier which has been turned on or off.
+type ModEventFn = fn(ModIndex, String) -> StateEvent;
+
+impl StateComponents {
+    /// Lists the changes from `prev` to `self`.
+    ///
+    /// The comparison is the same as in [StateComponents::get_changes()],
+    /// but per modifier and per LED rather than per component.
+    pub(super) fn get_change_events(&self, prev: &Self, keymap: &Keymap) -> StateChanges {
+        let components = self.get_changes(prev);
+        let mut events = vec![];
+
+        if components.intersects(
+            StateComponent::MODS_DEPRESSED
+                | StateComponent::MODS_LATCHED
+                | StateComponent::MODS_LOCKED,
+        ) {
+            for index in 0..keymap.num_mods() {
+                let mask: ModMask = 1 << index;
+                let name = || keymap.mod_get_name(index).unwrap_or_default().to_owned();
+
+                let transitions: [(ModMask, ModMask, ModEventFn, ModEventFn); 3] = [
+                    (
+                        prev.base_mods,
+                        self.base_mods,
+                        |index, name| StateEvent::ModPressed { index, name },
+                        |index, name| StateEvent::ModReleased { index, name },
+                    ),
+                    (
+                        prev.latched_mods,
+                        self.latched_mods,
+                        |index, name| StateEvent::ModLatched { index, name },
+                        |index, name| StateEvent::ModUnlatched { index, name },
+                    ),
+                    (
+                        prev.locked_mods,
+                        self.locked_mods,
+                        |index, name| StateEvent::ModLocked { index, name },
+                        |index, name| StateEvent::ModUnlocked { index, name },
+                    ),
+                ];
+
+                for (before, after, on, off) in transitions {
+                    match (before & mask != 0, after & mask != 0) {
+                        (false, true) => events.push(on(index, name())),
+                        (true, false) => events.push(off(index, name())),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        if components.contains(StateComponent::LAYOUT_EFFECTIVE) {
+            events.push(StateEvent::LayoutChanged {
+                from: prev.group,
+                to: self.group,
+            });
+        }
+
+        if components.contains(StateComponent::LEDS) {
+            for index in 0..XKB_MAX_LEDS {
+                let mask: LedMask = 1 << index;
+                let name = || keymap.led_get_name(index).unwrap_or_default().to_owned();
+
+                match (prev.leds & mask != 0, self.leds & mask != 0) {
+                    (false, true) => events.push(StateEvent::LedOn {
+                        index,
+                        name: name(),
+                    }),
+                    (true, false) => events.push(StateEvent::LedOff {
+                        index,
+                        name: name(),
+                    }),
+                    _ => {}
+                }
+            }
+        }
+
+        StateChanges { components, events }
+    }
+}
+
+impl State {
+    /// Update the keyboard state to reflect a given key being pressed or released,
+    /// as in [State::update_key()], and list the resulting changes.
+    #[cfg(feature = "server")]
+    pub fn update_key_with_changes(
+        &mut self,
+        kc: impl Into<RawKeycode>,
+        direction: KeyDirection,
+    ) -> StateChanges {
+        let prev_components = self.inner_state.components.clone();
+
+        self.update_key(kc, direction);
+
+        self.inner_state
+            .components
+            .get_change_events(&prev_components, &self.keymap)
+    }
+
+    /// Update the state from a set of explicit masks, as in [State::update_mask()],
+    /// and list the resulting changes.
+    #[cfg(feature = "client")]
+    pub fn update_mask_with_changes(
+        &mut self,
+        base_mods: ModMask,
+        latched_mods: ModMask,
+        locked_mods: ModMask,
+        base_group: LayoutIndex,
+        latched_group: LayoutIndex,
+        locked_group: LayoutIndex,
+    ) -> StateChanges {
+        let prev_components = self.inner_state.components.clone();
+
+        self.update_mask(
+            base_mods,
+            latched_mods,
+            locked_mods,
+            base_group,
+            latched_group,
+            locked_group,
+        );
+
+        self.inner_state
+            .components
+            .get_change_events(&prev_components, &self.keymap)
+    }
+}
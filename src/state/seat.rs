@@ -0,0 +1,252 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// Identifies a device within a [SeatState].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DeviceId(u32);
+
+/// The keyboard state of a seat made of several keyboard devices sharing one keymap.
+///
+/// Each device has its own [State], which keeps track of its depressed keys, filters and
+/// depressed modifiers. The seat presents one combined state, as X servers do with the core
+/// keyboard:
+/// * the depressed modifiers and layout are the union of those of every device;
+/// * the latched and locked modifiers and layout are shared by all the devices, so that e.g.
+///   Caps Lock locked from one keyboard can be unlocked from another.
+///
+/// The combined state is available through [SeatState::state()], and can be queried like any
+/// other [State].
+#[derive(Clone)]
+pub struct SeatState {
+    devices: BTreeMap<DeviceId, State>,
+    next_device_id: u32,
+
+    // The combined state of all the devices
+    combined: State,
+}
+
+impl SeatState {
+    /// Create a new seat without any device.
+    pub fn new(keymap: Keymap) -> Self {
+        Self {
+            devices: BTreeMap::new(),
+            next_device_id: 0,
+            combined: State::new(keymap),
+        }
+    }
+
+    /// Get the keymap shared by all the devices of the seat.
+    pub fn get_keymap(&self) -> &Keymap {
+        self.combined.get_keymap()
+    }
+
+    /// Get the combined state of the seat.
+    ///
+    /// This is the state that should be used to translate keys and be
+    /// serialized to clients, whichever device the key event comes from.
+    pub fn state(&self) -> &State {
+        &self.combined
+    }
+
+    /// Get the state of a single device of the seat.
+    pub fn device_state(&self, device: DeviceId) -> Option<&State> {
+        self.devices.get(&device)
+    }
+
+    /// Iterate over the devices of the seat.
+    pub fn iter_devices(&self) -> impl Iterator<Item = &DeviceId> {
+        self.devices.keys()
+    }
+
+    /// Add a device to the seat.
+    ///
+    /// The new device starts with no depressed keys, and with
+    /// the latched and locked components of the seat.
+    pub fn add_device(&mut self) -> DeviceId {
+        let id = DeviceId(self.next_device_id);
+        self.next_device_id += 1;
+
+        let mut state = State::new(self.combined.keymap.clone());
+        Self::sync_device(&mut state, &self.combined.inner_state.components);
+
+        self.devices.insert(id, state);
+
+        id
+    }
+
+    /// Remove a device from the seat.
+    ///
+    /// The modifiers and layout which were depressed by the keys of the device are released.
+    ///
+    /// Returns a mask of the combined state components that have changed, or `None` if the
+    /// device does not exist.
+    pub fn remove_device(&mut self, device: DeviceId) -> Option<StateComponent> {
+        self.devices.remove(&device)?;
+
+        let shared = self.combined.inner_state.components.clone();
+
+        Some(self.update_combined(&shared))
+    }
+
+    /// Update the state of a device to reflect a given key being pressed or released,
+    /// as in [State::update_key()].
+    ///
+    /// Changes to the latched and locked components are shared with the other devices.
+    ///
+    /// Returns a mask of the combined state components that have changed, or `None` if the
+    /// device does not exist.
+    pub fn update_key(
+        &mut self,
+        device: DeviceId,
+        kc: impl Into<RawKeycode>,
+        direction: KeyDirection,
+    ) -> Option<StateComponent> {
+        let state = self.devices.get_mut(&device)?;
+
+        let changed = state.update_key(kc, direction);
+        let shared = state.inner_state.components.clone();
+
+        if changed.intersects(
+            StateComponent::MODS_LATCHED
+                | StateComponent::MODS_LOCKED
+                | StateComponent::LAYOUT_LATCHED
+                | StateComponent::LAYOUT_LOCKED,
+        ) {
+            for (_, other) in self.devices.iter_mut().filter(|(id, _)| **id != device) {
+                Self::sync_device(other, &shared);
+            }
+        }
+
+        Some(self.update_combined(&shared))
+    }
+
+    /// Update the latched and locked modifiers and layout of every device of the seat,
+    /// as in [State::update_latched_locked()].
+    ///
+    /// Returns a mask of the combined state components that have changed.
+    pub fn update_latched_locked(
+        &mut self,
+        affect_latched_mods: ModMask,
+        latched_mods: ModMask,
+        latched_layout: Option<i32>,
+        affect_locked_mods: ModMask,
+        locked_mods: ModMask,
+        locked_layout: Option<i32>,
+    ) -> StateComponent {
+        let prev_components = self.combined.inner_state.components.clone();
+
+        // Compute the shared components from those of the seat, so that it keeps the latches
+        // and locks even without any device. The combined state is never fed key events, so
+        // it is only updated through `set_components()`; the latches are cleared by the
+        // pending latches of the devices instead.
+        let mut shared = prev_components.clone();
+        let mask = all_mods(&self.combined.keymap);
+
+        let (clear, set) = self
+            .combined
+            .resolve_affected_mods(affect_locked_mods & mask, locked_mods);
+        shared.locked_mods = (shared.locked_mods & !clear) | set;
+        if let Some(layout) = locked_layout {
+            shared.locked_group = layout;
+        }
+
+        let (clear, set) = self
+            .combined
+            .resolve_affected_mods(affect_latched_mods & mask, latched_mods);
+        shared.latched_mods = (shared.latched_mods & !clear) | set;
+        if let Some(layout) = latched_layout {
+            shared.latched_group = layout;
+        }
+
+        for state in self.devices.values_mut() {
+            Self::sync_device(state, &shared);
+        }
+        self.update_combined(&shared);
+
+        self.combined
+            .inner_state
+            .components
+            .get_changes(&prev_components)
+    }
+
+    /// The counterpart to [State::update_mask()] for modifiers, for the combined state of the
+    /// seat. See [State::serialize_mods()].
+    pub fn serialize_mods(&self, components: StateComponent) -> ModMask {
+        self.combined.serialize_mods(components)
+    }
+
+    /// The counterpart to [State::update_mask()] for layouts, for the combined state of the seat.
+    /// See [State::serialize_layout()].
+    pub fn serialize_layout(&self, components: StateComponent) -> LayoutIndex {
+        self.combined.serialize_layout(components)
+    }
+
+    /// Sets the latched and locked components of a device to the shared ones.
+    fn sync_device(state: &mut State, shared: &StateComponents) {
+        let all_mods = all_mods(&state.keymap);
+
+        state.update_latched_locked(
+            all_mods,
+            shared.latched_mods,
+            Some(shared.latched_group),
+            all_mods,
+            shared.locked_mods,
+            Some(shared.locked_group),
+        );
+    }
+
+    /// Recomputes the combined state from the depressed components of every device
+    /// and the given shared latched and locked components.
+    fn update_combined(&mut self, shared: &StateComponents) -> StateComponent {
+        let (base_mods, base_group) =
+            self.devices
+                .values()
+                .fold((0, 0), |(base_mods, base_group), state| {
+                    let components = &state.inner_state.components;
+
+                    (
+                        base_mods | components.base_mods,
+                        base_group + components.base_group,
+                    )
+                });
+
+        self.combined.set_components(
+            base_mods,
+            shared.latched_mods,
+            shared.locked_mods,
+            base_group,
+            shared.latched_group,
+            shared.locked_group,
+        )
+    }
+}
+
+// The mask of all the modifiers of the keymap
+fn all_mods(keymap: &Keymap) -> ModMask {
+    ModMask::MAX >> (XKB_MAX_MODS - keymap.num_mods())
+}
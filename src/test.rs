@@ -33,6 +33,7 @@ mod modifiers;
 mod rules_file;
 mod rules_file_includes;
 mod rulescomp;
+mod seat;
 mod state;
 mod stringcomp;
 
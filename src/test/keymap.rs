@@ -28,6 +28,7 @@
 use crate::log_init;
 use crate::rust_xkbcommon::*;
 use crate::test::*;
+use crate::xkb_keymap::*;
 
 #[test]
 fn test_garbage_key() {
@@ -216,3 +217,778 @@ fn test_multiple_keysyms_per_level() {
     assert_eq!(keysyms[0], Keysym::E);
     assert_eq!(keysyms[1], Keysym::F);
 }
+
+#[test]
+fn test_keysym_lookup() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us,ru"),
+        None,
+        Some("grp:menu_toggle"),
+    )
+    .unwrap();
+
+    let shift_mask = 1 << keymap.mod_get_index("Shift").unwrap();
+    let lock_mask = 1 << keymap.mod_get_index("Lock").unwrap();
+
+    // 'A' is typed with AC01 in the first layout
+    let results = keymap.keysym_lookup(Keysym::A);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].keycode, keymap.key_by_name("AC01").unwrap());
+    assert_eq!(results[0].layout, 0);
+    assert_eq!(results[0].level, 1);
+    assert_eq!(results[0].mods, vec![shift_mask, lock_mask]);
+
+    // Cyrillic letters are in the second layout
+    let results = keymap.keysym_lookup(Keysym::Cyrillic_ef);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].keycode, keymap.key_by_name("AC01").unwrap());
+    assert_eq!(results[0].layout, 1);
+    assert_eq!(results[0].level, 0);
+    assert_eq!(results[0].mods, vec![0]);
+
+    // '1' is on the main row and on the keypad; the main row needs no modifiers
+    let results = keymap.char_lookup('1');
+    assert!(results.len() >= 2);
+    assert_eq!(results[0].keycode, keymap.key_by_name("AE01").unwrap());
+    assert_eq!(results[0].mods, vec![0]);
+    assert!(results
+        .iter()
+        .any(|result| result.keycode == keymap.key_by_name("KP1").unwrap()));
+
+    // Characters are matched whatever the keysym encoding
+    assert_eq!(
+        keymap.char_lookup('ф'),
+        keymap.keysym_lookup(Keysym::Cyrillic_ef)
+    );
+
+    assert!(keymap.keysym_lookup(Keysym::Greek_alpha).is_empty());
+    assert!(keymap.char_lookup('α').is_empty());
+}
+
+#[test]
+fn test_keymap_diff() {
+    let compile = |layout, options| {
+        let context = test_get_context(TestContextFlags::empty()).unwrap();
+        test_compile_rules(
+            context,
+            Some("evdev"),
+            Some("pc104"),
+            Some(layout),
+            None,
+            options,
+        )
+        .unwrap()
+    };
+
+    let us = compile("us", None);
+    let us_ru = compile("us,ru", Some("grp:menu_toggle"));
+
+    assert!(us.diff(&us).is_empty());
+    assert!(us.diff(&compile("us", None)).is_empty());
+    assert_eq!(us.diff(&compile("us", None)).to_string(), "");
+
+    let diff = us.diff(&us_ru);
+    assert!(!diff.is_empty());
+
+    assert_eq!(
+        diff.layouts,
+        vec![LayoutDiff {
+            layout: 1,
+            change: Change::Added("Russian".into())
+        }]
+    );
+
+    let ac01 = diff.keys.iter().find(|key| key.name == "AC01").unwrap();
+    assert_eq!(ac01.keycode, us.key_by_name("AC01").unwrap());
+    assert!(ac01.changes.contains(&KeyChange::Keysyms {
+        layout: 1,
+        level: 0,
+        change: Change::Added(vec![Keysym::Cyrillic_ef])
+    }));
+
+    // The reverse diff has the opposite changes
+    let reverse = us_ru.diff(&us);
+    assert_eq!(
+        reverse.layouts,
+        vec![LayoutDiff {
+            layout: 1,
+            change: Change::Removed("Russian".into())
+        }]
+    );
+
+    let text = diff.to_string();
+    assert!(text.contains("layout 2: added \"Russian\""));
+    assert!(text.contains("key <AC01>: keysyms of group 2, level 1: added Cyrillic_ef"));
+    assert!(text.contains("key <COMP>: "));
+}
+
+#[test]
+fn test_keymap_overlay() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us"),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let ac01 = keymap.key_by_name("AC01").unwrap();
+    let syms = |keymap: &Keymap, level| keymap.key_get_syms_by_level(ac01, 0, level).unwrap();
+
+    let fragment = "xkb_symbols { key <AC01> { [ q, Q ] }; };";
+
+    // Override replaces the existing levels
+    let overlaid = keymap.overlay(fragment, MergeMode::Override).unwrap();
+    assert_eq!(syms(&overlaid, 0), vec![Keysym::q]);
+    assert_eq!(syms(&overlaid, 1), vec![Keysym::Q]);
+
+    // Only the overlaid key has changed
+    let diff = keymap.diff(&overlaid);
+    assert_eq!(diff.keys.len(), 1);
+    assert_eq!(diff.keys[0].name, "AC01");
+    assert!(diff.layouts.is_empty() && diff.mods.is_empty() && diff.types.is_empty());
+
+    // Default is the same as Override
+    assert!(overlaid
+        .diff(&keymap.overlay(fragment, MergeMode::Default).unwrap())
+        .is_empty());
+
+    // Augment keeps the existing definitions
+    let augmented = keymap.overlay(fragment, MergeMode::Augment).unwrap();
+    assert!(keymap.diff(&augmented).is_empty());
+
+    // An explicit merge mode in the fragment takes precedence
+    let augmented = keymap
+        .overlay(
+            "xkb_symbols { augment key <AC01> { [ q, Q ] }; };",
+            MergeMode::Override,
+        )
+        .unwrap();
+    assert!(keymap.diff(&augmented).is_empty());
+
+    // Replace drops the previous definition of the key
+    let replaced = keymap
+        .overlay("xkb_symbols { key <AC01> { [ x ] }; };", MergeMode::Replace)
+        .unwrap();
+    assert_eq!(syms(&replaced, 0), vec![Keysym::x]);
+    assert_eq!(replaced.num_levels_for_key(ac01, 0), 1);
+
+    // Several sections may be overlaid at once
+    let overlaid = keymap
+        .overlay(
+            "xkb_keymap {
+                xkb_types { type \"CUSTOM\" { modifiers = Shift; map[Shift] = 2; }; };
+                xkb_symbols { key <AC01> { type = \"CUSTOM\", [ q, Q ] }; };
+            };",
+            MergeMode::Override,
+        )
+        .unwrap();
+    assert_eq!(syms(&overlaid, 1), vec![Keysym::Q]);
+    let diff = keymap.diff(&overlaid);
+    assert_eq!(diff.types.len(), 1);
+    assert_eq!(diff.types[0].name, "CUSTOM");
+
+    assert!(keymap
+        .overlay("xkb_symbols {", MergeMode::Override)
+        .is_err());
+}
+
+#[test]
+fn test_keymap_fingerprint() {
+    let compile = |layout| {
+        let context = test_get_context(TestContextFlags::empty()).unwrap();
+        test_compile_rules(
+            context,
+            Some("evdev"),
+            Some("pc104"),
+            Some(layout),
+            None,
+            None,
+        )
+        .unwrap()
+    };
+
+    let us = compile("us");
+    let us_ru = compile("us,ru");
+
+    // The fingerprint is computed when the keymap is created
+    assert_ne!(us.fingerprint, [0; 32]);
+    assert_eq!(us.fingerprint(), us.compute_fingerprint());
+
+    // Keymaps compiled in different contexts
+    let other_us = compile("us");
+    assert!(us == other_us);
+    assert_eq!(us.fingerprint(), other_us.fingerprint());
+
+    assert!(us != us_ru);
+    assert_ne!(us.fingerprint(), us_ru.fingerprint());
+
+    // Section names and the order of the aliases do not matter
+    let text = us.get_as_string(KeymapFormat::TextV1).unwrap();
+    let mut aliases: Vec<&str> = text
+        .lines()
+        .filter(|line| line.trim_start().starts_with("alias "))
+        .collect();
+    assert!(aliases.len() > 1);
+    let mut lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("alias "))
+        .collect();
+    aliases.reverse();
+    let keycodes_end = lines.iter().position(|line| *line == "};").unwrap();
+    lines.splice(keycodes_end..keycodes_end, aliases);
+    let text = lines
+        .join("\n")
+        .replacen("xkb_keycodes \"", "xkb_keycodes \"renamed-", 1);
+
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let reordered =
+        Keymap::new_from_string(context, &text, KeymapFormat::TextV1, CompileFlags::empty())
+            .unwrap();
+    assert_ne!(text, reordered.get_as_string(KeymapFormat::TextV1).unwrap());
+    assert!(us == reordered);
+    assert_eq!(us.fingerprint(), reordered.fingerprint());
+
+    let keymaps: std::collections::HashSet<Keymap> =
+        [us, us_ru, other_us, reordered].into_iter().collect();
+    assert_eq!(keymaps.len(), 2);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_keymap_serde() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us,ru"),
+        None,
+        Some("grp:menu_toggle"),
+    )
+    .unwrap();
+
+    let json = serde_json::to_string(&keymap).unwrap();
+    let round_trip: Keymap = serde_json::from_str(&json).unwrap();
+    assert!(round_trip == keymap);
+
+    let view = keymap.to_view();
+    let ac01 = view.keys.iter().find(|key| key.name == "AC01").unwrap();
+    assert_eq!(ac01.groups.len(), 2);
+    assert_eq!(ac01.groups[0].key_type, "ALPHABETIC");
+    assert_eq!(ac01.groups[1].levels[0].syms, vec!["Cyrillic_ef"]);
+    let lfsh = view.keys.iter().find(|key| key.name == "LFSH").unwrap();
+    assert_eq!(lfsh.modmap, vec!["Shift"]);
+    assert_eq!(
+        lfsh.groups[0].levels[0].action.as_deref(),
+        Some("SetMods(modifiers=Shift,clearLocks)")
+    );
+
+    // Edit the view and compile it again
+    let mut view = view;
+    let ac01 = view.keys.iter_mut().find(|key| key.name == "AC01").unwrap();
+    ac01.groups[0].levels[0].syms = vec!["b".into()];
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let edited = Keymap::from_view(context, &view, CompileFlags::NO_FLAGS).unwrap();
+    let ac01 = edited.key_by_name("AC01").unwrap();
+    assert_eq!(
+        edited.key_get_syms_by_level(ac01, 0, 0).unwrap(),
+        vec![xkeysym::Keysym::b]
+    );
+
+    // Views go through the same validation as the compiler
+    let lfsh = view.keys.iter_mut().find(|key| key.name == "LFSH").unwrap();
+    lfsh.explicit_actions = true;
+    lfsh.groups[0].levels[0].action = Some("SetMods(modifiers=Shift".into());
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    assert!(Keymap::from_view(context, &view, CompileFlags::NO_FLAGS).is_err());
+
+    // Unquoted fields cannot inject other statements
+    use crate::error::keymap::KeymapCompileError;
+
+    let view = keymap.to_view();
+    let injected = " }; include \"evdev\" xkb_symbols { ";
+    let from_view = |view: &KeymapView| {
+        let context = test_get_context(TestContextFlags::empty()).unwrap();
+        Keymap::from_view(context, view, CompileFlags::NO_FLAGS)
+    };
+    let is_invalid =
+        |view: &KeymapView| matches!(from_view(view), Err(KeymapCompileError::InvalidView(_)));
+    assert!(from_view(&view).is_ok());
+
+    let mut malicious = view.clone();
+    malicious.keys[0].name = format!("AE01>{}<AE02", injected);
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    let ac01 = malicious
+        .keys
+        .iter_mut()
+        .find(|key| key.name == "AC01")
+        .unwrap();
+    ac01.groups[0].levels[0].syms = vec![format!("a ]{}", injected)];
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    let lfsh = malicious
+        .keys
+        .iter_mut()
+        .find(|key| key.name == "LFSH")
+        .unwrap();
+    lfsh.explicit_actions = true;
+    lfsh.groups[0].levels[0].action = Some(format!("NoAction(){}", injected));
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.keys[0].modmap = vec![format!("Shift{}", injected)];
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.mods[0].name = format!("Shift;{}", injected);
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.aliases[0].real = format!("AE01>;{}<AE02", injected);
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.interprets[0].match_op = format!("Exactly(all) {{}};{}", injected);
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.interprets[0].virtual_modifier = Some(format!("NumLock;{}", injected));
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    let led = malicious
+        .leds
+        .iter_mut()
+        .find(|led| !led.which_mods.is_empty())
+        .unwrap();
+    led.which_mods = vec![format!("locked;{}", injected)];
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.leds[0].controls = vec![format!("SlowKeys;{}", injected)];
+    assert!(is_invalid(&malicious));
+
+    // Nor can quoted ones, since strings have no escapes
+    let mut malicious = view.clone();
+    malicious.group_names[0] = "x\"; modifier_map Mod3 { <AC01> }; //".into();
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.group_names[0] = "a\\b".into();
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.types[0].name = format!("ONE_LEVEL\";{}", injected);
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    let _type = malicious
+        .types
+        .iter_mut()
+        .find(|_type| !_type.level_names.is_empty())
+        .unwrap();
+    *_type.level_names.values_mut().next().unwrap() = "Base\n".into();
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    malicious.leds[0].name = Some(format!("Caps Lock\";{}", injected));
+    assert!(is_invalid(&malicious));
+
+    let mut malicious = view.clone();
+    let ac01 = malicious
+        .keys
+        .iter_mut()
+        .find(|key| key.name == "AC01")
+        .unwrap();
+    ac01.groups[0].explicit_type = true;
+    ac01.groups[0].key_type = format!("ALPHABETIC\"];{}", injected);
+    assert!(is_invalid(&malicious));
+
+    let rmlvo = RuleNames {
+        rules: Some("evdev".into()),
+        model: Some("pc104".into()),
+        layout: Some("us,ru".into()),
+        variant: None,
+        options: Some("grp:menu_toggle".into()),
+    };
+    let json = serde_json::to_string(&rmlvo).unwrap();
+    let round_trip: RuleNames = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_trip.layout.as_deref(), Some("us,ru"));
+    assert_eq!(round_trip.options, rmlvo.options);
+}
+
+#[test]
+fn test_keymap_bytes() {
+    use crate::error::keymap::KeymapLoadError;
+
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us,ru"),
+        None,
+        Some("grp:menu_toggle"),
+    )
+    .unwrap();
+
+    let bytes = keymap.to_bytes();
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let loaded = Keymap::from_bytes(context, &bytes).unwrap();
+    assert!(loaded == keymap);
+    assert_eq!(
+        loaded.get_as_string(KeymapFormat::TextV1).unwrap(),
+        keymap.get_as_string(KeymapFormat::TextV1).unwrap()
+    );
+    assert_eq!(loaded.to_bytes(), bytes);
+
+    let load = |bytes: &[u8]| {
+        let context = test_get_context(TestContextFlags::empty()).unwrap();
+        Keymap::from_bytes(context, bytes)
+    };
+
+    assert!(matches!(
+        load(&bytes[..bytes.len() - 1]),
+        Err(KeymapLoadError::UnexpectedEnd)
+    ));
+    assert!(matches!(
+        load(b"xkb_keymap {"),
+        Err(KeymapLoadError::BadMagic)
+    ));
+
+    let mut other_version = bytes.clone();
+    other_version[4] ^= 0xff;
+    assert!(matches!(
+        load(&other_version),
+        Err(KeymapLoadError::UnsupportedVersion(_))
+    ));
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(matches!(load(&trailing), Err(KeymapLoadError::Invalid(_))));
+}
+
+#[test]
+fn test_keymap_cache() {
+    let dir = std::env::temp_dir().join(format!("xkbcommon-rs-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let cache = KeymapCache::new(&dir);
+
+    let rmlvo = RuleNames {
+        rules: Some("evdev".into()),
+        model: Some("pc104".into()),
+        layout: Some("us,ru".into()),
+        variant: None,
+        options: Some("grp:menu_toggle".into()),
+    };
+    let compile = || {
+        let context = test_get_context(TestContextFlags::empty()).unwrap();
+        cache
+            .new_from_names(context, Some(rmlvo.clone()), CompileFlags::NO_FLAGS)
+            .unwrap()
+    };
+
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let expected =
+        Keymap::new_from_names(context, Some(rmlvo.clone()), CompileFlags::NO_FLAGS).unwrap();
+
+    // The first call compiles the keymap and adds it to the cache
+    assert!(compile() == expected);
+    let entries: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(entries.len(), 1);
+
+    // The entry records the files the keymap was compiled from
+    let entry = std::fs::read(&entries[0]).unwrap();
+    let contains = |needle: &str| {
+        entry
+            .windows(needle.len())
+            .any(|window| window == needle.as_bytes())
+    };
+    assert!(contains("rules/evdev"));
+    assert!(contains("symbols/ru"));
+
+    // The second call loads it from the cache
+    assert!(compile() == expected);
+
+    // A corrupted entry is replaced
+    std::fs::write(&entries[0], &entry[..entry.len() / 2]).unwrap();
+    assert!(compile() == expected);
+    assert_eq!(std::fs::read(&entries[0]).unwrap(), entry);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_keymap_cache_rules_include() {
+    let dir =
+        std::env::temp_dir().join(format!("xkbcommon-rs-cache-include-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let cache = KeymapCache::new(dir.join("cache"));
+
+    // The rules include another file, which maps the layout
+    let rules_dir = dir.join("rules");
+    std::fs::create_dir_all(&rules_dir).unwrap();
+    let included = rules_dir.join("inc");
+    std::fs::write(
+        rules_dir.join("main"),
+        format!("! include {}\n", included.display()),
+    )
+    .unwrap();
+    let write_included = |symbols: &str| {
+        let rules = format!(
+            "! model = keycodes\n  * = evdev\n\
+             ! model = types\n  * = complete\n\
+             ! model = compat\n  * = complete\n\
+             ! layout = symbols\n  * = {}\n",
+            symbols
+        );
+        std::fs::write(&included, rules).unwrap();
+    };
+
+    let rmlvo = RuleNames {
+        rules: Some("main".into()),
+        model: Some("pc104".into()),
+        layout: Some("us".into()),
+        variant: None,
+        options: None,
+    };
+    let compile = || {
+        let mut context = test_get_context(TestContextFlags::empty()).unwrap();
+        context.include_path_append(dir.to_str().unwrap()).unwrap();
+        cache
+            .new_from_names(context, Some(rmlvo.clone()), CompileFlags::NO_FLAGS)
+            .unwrap()
+    };
+
+    write_included("pc+us");
+    assert_eq!(compile().num_layouts(), 1);
+    assert_eq!(compile().num_layouts(), 1);
+
+    // Editing the included file invalidates the entry
+    write_included("pc+us+ru:2");
+    assert_eq!(compile().num_layouts(), 2);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_keymap_cache_shadowed() {
+    let dir = std::env::temp_dir().join(format!(
+        "xkbcommon-rs-cache-shadowed-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    let cache = KeymapCache::new(dir.join("cache"));
+
+    // A user include path, searched before the system one
+    let user_dir = dir.join("user");
+    std::fs::create_dir_all(user_dir.join("symbols")).unwrap();
+
+    let rmlvo = RuleNames {
+        rules: Some("evdev".into()),
+        model: Some("pc104".into()),
+        layout: Some("us".into()),
+        variant: None,
+        options: None,
+    };
+    let compile = || {
+        let mut context = test_get_context(TestContextFlags::empty()).unwrap();
+        let data_dir = context.include_path_get(0).unwrap().clone();
+        context.include_path_clear();
+        context
+            .include_path_append(user_dir.to_str().unwrap())
+            .unwrap();
+        context.include_path_append(&data_dir).unwrap();
+        cache
+            .new_from_names(context, Some(rmlvo.clone()), CompileFlags::NO_FLAGS)
+            .unwrap()
+    };
+    let ac01 = |keymap: &Keymap| keymap.key_get_syms_by_level(38u32, 0, 0).unwrap();
+
+    assert_eq!(ac01(&compile()), vec![xkeysym::Keysym::a]);
+    assert_eq!(ac01(&compile()), vec![xkeysym::Keysym::a]);
+
+    // A new file in the user include path invalidates the entry
+    std::fs::write(
+        user_dir.join("symbols").join("us"),
+        "default xkb_symbols \"basic\" { key <AC01> { [ b, B ] }; };",
+    )
+    .unwrap();
+    assert_eq!(ac01(&compile()), vec![xkeysym::Keysym::b]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_keymap_lint() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let keymap = test_compile_file(context, "keymaps/unbound-vmod.xkb").unwrap();
+    let findings = keymap.lint();
+
+    let find = |code: LintCode, name: &str| {
+        findings
+            .iter()
+            .filter(|finding| finding.code == code && finding.name == name)
+            .collect::<Vec<_>>()
+    };
+
+    // LevelThree is not bound, so the third level of the keys cannot be reached
+    assert_eq!(
+        find(LintCode::UnboundVirtualModifier, "LevelThree").len(),
+        1
+    );
+    let ae01 = find(LintCode::UnreachableLevel, "AE01");
+    assert_eq!(ae01.len(), 1);
+    assert_eq!(ae01[0].keycode, keymap.key_by_name("AE01"));
+    assert_eq!((ae01[0].layout, ae01[0].level), (Some(0), Some(2)));
+    assert_eq!(
+        ae01[0].to_string(),
+        "[XKB-L001] key <AE01>: level 3 of group 1 is not selected by any modifiers of type \"FOUR_LEVEL\""
+    );
+
+    assert_eq!(find(LintCode::ConflictingModmap, "RALT").len(), 1);
+    assert_eq!(find(LintCode::MissingRepeat, "LVL3").len(), 1);
+    assert_eq!(find(LintCode::UnusedKeyType, "ALPHABETIC").len(), 1);
+    assert_eq!(find(LintCode::UndrivenLed, "Compose").len(), 1);
+    assert!(find(LintCode::UndrivenLed, "Caps Lock").is_empty());
+    assert!(findings
+        .iter()
+        .all(|finding| finding.code != LintCode::UnboundVirtualModifier || finding.name != "Alt"));
+
+    let keymap = test_compile_string(
+        test_get_context(TestContextFlags::empty()).unwrap(),
+        r#"xkb_keymap {
+            xkb_keycodes { <AE01> = 10; <AE02> = 11; };
+            xkb_types { include "basic" };
+            xkb_compat { include "basic" };
+            xkb_symbols {
+                key <AE01> { [ 1, exclam ] };
+                key <AE02> { [ 2, exclam ] };
+            };
+        };"#
+        .into(),
+    )
+    .unwrap();
+    let findings = keymap.lint();
+    let duplicates: Vec<_> = findings
+        .iter()
+        .filter(|finding| finding.code == LintCode::DuplicateKeysym)
+        .collect();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].name, "AE02");
+    assert_eq!(duplicates[0].level, Some(1));
+}
+
+#[test]
+fn test_keymap_level_names() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us,ru"),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let ae01 = keymap.key_by_name("AE01").unwrap();
+    let ac01 = keymap.key_by_name("AC01").unwrap();
+
+    assert_eq!(keymap.key_get_type_name(ae01, 0), Some("TWO_LEVEL"));
+    assert_eq!(keymap.key_get_level_name(ae01, 0, 0), Some("Base"));
+    assert_eq!(keymap.key_get_level_name(ae01, 0, 1), Some("Shift"));
+    assert_eq!(keymap.key_get_level_name(ae01, 0, 2), None);
+    assert_eq!(keymap.key_get_type_name(ac01, 1), Some("ALPHABETIC"));
+    assert_eq!(keymap.key_get_level_name(ac01, 1, 1), Some("Caps"));
+    // Layouts are wrapped as in State
+    assert_eq!(keymap.key_get_level_name(ac01, 2, 1), Some("Caps"));
+    assert_eq!(keymap.key_get_level_name(0u32, 0, 0), None);
+
+    let names: Vec<_> = keymap.key_type_names().collect();
+    assert!(names.contains(&"ONE_LEVEL"));
+    assert!(names.contains(&"FOUR_LEVEL"));
+    assert_eq!(names.len(), keymap.types.len());
+
+    let shift = 1 << keymap.mod_get_index(ModName::SHIFT).unwrap();
+    let lock = 1 << keymap.mod_get_index(ModName::CAPS).unwrap();
+
+    assert_eq!(keymap.key_type_num_levels("ONE_LEVEL"), Some(1));
+    assert_eq!(keymap.key_type_num_levels("FOUR_LEVEL"), Some(4));
+    assert_eq!(keymap.key_type_get_mods("ALPHABETIC"), Some(shift | lock));
+    assert_eq!(
+        keymap.key_type_get_level_name("ALPHABETIC", 0),
+        Some("Base")
+    );
+    assert_eq!(
+        keymap.key_type_get_entries("ALPHABETIC").unwrap(),
+        vec![
+            KeyTypeMapEntry {
+                mods: shift,
+                level: 1,
+                preserve: 0
+            },
+            KeyTypeMapEntry {
+                mods: lock,
+                level: 1,
+                preserve: 0
+            },
+        ]
+    );
+    assert_eq!(keymap.key_type_num_levels("NO_SUCH_TYPE"), None);
+    assert_eq!(keymap.key_type_get_entries("NO_SUCH_TYPE"), None);
+}
+
+#[test]
+fn test_keymap_aliases() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us"),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let ad01 = keymap.key_by_name("AD01").unwrap();
+    assert_eq!(keymap.key_by_name("LatQ"), Some(ad01));
+    assert_eq!(keymap.key_by_name_opts("LatQ", true), Some(ad01));
+    assert_eq!(keymap.key_by_name_opts("LatQ", false), None);
+    assert_eq!(keymap.key_by_name_opts("AD01", false), Some(ad01));
+    assert_eq!(keymap.key_by_name_opts("NOPE", true), None);
+
+    assert_eq!(keymap.key_get_aliases(ad01), vec!["LatQ"]);
+    let comp = keymap.key_by_name("COMP").unwrap();
+    assert!(keymap.key_get_aliases(comp).contains(&"MENU"));
+    assert!(keymap
+        .key_get_aliases(keymap.key_by_name("AE01").unwrap())
+        .is_empty());
+
+    let aliases: Vec<_> = keymap.iter_aliases().collect();
+    assert!(aliases.contains(&("LatQ", "AD01")));
+    assert!(aliases.contains(&("MENU", "COMP")));
+    for (alias, real) in aliases {
+        assert_eq!(
+            keymap.key_by_name(alias),
+            keymap.key_by_name_opts(real, false)
+        );
+    }
+}
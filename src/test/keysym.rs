@@ -30,6 +30,7 @@ use xkeysym::{Keysym, RawKeysym};
 use crate::keysym::KeysymFlags;
 use crate::keysym::XKB_KEYSYM_MAX;
 use crate::keysyms::*;
+use crate::keysyms_case::*;
 use crate::keysyms_utf::*;
 use icu_casemap::CaseMapper;
 
@@ -268,11 +269,7 @@ fn get_keysym_name(ks: Keysym) -> String {
 }
 
 fn test_utf32_to_keysym(ucs: u32, expected: Keysym) -> bool {
-    // TODO: if any, more allowed utf32s than chars
-    let actual = match char::try_from(ucs) {
-        Ok(c) => Keysym::from_char(c),
-        _ => xkeysym::NO_SYMBOL,
-    };
+    let actual = utf32_to_keysym(ucs).unwrap_or(xkeysym::NO_SYMBOL);
     let expected_name = get_keysym_name(expected);
     let actual_name = get_keysym_name(actual);
 
@@ -1704,13 +1701,8 @@ fn test_keysyms() {
     let mut count = 0;
     let mut count_non_unicode = 0;
 
-    let mut iter = KeysymIterator::new(false);
-
-    while let Some(ks) = iter.next() {
-        // May have repeat syms
-        if ks_prev == ks {
-            continue;
-        }
+    for ks in KeysymIterator::new(false) {
+        let ks = ks.raw();
 
         count += 1;
         if ks < XKB_KEYSYM_UNICODE_MIN || ks > XKB_KEYSYM_UNICODE_MAX {
@@ -1728,7 +1720,7 @@ fn test_keysyms() {
         assert!(needed <= 5);
 
         // Check maximum name length
-        let name = iter.get_name().unwrap();
+        let name = keysym_get_name(&Keysym::from(ks)).unwrap();
         let needed = name.len();
         assert!(0 < needed && needed <= XKB_KEYSYM_NAME_MAX_SIZE);
 
@@ -2016,6 +2008,31 @@ fn test_keysyms() {
     assert!(test_utf32_to_keysym(0x110000, Keysym::NoSymbol));
     assert!(test_utf32_to_keysym(0xdeadbeef, Keysym::NoSymbol));
 
+    assert_eq!(utf8_to_keysym(""), vec![]);
+    assert_eq!(
+        utf8_to_keysym("aZ 9\tαЖ€😉\u{fdd0}"),
+        vec![
+            Keysym::a,
+            Keysym::Z,
+            Keysym::space,
+            Keysym::_9,
+            Keysym::Tab,
+            Keysym::Greek_alpha,
+            Keysym::Cyrillic_ZHE,
+            Keysym::EuroSign,
+            Keysym::from(0x0101F609),
+        ]
+    );
+    // Round trip
+    for s in ["hello", "ÀÉÎõü", "Ελληνικά", "кириллица", "עברית"] {
+        let text: String = utf8_to_keysym(s)
+            .iter()
+            .filter_map(keysym_to_utf32)
+            .filter_map(char::from_u32)
+            .collect();
+        assert_eq!(text, s);
+    }
+
     assert!(keysym_is_lower(&Keysym::a));
     assert!(keysym_is_lower(&Keysym::Greek_lambda));
     assert!(keysym_is_lower(&keysym_from_name("U03b1", 0).unwrap())); /* GREEK SMALL LETTER ALPHA */
@@ -2059,3 +2076,360 @@ fn test_keysyms() {
 
     test_github_issue_42();
 }
+
+#[test]
+fn keysym_metadata() {
+    assert_eq!(keysym_get_names(&Keysym::a), vec!["a"]);
+    assert_eq!(
+        keysym_get_names(&Keysym::Prior),
+        vec!["Prior", "Page_Up", "SunPageUp"]
+    );
+    assert_eq!(
+        keysym_get_names(&Keysym::from(0xab)),
+        vec!["guillemotleft", "guillemetleft"]
+    );
+    assert!(keysym_get_names(&Keysym::from(XKB_KEYSYM_UNICODE_MIN)).is_empty());
+    assert!(keysym_get_names(&Keysym::from(XKB_KEYSYM_MAX)).is_empty());
+
+    // Deprecated names
+    assert!(keysym_name_is_deprecated("Page_Up"));
+    assert!(keysym_name_is_deprecated("guillemotleft"));
+    assert!(keysym_name_is_deprecated("quoteright"));
+    assert!(keysym_name_is_deprecated("dead_small_schwa"));
+    assert!(keysym_name_is_deprecated("L1"));
+    assert!(!keysym_name_is_deprecated("Prior"));
+    assert!(!keysym_name_is_deprecated("apostrophe"));
+    assert!(!keysym_name_is_deprecated("dead_schwa"));
+    assert!(!keysym_name_is_deprecated("XF86Next_VMode"));
+    // Non-deprecated aliases
+    assert!(!keysym_name_is_deprecated("script_switch"));
+    assert!(!keysym_name_is_deprecated("dead_perispomeni"));
+    // Case-sensitive
+    assert!(!keysym_name_is_deprecated("page_up"));
+
+    // Deprecated keysyms
+    assert!(keysym_is_deprecated(&Keysym::from(0x1000ff76))); // Ext16bit_L
+    assert!(!keysym_is_deprecated(&Keysym::from(0x7a5))); // Greek_IOTAdieresis
+    assert!(!keysym_is_deprecated(&Keysym::Prior));
+    assert!(!keysym_is_deprecated(&Keysym::a));
+    assert!(!keysym_is_deprecated(&Keysym::from(XKB_KEYSYM_UNICODE_MIN)));
+
+    let info = keysym_get_info(&Keysym::from(0x27)).unwrap();
+    assert_eq!(info.name, "apostrophe");
+    assert_eq!(info.preferred_name, "apostrophe");
+    assert_eq!(info.aliases, vec!["quoteright"]);
+    assert_eq!(info.utf32, Some(0x27));
+    assert!(!info.deprecated);
+
+    let info = keysym_get_info(&Keysym::from(0x0101f609)).unwrap();
+    assert_eq!(info.name, "U0001F609");
+    assert_eq!(info.preferred_name, "U0001F609");
+    assert!(info.aliases.is_empty());
+    assert_eq!(info.utf32, Some(0x1f609));
+
+    assert_eq!(keysym_get_info(&Keysym::from(XKB_KEYSYM_MAX)), None);
+
+    // The first name may be deprecated, while the keysym is not
+    for (ks, name, preferred_name) in [
+        (0xab, "guillemotleft", "guillemetleft"),
+        (0xba, "masculine", "ordmasculine"),
+        (0xbb, "guillemotright", "guillemetright"),
+        (0xfe8a, "dead_small_schwa", "dead_schwa"),
+        (0xfe8b, "dead_capital_schwa", "dead_SCHWA"),
+    ] {
+        let info = keysym_get_info(&Keysym::from(ks)).unwrap();
+        assert_eq!(info.name, name);
+        assert!(keysym_name_is_deprecated(&info.name));
+        assert_eq!(info.preferred_name, preferred_name);
+        assert!(!info.deprecated);
+    }
+
+    // Explicitly named keysyms, each returned once
+    let mut count = 0;
+    let mut names = 0;
+    let mut prev = None;
+    for ks in KeysymIterator::new(true) {
+        assert!(prev < Some(ks));
+        prev = Some(ks);
+
+        let info = keysym_get_info(&ks).unwrap();
+        assert_eq!(info.name, keysym_get_names(&ks)[0]);
+        assert_eq!(
+            keysym_name_is_deprecated(&info.preferred_name),
+            info.deprecated
+        );
+        assert!(keysym_is_assigned(&ks));
+
+        count += 1;
+        names += 1 + info.aliases.len();
+    }
+    assert_eq!(prev, Some(Keysym::from(XKB_KEYSYM_MAX_ASSIGNED)));
+    assert_eq!(count, XKB_KEYSYM_COUNT_EXPLICIT);
+    assert_eq!(names, crate::keysyms_generated_phf::KEYSYM_TO_NAME.len());
+}
+
+#[test]
+fn keysym_categories() {
+    use KeysymCategory::*;
+
+    let cases = [
+        (Keysym::NoSymbol, Other),
+        (Keysym::ISO_Level3_Latch, IsoLockLatch),
+        (Keysym::ISO_Next_Group_Lock, IsoLockLatch),
+        (Keysym::ISO_Lock, IsoLockLatch),
+        (Keysym::ISO_Level3_Shift, Modifier),
+        (Keysym::Shift_L, Modifier),
+        (Keysym::Caps_Lock, Modifier),
+        (Keysym::Num_Lock, Modifier),
+        (Keysym::Mode_switch, Modifier),
+        (Keysym::KP_F1, Pf),
+        (Keysym::KP_F4, Pf),
+        (Keysym::KP_1, Keypad),
+        (Keysym::KP_Enter, Keypad),
+        (Keysym::F1, Function),
+        (Keysym::F35, Function),
+        (Keysym::Home, Cursor),
+        (Keysym::Left, Cursor),
+        (Keysym::Prior, Cursor),
+        (Keysym::Select, MiscFunction),
+        (Keysym::Print, MiscFunction),
+        (Keysym::Menu, MiscFunction),
+        (Keysym::BackSpace, Tty),
+        (Keysym::Return, Tty),
+        (Keysym::Escape, Tty),
+        (Keysym::Delete, Tty),
+        (Keysym::dead_acute, Dead),
+        (Keysym::dead_greek, Dead),
+        (Keysym::XF86_AudioPlay, XF86),
+        (Keysym::XF86_Switch_VT_1, XF86),
+        (Keysym::a, Letter),
+        (Keysym::Z, Letter),
+        (Keysym::eacute, Letter),
+        (Keysym::Greek_alpha, Letter),
+        (Keysym::Cyrillic_ZHE, Letter),
+        (Keysym::from(0x01000101), Letter),
+        (Keysym::_1, Digit),
+        (Keysym::from(0x01000661), Digit), // ARABIC-INDIC DIGIT ONE
+        (Keysym::space, Symbol),
+        (Keysym::comma, Symbol),
+        (Keysym::EuroSign, Symbol),
+        (Keysym::from(0x0101f609), Symbol),
+        (Keysym::SUN_Copy, Other),
+        (Keysym::VoidSymbol, Other),
+    ];
+    for (ks, expected) in cases {
+        assert_eq!(keysym_category(&ks), expected, "{:?}", ks);
+    }
+}
+
+#[test]
+fn keysym_labels() {
+    use crate::keysym::{keysym_get_label, LabelStyle};
+
+    let cases = [
+        (Keysym::NoSymbol, None, None),
+        // Printable keysyms
+        (Keysym::a, Some("a"), Some("a")),
+        (Keysym::A, Some("A"), Some("A")),
+        (Keysym::EuroSign, Some("€"), Some("€")),
+        (Keysym::Cyrillic_zhe, Some("ж"), Some("ж")),
+        (Keysym::from(0x0101f609), Some("😉"), Some("😉")),
+        (Keysym::space, Some("␣"), Some("Space")),
+        // Keypad
+        (Keysym::KP_1, Some("1"), Some("Keypad 1")),
+        (Keysym::KP_Add, Some("+"), Some("Keypad +")),
+        (Keysym::KP_Enter, Some("⌤"), Some("Keypad Enter")),
+        // Dead keys
+        (Keysym::dead_acute, Some("◌\u{301}"), Some("Dead Acute")),
+        (
+            Keysym::dead_belowdot,
+            Some("◌\u{323}"),
+            Some("Dead Belowdot"),
+        ),
+        (Keysym::dead_greek, Some("Greek"), Some("Dead Greek")),
+        // Function keys
+        (Keysym::Return, Some("⏎"), Some("Enter")),
+        (Keysym::Shift_L, Some("⇧"), Some("Shift")),
+        (Keysym::BackSpace, Some("⌫"), Some("Backspace")),
+        (Keysym::Prior, Some("⇞"), Some("Page Up")),
+        (Keysym::F12, Some("F12"), Some("F12")),
+        (Keysym::XF86_AudioRaiseVolume, Some("🔊"), Some("Volume Up")),
+        // Fallbacks
+        (Keysym::Sys_Req, Some("SysRq"), Some("System Request")),
+        (Keysym::Scroll_Lock, Some("⇳"), Some("Scroll Lock")),
+        (
+            Keysym::XF86_KbdBrightnessUp,
+            Some("KbdBrightnessUp"),
+            Some("Kbd Brightness Up"),
+        ),
+        (
+            Keysym::ISO_Next_Group,
+            Some("ISO Next Group"),
+            Some("ISO Next Group"),
+        ),
+        (Keysym::Codeinput, Some("Codeinput"), Some("Codeinput")),
+    ];
+    for (ks, compact, descriptive) in cases {
+        assert_eq!(
+            keysym_get_label(&ks, LabelStyle::Compact).as_deref(),
+            compact,
+            "{:?}",
+            ks
+        );
+        assert_eq!(
+            keysym_get_label(&ks, LabelStyle::Descriptive).as_deref(),
+            descriptive,
+            "{:?}",
+            ks
+        );
+    }
+
+    // Every named keysym has a label
+    for ks in KeysymIterator::new(true).skip(1) {
+        assert!(!keysym_get_label(&ks, LabelStyle::Compact)
+            .unwrap()
+            .is_empty());
+        assert!(!keysym_get_label(&ks, LabelStyle::Descriptive)
+            .unwrap()
+            .is_empty());
+    }
+}
+
+#[test]
+fn keysym_unicode_table() {
+    use crate::keysyms_generated_phf::KEYSYM_TO_UNICODE;
+
+    assert!(KEYSYM_TO_UNICODE.windows(2).all(|w| w[0].0 < w[1].0));
+    for (ks, ucs) in KEYSYM_TO_UNICODE {
+        assert_eq!(keysym_to_utf32(&ks), Some(ucs), "{:?}", ks);
+        // the headers agree with the mappings of xkeysym
+        assert_eq!(ks.key_char().map(|c| c as u32), Some(ucs), "{:?}", ks);
+    }
+}
+
+#[test]
+fn keysym_name_search() {
+    use crate::keysym::{keysym_search_prefix, keysym_suggest};
+
+    assert_eq!(keysym_search_prefix("aacu", 10), vec!["Aacute", "aacute"]);
+    assert_eq!(
+        keysym_search_prefix("XF86AudioRaise", 10),
+        vec!["XF86AudioRaiseVolume"]
+    );
+    assert_eq!(keysym_search_prefix("F1", 3), vec!["F1", "F10", "F11"]);
+    assert_eq!(keysym_search_prefix("nosym", 10), vec!["NoSymbol"]);
+    assert!(keysym_search_prefix("ThisKeyShouldNotExist", 10).is_empty());
+    assert!(keysym_search_prefix("a", 0).is_empty());
+    assert_eq!(keysym_search_prefix("", 5).len(), 5);
+    // Deprecated names are skipped
+    assert!(keysym_search_prefix("Page_", 10).is_empty());
+    assert_eq!(
+        keysym_search_prefix("guillem", 10),
+        vec!["guillemetleft", "guillemetright"]
+    );
+    // Same as searching all the names
+    for prefix in [
+        "",
+        "a",
+        "A",
+        "kp_",
+        "n",
+        "No",
+        "nosymbol",
+        "nosymbols",
+        "XF86Audio",
+        "z",
+    ] {
+        let mut expected: Vec<&str> = crate::keysyms_generated_phf::NAME_TO_KEYSYM
+            .keys()
+            .filter(|name| name.to_lowercase().starts_with(&prefix.to_lowercase()))
+            .filter(|name| !keysym_name_is_deprecated(name))
+            .copied()
+            .collect();
+        expected.sort_by_key(|name| (name.to_lowercase(), *name));
+        expected.truncate(20);
+        assert_eq!(keysym_search_prefix(prefix, 20), expected, "{}", prefix);
+    }
+
+    assert_eq!(keysym_suggest("Aacut", 2), vec!["Aacute", "aacute"]);
+    assert_eq!(keysym_suggest("aacut", 1), vec!["aacute"]);
+    assert_eq!(
+        keysym_suggest("XF86AudioRasieVolume", 1),
+        vec!["XF86AudioRaiseVolume"]
+    );
+    assert_eq!(keysym_suggest("retrun", 1), vec!["Return"]);
+    assert_eq!(keysym_suggest("Return", 1), vec!["Return"]);
+    assert_eq!(keysym_suggest("shift_l", 1), vec!["Shift_L"]);
+    assert!(keysym_suggest("ThisKeyShouldNotExist", 5).is_empty());
+    assert!(keysym_suggest("Aacut", 0).is_empty());
+}
+
+#[test]
+fn keysym_titlecase() {
+    // Digraphs have a distinct titlecase
+    assert_eq!(
+        keysym_to_title(&Keysym::new(0x10001c6)),
+        Keysym::new(0x10001c5)
+    );
+    assert_eq!(
+        keysym_to_title(&Keysym::new(0x10001c4)),
+        Keysym::new(0x10001c5)
+    );
+    assert_eq!(
+        keysym_to_title(&Keysym::new(0x10001c5)),
+        Keysym::new(0x10001c5)
+    );
+    assert_eq!(
+        keysym_to_title(&Keysym::new(0x10001f3)),
+        Keysym::new(0x10001f2)
+    );
+    assert_eq!(
+        keysym_to_upper(&Keysym::new(0x10001c6)),
+        Keysym::new(0x10001c4)
+    );
+    // Otherwise the same as the uppercase
+    assert_eq!(keysym_to_title(&Keysym::a), Keysym::A);
+    assert_eq!(keysym_to_title(&Keysym::A), Keysym::A);
+    assert_eq!(keysym_to_title(&Keysym::eacute), Keysym::Eacute);
+    assert_eq!(keysym_to_title(&Keysym::Greek_alpha), Keysym::Greek_ALPHA);
+    assert_eq!(keysym_to_title(&Keysym::_1), Keysym::_1);
+    assert_eq!(keysym_to_title(&Keysym::Return), Keysym::Return);
+    // Georgian letters have no titlecase
+    assert_eq!(keysym_to_title(&Keysym::Georgian_an), Keysym::Georgian_an);
+}
+
+#[cfg(feature = "unicode-case")]
+#[test]
+fn keysym_case_mapper() {
+    assert!(KeysymCaseMapper::new("not a language tag").is_none());
+
+    let root = KeysymCaseMapper::new("").unwrap();
+    assert_eq!(root.to_upper(&Keysym::i), Keysym::I);
+    assert_eq!(root.to_lower(&Keysym::I), Keysym::i);
+    assert_eq!(
+        root.to_upper(&Keysym::Greek_finalsmallsigma),
+        Keysym::Greek_SIGMA
+    );
+    assert_eq!(
+        root.to_title(&Keysym::new(0x10001c6)),
+        Keysym::new(0x10001c5)
+    );
+    assert_eq!(
+        root.to_upper(&Keysym::new(0x10001c6)),
+        Keysym::new(0x10001c4)
+    );
+    // ß uppercases to "SS": keep the simple mapping
+    assert_eq!(root.to_upper(&Keysym::ssharp), Keysym::ssharp);
+    assert_eq!(root.to_upper(&Keysym::Return), Keysym::Return);
+
+    // Turkish and Azeri dotted and dotless i
+    let tr = KeysymCaseMapper::new("tr").unwrap();
+    assert_eq!(tr.to_upper(&Keysym::i), Keysym::Iabovedot);
+    assert_eq!(tr.to_lower(&Keysym::I), Keysym::idotless);
+    assert_eq!(tr.to_upper(&Keysym::idotless), Keysym::I);
+    assert_eq!(tr.to_lower(&Keysym::Iabovedot), Keysym::i);
+    assert_eq!(tr.to_title(&Keysym::i), Keysym::Iabovedot);
+    assert_eq!(tr.to_upper(&Keysym::a), Keysym::A);
+    let az = KeysymCaseMapper::new("az-AZ").unwrap();
+    assert_eq!(az.to_upper(&Keysym::i), Keysym::Iabovedot);
+}
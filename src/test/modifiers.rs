@@ -119,3 +119,51 @@ fn test_modmap_none() {
     let key = keymap.xkb_key(keycode.raw()).unwrap();
     assert!(key.modmap == MOD_3_MASK);
 }
+
+#[test]
+fn test_mod_mapping() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us"),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let index = |name: &str| keymap.mod_get_index(name).unwrap();
+    let mask = |name: &str| 1 << index(name);
+
+    // Real modifiers are bound to themselves
+    assert!(!keymap.mod_is_virtual(index("Shift")));
+    assert_eq!(keymap.mod_get_mapping(index("Shift")), mask("Shift"));
+    assert_eq!(keymap.mod_get_mapping(index("Mod2")), mask("Mod2"));
+
+    assert!(keymap.mod_is_virtual(index("NumLock")));
+    assert_eq!(keymap.mod_get_mapping(index("NumLock")), mask("Mod2"));
+    assert_eq!(keymap.mod_get_mapping(index("LevelThree")), mask("Mod5"));
+    assert_eq!(keymap.mod_get_mapping(index("Alt")), mask("Mod1"));
+
+    assert!(!keymap.mod_is_virtual(keymap.num_mods()));
+    assert_eq!(keymap.mod_get_mapping(keymap.num_mods()), 0);
+
+    assert_eq!(
+        keymap.mod_mask_to_real(mask("Shift") | mask("NumLock") | mask("LevelThree")),
+        mask("Shift") | mask("Mod2") | mask("Mod5")
+    );
+    assert_eq!(keymap.mod_mask_to_real(0), 0);
+
+    let nmlk = keymap.key_by_name("NMLK").unwrap();
+    assert_eq!(keymap.key_get_modmap(nmlk), mask("Mod2"));
+    assert_eq!(keymap.key_get_vmodmap(nmlk), mask("NumLock"));
+
+    let lfsh = keymap.key_by_name("LFSH").unwrap();
+    assert_eq!(keymap.key_get_modmap(lfsh), mask("Shift"));
+    assert_eq!(keymap.key_get_vmodmap(lfsh), 0);
+
+    let ac01 = keymap.key_by_name("AC01").unwrap();
+    assert_eq!(keymap.key_get_modmap(ac01), 0);
+    assert_eq!(keymap.key_get_vmodmap(ac01), 0);
+}
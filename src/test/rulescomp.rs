@@ -335,3 +335,30 @@ fn rmlvo() {
     let rmlvo = None;
     assert!(Keymap::new_from_names(context, rmlvo, 5453).is_err());
 }
+
+#[test]
+fn component_names_from_names() {
+    use crate::xkb_keymap::ComponentNames;
+
+    let mut ctx = test_get_context(TestContextFlags::empty()).unwrap();
+
+    let kccgst = ComponentNames::from_names(
+        &mut ctx,
+        Some(RuleNames::new("evdev", "pc105", "us,de", "", "")),
+    )
+    .unwrap();
+    assert_eq!(kccgst.keycodes, "evdev+aliases(qwerty)");
+    assert_eq!(kccgst.types, "complete");
+    assert_eq!(kccgst.compat, "complete");
+    assert_eq!(kccgst.symbols, "pc+us+de:2+inet(evdev)");
+
+    // The defaults are used for missing names
+    let kccgst = ComponentNames::from_names(&mut ctx, None).unwrap();
+    assert_eq!(kccgst.symbols, "pc+us+inet(evdev)");
+
+    assert!(ComponentNames::from_names(
+        &mut ctx,
+        Some(RuleNames::new("does-not-exist", "", "us", "", ""))
+    )
+    .is_err());
+}
@@ -0,0 +1,269 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+use crate::test::*;
+use crate::xkb_state::*;
+use crate::*;
+use evdev::Key;
+use xkeysym::Keysym;
+
+const EVDEV_OFFSET: u32 = 8;
+
+fn get_keymap() -> Keymap {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let keymap = test_compile_rules(
+        context,
+        Some("evdev"),
+        Some("pc104"),
+        Some("us,ru"),
+        None,
+        Some("grp:menu_toggle"),
+    );
+
+    keymap.unwrap()
+}
+
+fn press(seat: &mut SeatState, device: DeviceId, key: Key) -> StateComponent {
+    seat.update_key(device, key.0 as u32 + EVDEV_OFFSET, KeyDirection::Down)
+        .unwrap()
+}
+
+fn release(seat: &mut SeatState, device: DeviceId, key: Key) -> StateComponent {
+    seat.update_key(device, key.0 as u32 + EVDEV_OFFSET, KeyDirection::Up)
+        .unwrap()
+}
+
+fn one_sym(seat: &SeatState, key: Key) -> Option<Keysym> {
+    seat.state().key_get_one_sym(key.0 as u32 + EVDEV_OFFSET)
+}
+
+#[test]
+fn test_seat_depressed() {
+    let mut seat = SeatState::new(get_keymap());
+    let a = seat.add_device();
+    let b = seat.add_device();
+    assert_ne!(a, b);
+
+    // Shift on one keyboard applies to the other one
+    let changed = press(&mut seat, a, Key::KEY_LEFTSHIFT);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_DEPRESSED | StateComponent::MODS_EFFECTIVE
+    );
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::A));
+    assert!(!seat
+        .device_state(b)
+        .unwrap()
+        .mod_name_is_active(ModName::SHIFT, StateComponent::MODS_DEPRESSED)
+        .unwrap());
+    release(&mut seat, a, Key::KEY_LEFTSHIFT);
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::a));
+
+    // Ctrl stays depressed until released on every keyboard
+    press(&mut seat, a, Key::KEY_LEFTCTRL);
+    let changed = press(&mut seat, b, Key::KEY_LEFTCTRL);
+    assert!(changed.is_empty());
+    let changed = release(&mut seat, a, Key::KEY_LEFTCTRL);
+    assert!(changed.is_empty());
+    assert!(seat
+        .state()
+        .mod_name_is_active(ModName::CTRL, StateComponent::MODS_DEPRESSED)
+        .unwrap());
+    let changed = release(&mut seat, b, Key::KEY_LEFTCTRL);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_DEPRESSED | StateComponent::MODS_EFFECTIVE
+    );
+    assert!(!seat
+        .state()
+        .mod_name_is_active(ModName::CTRL, StateComponent::MODS_EFFECTIVE)
+        .unwrap());
+
+    // Removing a keyboard releases its modifiers
+    press(&mut seat, b, Key::KEY_RIGHTSHIFT);
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::A));
+    let changed = seat.remove_device(b).unwrap();
+    assert_eq!(
+        changed,
+        StateComponent::MODS_DEPRESSED | StateComponent::MODS_EFFECTIVE
+    );
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::a));
+    assert!(seat.remove_device(b).is_none());
+    assert!(seat
+        .update_key(b, Key::KEY_A.0 as u32 + EVDEV_OFFSET, KeyDirection::Down)
+        .is_none());
+    assert_eq!(seat.iter_devices().collect::<Vec<_>>(), vec![&a]);
+}
+
+#[test]
+fn test_seat_locked() {
+    let mut seat = SeatState::new(get_keymap());
+    let a = seat.add_device();
+    let b = seat.add_device();
+
+    // Caps Lock locked on one keyboard...
+    press(&mut seat, a, Key::KEY_CAPSLOCK);
+    release(&mut seat, a, Key::KEY_CAPSLOCK);
+    assert!(seat.state().led_name_is_active(LedName::CAPS).unwrap());
+    assert!(seat
+        .device_state(b)
+        .unwrap()
+        .mod_name_is_active(ModName::CAPS, StateComponent::MODS_LOCKED)
+        .unwrap());
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::A));
+
+    // ...is unlocked from the other one
+    press(&mut seat, b, Key::KEY_CAPSLOCK);
+    let changed = release(&mut seat, b, Key::KEY_CAPSLOCK);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_DEPRESSED
+            | StateComponent::MODS_LOCKED
+            | StateComponent::MODS_EFFECTIVE
+            | StateComponent::LEDS
+    );
+    assert!(!seat.state().led_name_is_active(LedName::CAPS).unwrap());
+    assert!(!seat
+        .device_state(a)
+        .unwrap()
+        .mod_name_is_active(ModName::CAPS, StateComponent::MODS_LOCKED)
+        .unwrap());
+
+    // The locked layout is shared as well
+    press(&mut seat, a, Key::KEY_COMPOSE);
+    release(&mut seat, a, Key::KEY_COMPOSE);
+    assert_eq!(seat.serialize_layout(StateComponent::LAYOUT_LOCKED), 1);
+    assert_eq!(
+        seat.device_state(b)
+            .unwrap()
+            .serialize_layout(StateComponent::LAYOUT_LOCKED),
+        1
+    );
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::Cyrillic_ef));
+
+    // New keyboards start with the locks of the seat
+    let c = seat.add_device();
+    assert_eq!(
+        seat.device_state(c)
+            .unwrap()
+            .serialize_layout(StateComponent::LAYOUT_LOCKED),
+        1
+    );
+}
+
+#[test]
+fn test_seat_latched() {
+    let mut seat = SeatState::new(get_keymap());
+    let a = seat.add_device();
+    let b = seat.add_device();
+
+    let shift = seat.get_keymap().mod_get_index(ModName::SHIFT).unwrap();
+
+    let changed = seat.update_latched_locked(1 << shift, 1 << shift, None, 0, 0, None);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_LATCHED | StateComponent::MODS_EFFECTIVE
+    );
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::A));
+
+    // The latch is cleared by a key press on either keyboard
+    let changed = press(&mut seat, b, Key::KEY_A);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_LATCHED | StateComponent::MODS_EFFECTIVE
+    );
+    release(&mut seat, b, Key::KEY_A);
+    assert_eq!(seat.serialize_mods(StateComponent::MODS_LATCHED), 0);
+    assert_eq!(
+        seat.device_state(a)
+            .unwrap()
+            .serialize_mods(StateComponent::MODS_LATCHED),
+        0
+    );
+
+    // The cancelled latch is not applied by the other keyboard later
+    press(&mut seat, a, Key::KEY_A);
+    release(&mut seat, a, Key::KEY_A);
+    assert_eq!(seat.serialize_mods(StateComponent::MODS_EFFECTIVE), 0);
+}
+
+#[test]
+fn test_seat_latched_no_devices() {
+    let mut seat = SeatState::new(get_keymap());
+
+    // The seat keeps the latches without any device...
+    let changed = seat.update_latched_locked(0, 0, Some(1), 0, 0, None);
+    assert_eq!(
+        changed,
+        StateComponent::LAYOUT_LATCHED | StateComponent::LAYOUT_EFFECTIVE | StateComponent::LEDS
+    );
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::Cyrillic_ef));
+
+    // ...and they are cleared by the next key press of a new keyboard
+    let a = seat.add_device();
+    press(&mut seat, a, Key::KEY_A);
+    release(&mut seat, a, Key::KEY_A);
+    assert_eq!(seat.serialize_layout(StateComponent::LAYOUT_LATCHED), 0);
+    assert_eq!(one_sym(&seat, Key::KEY_A), Some(Keysym::a));
+}
+
+#[test]
+fn test_seat_max_mods() {
+    let context = test_get_context(TestContextFlags::empty()).unwrap();
+    let vmods = (1..=24)
+        .map(|i| format!("V{:02}", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let keymap = test_compile_string(
+        context,
+        format!(
+            "xkb_keymap {{
+                xkb_keycodes {{ <AC01> = 38; }};
+                xkb_types {{ virtual_modifiers {}; }};
+                xkb_compat {{ }};
+                xkb_symbols {{ key <AC01> {{ [ a ] }}; }};
+            }};",
+            vmods
+        ),
+    )
+    .unwrap();
+    assert_eq!(keymap.num_mods(), 32);
+
+    let mut seat = SeatState::new(keymap);
+    let a = seat.add_device();
+    let b = seat.add_device();
+
+    let v24 = seat.get_keymap().mod_get_index("V24").unwrap();
+    assert_eq!(v24, 31);
+    seat.update_latched_locked(0, 0, None, 1 << v24, 1 << v24, None);
+    assert_eq!(seat.serialize_mods(StateComponent::MODS_LOCKED), 1 << v24);
+    for device in [a, b] {
+        assert_eq!(
+            seat.device_state(device)
+                .unwrap()
+                .serialize_mods(StateComponent::MODS_LOCKED),
+            1 << v24
+        );
+    }
+}
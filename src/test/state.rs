@@ -394,6 +394,151 @@ fn test_state_update_mask_mods() {
     );
 }
 
+#[test]
+fn test_state_update_latched_locked() {
+    let keymap = get_keymap_1();
+
+    let mut state = State::new(keymap.clone());
+
+    let caps = keymap.mod_get_index(ModName::CAPS).unwrap();
+    let shift = keymap.mod_get_index(ModName::SHIFT).unwrap();
+    let ctrl = keymap.mod_get_index(ModName::CTRL).unwrap();
+    let num = keymap.mod_get_index("NumLock").unwrap();
+    let mod2 = keymap.mod_get_index("Mod2").unwrap();
+
+    // Lock Caps without a key event
+    let changed = state.update_latched_locked(0, 0, None, 1 << caps, 1 << caps, None);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_LOCKED | StateComponent::MODS_EFFECTIVE | StateComponent::LEDS
+    );
+    assert!(state.led_name_is_active(LedName::CAPS).unwrap());
+    assert_eq!(
+        state.key_get_one_sym(Key::KEY_A.0 as u32 + EVDEV_OFFSET),
+        Some(Keysym::A)
+    );
+
+    // Affecting nothing changes nothing
+    let changed = state.update_latched_locked(0, 1 << shift, None, 0, 1 << ctrl, None);
+    assert!(changed.is_empty());
+
+    // Virtual modifiers are resolved
+    let changed = state.update_latched_locked(0, 0, None, 1 << num, 1 << num, None);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_LOCKED | StateComponent::MODS_EFFECTIVE | StateComponent::LEDS
+    );
+    assert_eq!(
+        state.serialize_mods(StateComponent::MODS_LOCKED),
+        1 << caps | 1 << num | 1 << mod2
+    );
+    state.update_latched_locked(0, 0, None, 1 << num, 0, None);
+    assert_eq!(state.serialize_mods(StateComponent::MODS_LOCKED), 1 << caps);
+
+    // Depressed modifiers are left untouched
+    state.update_key(
+        Key::KEY_LEFTCTRL.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    state.update_latched_locked(0, 0, None, 1 << caps, 0, None);
+    assert_eq!(
+        state.serialize_mods(StateComponent::MODS_DEPRESSED),
+        1 << ctrl
+    );
+    assert_eq!(state.serialize_mods(StateComponent::MODS_LOCKED), 0);
+    state.update_key(Key::KEY_LEFTCTRL.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    assert_eq!(state.serialize_mods(StateComponent::MODS_EFFECTIVE), 0);
+
+    // Lock the second layout
+    let changed = state.update_latched_locked(0, 0, None, 0, 0, Some(1));
+    assert_eq!(
+        changed,
+        StateComponent::LAYOUT_LOCKED | StateComponent::LAYOUT_EFFECTIVE | StateComponent::LEDS
+    );
+    assert_eq!(state.serialize_layout(StateComponent::LAYOUT_EFFECTIVE), 1);
+    assert_eq!(
+        state.key_get_one_sym(Key::KEY_A.0 as u32 + EVDEV_OFFSET),
+        Some(Keysym::Cyrillic_ef)
+    );
+
+    // Out of range layouts are wrapped
+    state.update_latched_locked(0, 0, None, 0, 0, Some(3));
+    assert_eq!(state.serialize_layout(StateComponent::LAYOUT_LOCKED), 1);
+    state.update_latched_locked(0, 0, None, 0, 0, Some(0));
+
+    // Latched layouts are cleared by the next key press
+    let changed = state.update_latched_locked(0, 0, Some(1), 0, 0, None);
+    assert_eq!(
+        changed,
+        StateComponent::LAYOUT_LATCHED | StateComponent::LAYOUT_EFFECTIVE | StateComponent::LEDS
+    );
+    // Modifier keys do not break the latch
+    state.update_key(
+        Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    state.update_key(Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    assert_eq!(state.serialize_layout(StateComponent::LAYOUT_LATCHED), 1);
+    assert_eq!(
+        state.key_get_one_sym(Key::KEY_A.0 as u32 + EVDEV_OFFSET),
+        Some(Keysym::Cyrillic_ef)
+    );
+    let changed = state.update_key(Key::KEY_A.0 as u32 + EVDEV_OFFSET, KeyDirection::Down);
+    assert_eq!(
+        changed,
+        StateComponent::LAYOUT_LATCHED | StateComponent::LAYOUT_EFFECTIVE | StateComponent::LEDS
+    );
+    state.update_key(Key::KEY_A.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    assert_eq!(state.serialize_layout(StateComponent::LAYOUT_LATCHED), 0);
+    assert_eq!(
+        state.key_get_one_sym(Key::KEY_A.0 as u32 + EVDEV_OFFSET),
+        Some(Keysym::a)
+    );
+
+    // Latching the base layout cancels a pending layout latch
+    state.update_latched_locked(0, 0, Some(1), 0, 0, None);
+    state.update_latched_locked(0, 0, Some(0), 0, 0, None);
+    assert_eq!(state.serialize_layout(StateComponent::LAYOUT_LATCHED), 0);
+    state.update_latched_locked(0, 0, None, 0, 0, Some(1));
+    state.update_key(Key::KEY_A.0 as u32 + EVDEV_OFFSET, KeyDirection::Down);
+    state.update_key(Key::KEY_A.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    assert_eq!(state.serialize_layout(StateComponent::LAYOUT_EFFECTIVE), 1);
+    state.update_latched_locked(0, 0, None, 0, 0, Some(0));
+
+    // Latched modifiers are cleared by the next key press
+    let changed = state.update_latched_locked(1 << shift, 1 << shift, None, 0, 0, None);
+    assert_eq!(
+        changed,
+        StateComponent::MODS_LATCHED | StateComponent::MODS_EFFECTIVE
+    );
+    assert_eq!(
+        state.key_get_one_sym(Key::KEY_A.0 as u32 + EVDEV_OFFSET),
+        Some(Keysym::A)
+    );
+    state.update_key(Key::KEY_A.0 as u32 + EVDEV_OFFSET, KeyDirection::Down);
+    state.update_key(Key::KEY_A.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    assert_eq!(state.serialize_mods(StateComponent::MODS_LATCHED), 0);
+    assert_eq!(
+        state.key_get_one_sym(Key::KEY_A.0 as u32 + EVDEV_OFFSET),
+        Some(Keysym::a)
+    );
+
+    // A cleared latch is not cleared again by a later key press
+    state.update_latched_locked(1 << shift, 1 << shift, None, 0, 0, None);
+    state.update_latched_locked(1 << shift, 0, None, 0, 0, None);
+    assert_eq!(state.serialize_mods(StateComponent::MODS_LATCHED), 0);
+    state.update_latched_locked(1 << ctrl, 1 << ctrl, None, 0, 0, None);
+    state.update_key(
+        Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    assert_eq!(
+        state.serialize_mods(StateComponent::MODS_EFFECTIVE),
+        1 << shift | 1 << ctrl
+    );
+    state.update_key(Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+}
+
 #[test]
 fn test_state_repeat() {
     let keymap = get_keymap_1();
@@ -404,6 +549,275 @@ fn test_state_repeat() {
     assert!(keymap.key_repeats(Key::KEY_KBDILLUMDOWN.0 as u32 + EVDEV_OFFSET));
 }
 
+fn check_resolve_all_keys(state: &State) {
+    for mode in [
+        ConsumedMode::Xkb,
+        ConsumedMode::Gtk,
+        ConsumedMode::Windows,
+        ConsumedMode::MacOs,
+    ] {
+        let mut count = 0;
+
+        for resolved in state.resolve_all_keys(mode) {
+            let kc = resolved.keycode;
+            assert_eq!(Some(resolved.layout), state.key_get_layout(kc));
+            assert_eq!(
+                Some(resolved.level),
+                state.key_get_level(kc, resolved.layout)
+            );
+            assert_eq!(resolved.syms, state.key_get_syms(kc));
+            assert_eq!(resolved.utf8, state.key_get_utf8(kc));
+            assert_eq!(
+                resolved.consumed_mods,
+                state.key_get_consumed_mods2(kc, mode)
+            );
+            assert_eq!(resolved.repeats, state.get_keymap().key_repeats(kc));
+            count += 1;
+        }
+
+        let expected = state
+            .get_keymap()
+            .iter_keycodes()
+            .filter(|kc| state.key_get_layout(**kc).is_some())
+            .count();
+        assert_eq!(count, expected);
+    }
+}
+
+#[test]
+fn test_state_resolve_all_keys() {
+    let keymap = get_keymap_1();
+    let mut state = State::new(keymap.clone());
+
+    check_resolve_all_keys(&state);
+
+    // Caps locked
+    state.update_key(
+        Key::KEY_CAPSLOCK.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    state.update_key(Key::KEY_CAPSLOCK.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    check_resolve_all_keys(&state);
+
+    // Shift and Num Lock
+    state.update_key(Key::KEY_NUMLOCK.0 as u32 + EVDEV_OFFSET, KeyDirection::Down);
+    state.update_key(Key::KEY_NUMLOCK.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    state.update_key(
+        Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    check_resolve_all_keys(&state);
+    state.update_key(Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+
+    // Control in the ru layout
+    state.update_key(Key::KEY_COMPOSE.0 as u32 + EVDEV_OFFSET, KeyDirection::Down);
+    state.update_key(Key::KEY_COMPOSE.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    state.update_key(
+        Key::KEY_RIGHTCTRL.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    check_resolve_all_keys(&state);
+
+    let a = state
+        .resolve_all_keys(ConsumedMode::Xkb)
+        .find(|resolved| resolved.keycode.raw() == Key::KEY_A.0 as u32 + EVDEV_OFFSET)
+        .unwrap();
+    assert_eq!(a.layout, 1);
+    assert_eq!(a.syms, vec![Keysym::Cyrillic_EF]);
+    assert_eq!(a.utf8, Some(b"\x01".to_vec()));
+    assert!(a.repeats);
+}
+
+#[test]
+fn test_state_update_with_changes() {
+    let keymap = get_keymap_1();
+
+    let mut state = State::new(keymap.clone());
+
+    let shift = keymap.mod_get_index(ModName::SHIFT).unwrap();
+    let caps = keymap.mod_get_index(ModName::CAPS).unwrap();
+    let caps_led = keymap.led_get_index(LedName::CAPS).unwrap();
+    let group_led = keymap.led_get_index("Group 2").unwrap();
+
+    let changes = state.update_key_with_changes(
+        Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    assert_eq!(
+        changes.components,
+        StateComponent::MODS_DEPRESSED | StateComponent::MODS_EFFECTIVE
+    );
+    assert_eq!(
+        changes.events,
+        vec![StateEvent::ModPressed {
+            index: shift,
+            name: ModName::SHIFT.name().into()
+        }]
+    );
+
+    // Holding another Shift key changes nothing
+    let changes = state.update_key_with_changes(
+        Key::KEY_RIGHTSHIFT.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    assert!(changes.is_empty());
+    assert!(changes.events.is_empty());
+    state.update_key(
+        Key::KEY_RIGHTSHIFT.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Up,
+    );
+    state.update_key(Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+
+    // Caps Lock is depressed and locked, and turns on its LED
+    let changes = state.update_key_with_changes(
+        Key::KEY_CAPSLOCK.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    assert_eq!(
+        changes.events,
+        vec![
+            StateEvent::ModPressed {
+                index: caps,
+                name: ModName::CAPS.name().into()
+            },
+            StateEvent::ModLocked {
+                index: caps,
+                name: ModName::CAPS.name().into()
+            },
+            StateEvent::LedOn {
+                index: caps_led,
+                name: LedName::CAPS.name().into()
+            },
+        ]
+    );
+    let changes =
+        state.update_key_with_changes(Key::KEY_CAPSLOCK.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    assert_eq!(
+        changes.events,
+        vec![StateEvent::ModReleased {
+            index: caps,
+            name: ModName::CAPS.name().into()
+        }]
+    );
+
+    // Switch layout
+    let changes =
+        state.update_key_with_changes(Key::KEY_COMPOSE.0 as u32 + EVDEV_OFFSET, KeyDirection::Down);
+    assert_eq!(
+        changes.events,
+        vec![
+            StateEvent::LayoutChanged { from: 0, to: 1 },
+            StateEvent::LedOn {
+                index: group_led,
+                name: "Group 2".into()
+            },
+        ]
+    );
+    state.update_key(Key::KEY_COMPOSE.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+
+    // The same changes are reported from masks
+    let changes = state.update_mask_with_changes(1 << shift, 0, 0, 0, 0, 0);
+    assert_eq!(
+        changes.events,
+        vec![
+            StateEvent::ModPressed {
+                index: shift,
+                name: ModName::SHIFT.name().into()
+            },
+            StateEvent::ModUnlocked {
+                index: caps,
+                name: ModName::CAPS.name().into()
+            },
+            StateEvent::LayoutChanged { from: 1, to: 0 },
+            StateEvent::LedOff {
+                index: caps_led,
+                name: LedName::CAPS.name().into()
+            },
+            StateEvent::LedOff {
+                index: group_led,
+                name: "Group 2".into()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_state_shortcut_keysym() {
+    let keymap = get_keymap_1();
+
+    let mut state = State::new(keymap.clone());
+
+    let shift = keymap.mod_get_index(ModName::SHIFT).unwrap();
+    let caps = keymap.mod_get_index(ModName::CAPS).unwrap();
+    let ctrl = keymap.mod_get_index(ModName::CTRL).unwrap();
+
+    let a = Key::KEY_A.0 as u32 + EVDEV_OFFSET;
+    let one = Key::KEY_1.0 as u32 + EVDEV_OFFSET;
+    let semicolon = Key::KEY_SEMICOLON.0 as u32 + EVDEV_OFFSET;
+
+    state.update_key(
+        Key::KEY_LEFTCTRL.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    state.update_key(
+        Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+
+    // Shift only changes the case of a letter
+    assert_eq!(
+        state.key_get_shortcut_keysym(a, ConsumedMode::Xkb),
+        Some((Keysym::A, 1 << ctrl))
+    );
+    assert_eq!(
+        state.key_get_shortcut_keysym(a, ConsumedMode::Windows),
+        Some((Keysym::a, 1 << ctrl | 1 << shift))
+    );
+    assert_eq!(
+        state.key_get_shortcut_keysym(a, ConsumedMode::MacOs),
+        Some((Keysym::a, 1 << ctrl | 1 << shift))
+    );
+    assert_eq!(state.key_get_consumed_mods2(a, ConsumedMode::Windows), 0);
+
+    // Shift turns a digit into punctuation
+    assert_eq!(
+        state.key_get_shortcut_keysym(one, ConsumedMode::Windows),
+        Some((Keysym::exclam, 1 << ctrl))
+    );
+    assert_eq!(
+        state.key_get_consumed_mods2(one, ConsumedMode::Windows),
+        1 << shift
+    );
+
+    // Shift does not change the class of punctuation
+    assert_eq!(
+        state.key_get_shortcut_keysym(semicolon, ConsumedMode::Windows),
+        Some((Keysym::semicolon, 1 << ctrl | 1 << shift))
+    );
+
+    // Lock is not consumed by letters either
+    state.update_key(Key::KEY_LEFTSHIFT.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    state.update_key(
+        Key::KEY_CAPSLOCK.0 as u32 + EVDEV_OFFSET,
+        KeyDirection::Down,
+    );
+    state.update_key(Key::KEY_CAPSLOCK.0 as u32 + EVDEV_OFFSET, KeyDirection::Up);
+    assert_eq!(
+        state.key_get_shortcut_keysym(a, ConsumedMode::Windows),
+        Some((Keysym::a, 1 << ctrl | 1 << caps))
+    );
+    assert_eq!(
+        state.key_get_shortcut_keysym(a, ConsumedMode::Xkb),
+        Some((Keysym::A, 1 << ctrl))
+    );
+
+    // Invalid key
+    assert_eq!(
+        state.key_get_shortcut_keysym(XKB_KEYCODE_INVALID - 1, ConsumedMode::Windows),
+        None
+    );
+}
+
 #[test]
 fn test_state_consume() {
     let keymap = get_keymap_1();
@@ -849,3 +1263,136 @@ fn get_keymap_2() -> Keymap {
 
     keymap.unwrap()
 }
+
+#[cfg(feature = "unicode-case")]
+#[test]
+fn test_state_caps_case_mapper() {
+    // A level type which does not consume Lock, so that Caps is transformed
+    let keymap = test_compile_string(
+        test_get_context(TestContextFlags::empty()).unwrap(),
+        r#"xkb_keymap {
+            xkb_keycodes { <AD08> = 31; };
+            xkb_types { include "basic" };
+            xkb_compat { include "basic" };
+            xkb_symbols {
+                key <AD08> { type = "TWO_LEVEL", [ i, idotless ] };
+            };
+        };"#
+        .into(),
+    )
+    .unwrap();
+    let caps = keymap.mod_get_index(ModName::CAPS).unwrap();
+    let mut state = State::new(keymap);
+
+    state.update_mask(0, 0, 1 << caps, 0, 0, 0);
+    assert_eq!(state.key_get_one_sym(31u32).unwrap(), Keysym::I);
+
+    state.set_case_mapper(crate::keysym::KeysymCaseMapper::new("tr"));
+    assert_eq!(state.key_get_one_sym(31u32).unwrap(), Keysym::Iabovedot);
+    assert_eq!(state.key_get_utf8(31u32), Some("İ".as_bytes().to_vec()));
+
+    state.set_case_mapper(None);
+    assert_eq!(state.key_get_one_sym(31u32).unwrap(), Keysym::I);
+}
+
+#[test]
+fn test_key_get_text() {
+    let keymap = test_compile_string(
+        test_get_context(TestContextFlags::empty()).unwrap(),
+        r#"xkb_keymap {
+            xkb_keycodes {
+                <AC01> = 38; <AC02> = 39; <AC03> = 40; <AC04> = 41;
+                <AC05> = 42; <LFSH> = 50; <LCTL> = 37;
+            };
+            xkb_types { include "basic" };
+            xkb_compat { include "basic" };
+            xkb_symbols {
+                key <AC01> { [ a, A ] };
+                key <AC02> { [ { e, U0301 }, { E, U0301 } ] };
+                key <AC03> { [ dead_acute, dead_belowdot ] };
+                key <AC04> { [ { Shift_L, x }, Shift_R ] };
+                key <AC05> { [ U0301 ] };
+                key <LFSH> { [ Shift_L ] };
+                key <LCTL> { [ Control_L ] };
+                modifier_map Shift { <LFSH> };
+                modifier_map Control { <LCTL> };
+            };
+        };"#
+        .into(),
+    )
+    .unwrap();
+    let mut state = State::new(keymap);
+    let text = |state: &State, kc: u32, options| state.key_get_text(kc, options);
+
+    assert_eq!(
+        text(&state, 38, TextOptions::default()),
+        KeyText::Text("a".into())
+    );
+    assert_eq!(text(&state, 38, TextOptions::default()).as_str(), Some("a"));
+
+    // Several keysyms, composed if normalized
+    #[cfg(feature = "unicode-normalization")]
+    assert_eq!(
+        text(&state, 39, TextOptions::default()),
+        KeyText::Text("é".into())
+    );
+    #[cfg(not(feature = "unicode-normalization"))]
+    assert_eq!(
+        text(&state, 39, TextOptions::default()),
+        KeyText::Text("e\u{301}".into())
+    );
+    assert_eq!(
+        text(&state, 39, TextOptions::NO_FLAGS),
+        KeyText::Text("e\u{301}".into())
+    );
+    assert_eq!(
+        state.key_get_utf8(39u32),
+        Some("e\u{301}".as_bytes().to_vec())
+    );
+
+    // Dead keys
+    assert_eq!(text(&state, 40, TextOptions::default()), KeyText::NoText);
+    assert_eq!(
+        text(&state, 40, TextOptions::SPACING_DEAD_KEYS),
+        KeyText::Text("´".into())
+    );
+
+    // Keysyms without text are skipped
+    assert_eq!(
+        text(&state, 41, TextOptions::default()),
+        KeyText::Text("x".into())
+    );
+    assert_eq!(state.key_get_utf8(41u32), None);
+
+    // A lone combining character
+    assert_eq!(
+        text(&state, 42, TextOptions::default()),
+        KeyText::Text("\u{301}".into())
+    );
+
+    // Modifiers and invalid keycodes produce no text
+    assert_eq!(text(&state, 50, TextOptions::default()), KeyText::NoText);
+    assert_eq!(text(&state, 50, TextOptions::default()).as_str(), None);
+    assert_eq!(text(&state, 300, TextOptions::default()), KeyText::NoText);
+
+    state.update_key(50u32, KeyDirection::Down);
+    #[cfg(feature = "unicode-normalization")]
+    assert_eq!(
+        text(&state, 39, TextOptions::default()),
+        KeyText::Text("É".into())
+    );
+    assert_eq!(
+        text(&state, 40, TextOptions::SPACING_DEAD_KEYS),
+        KeyText::Text("\u{a0}\u{323}".into())
+    );
+    assert_eq!(text(&state, 41, TextOptions::default()), KeyText::NoText);
+    state.update_key(50u32, KeyDirection::Up);
+
+    // Control transformation
+    state.update_key(37u32, KeyDirection::Down);
+    assert_eq!(
+        text(&state, 38, TextOptions::default()),
+        KeyText::Text("\u{1}".into())
+    );
+    state.update_key(37u32, KeyDirection::Up);
+}
@@ -136,12 +136,17 @@ pub(crate) enum ExprOpType {
     UnaryPlus,
 }
 
+/// How definitions are merged with existing definitions of the same item.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
-pub(crate) enum MergeMode {
+pub enum MergeMode {
+    /// Use the merge mode of the enclosing file or statement.
     #[default]
     Default,
+    /// Keep the existing definitions; only add what is missing.
     Augment,
+    /// Replace the existing definitions, field by field.
     Override,
+    /// Replace the existing definitions entirely.
     Replace,
 }
 
@@ -204,6 +209,37 @@ pub(crate) enum Decl {
 }
 
 impl Decl {
+    /// Sets the merge mode of the statement, unless it has an explicit one.
+    fn set_default_merge(&mut self, merge: MergeMode) {
+        use Decl::*;
+        let stmt_merge = match self {
+            Include(stmt) => {
+                if let Some(part) = stmt.maps.first_mut() {
+                    if part.merge == MergeMode::Default {
+                        part.merge = merge;
+                    }
+                }
+                &mut stmt.merge
+            }
+            Var(def) => &mut def.merge,
+            VMod(def) => &mut def.merge,
+            Interp(def) => &mut def.merge,
+            KeyName(def) => &mut def.merge,
+            KeyAlias(def) => &mut def.merge,
+            KeyType(def) => &mut def.merge,
+            Symbols(def) => &mut def.merge,
+            ModMap(def) => &mut def.merge,
+            GroupCompat(def) => &mut def.merge,
+            LedMap(def) => &mut def.merge,
+            LedName(def) => &mut def.merge,
+            Shape | Section | Doodad | Skipped => return,
+        };
+
+        if *stmt_merge == MergeMode::Default {
+            *stmt_merge = merge;
+        }
+    }
+
     pub(super) fn stmt_type(&self) -> &'static str {
         use Decl::*;
         match self {
@@ -836,4 +872,48 @@ impl XkbFile {
     pub(crate) fn file_type(&self) -> XkbFileType {
         self.file_type
     }
+
+    /// Appends the statements of `fragment` to the sections of this keymap file.
+    ///
+    /// `fragment` may be a single section or a whole keymap. Its statements are
+    /// merged using `merge`, unless they have an explicit merge mode.
+    pub(crate) fn overlay(
+        &mut self,
+        mut fragment: XkbFile,
+        merge: MergeMode,
+    ) -> Result<(), KeymapCompileError> {
+        let merge = match merge {
+            MergeMode::Default => MergeMode::Override,
+            merge => merge,
+        };
+
+        let fragments: Vec<XkbFile> = match fragment.file_type {
+            XkbFileType::Keymap => fragment.take_files().collect(),
+            _ => vec![fragment],
+        };
+
+        for fragment in fragments {
+            let file_type = fragment.file_type;
+            if file_type < XkbFileType::first_type() || file_type > XkbFileType::last_type() {
+                log::error!("Cannot overlay a {:?} section on a keymap", file_type);
+                return Err(KeymapCompileError::CannotOverlay(file_type));
+            }
+
+            let section = self
+                .files
+                .iter_mut()
+                .flatten()
+                .find(|file| file.file_type == file_type)
+                .ok_or(KeymapCompileError::RequiredSectionMissing(file_type))?;
+
+            section
+                .defs
+                .extend(fragment.defs.into_iter().map(|mut decl| {
+                    decl.set_default_merge(merge);
+                    decl
+                }));
+        }
+
+        Ok(())
+    }
 }
@@ -230,11 +230,20 @@ impl Context {
     }
 }
 
+use crate::keymap::cache::ConsumedFile;
 use std::path::PathBuf;
 
 impl Context {
+    // Record a file which has just been opened, as it is when read
+    pub(super) fn consume_file(&mut self, path: PathBuf, file: &mut std::fs::File) {
+        match ConsumedFile::read(path, file) {
+            Ok(consumed) => self.consumed_files.push(consumed),
+            Err(e) => log::warn!("Could not record file for the keymap cache: {}", e),
+        }
+    }
+
     pub(super) fn find_file_in_xkb_path(
-        &self,
+        &mut self,
         name: Option<&String>,
         file_type: XkbFileType,
         offset: &mut usize,
@@ -251,14 +260,20 @@ impl Context {
             // TODO: limit buffer to size
             let buf = format!("{}/{}/{}", include_path, type_dir, name);
 
-            if let Ok(metadata) = std::fs::metadata(&buf) {
-                if metadata.is_file() {
-                    if let Ok(file) = std::fs::File::open(buf.clone()) {
+            match std::fs::metadata(&buf) {
+                Ok(metadata) if metadata.is_file() => {
+                    if let Ok(mut file) = std::fs::File::open(buf.clone()) {
                         *offset = i;
+                        if self.track_files {
+                            self.consume_file(buf.clone().into(), &mut file);
+                        }
 
                         return Some((buf.into(), file));
                     }
                 }
+                Ok(_) => {}
+                Err(_) if self.track_files => self.missing_files.push(buf.into()),
+                Err(_) => {}
             }
         }
 
@@ -327,5 +327,7 @@ impl Keymap {
         if let Some(val) = max_key_groups {
             self.num_groups = std::cmp::max(val, self.num_groups);
         }
+
+        self.fingerprint = self.compute_fingerprint();
     }
 }
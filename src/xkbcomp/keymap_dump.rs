@@ -56,8 +56,13 @@ use crate::rust_xkbcommon::*;
 
 use std::fmt::Write;
 
+#[derive(Default)]
 struct KeymapWriter {
     buf: String,
+
+    // Leave out what does not affect the meaning of the keymap,
+    // and write unordered items in a fixed order
+    canonical: bool,
 }
 
 impl KeymapWriter {
@@ -93,7 +98,11 @@ impl KeymapWriter {
 
     fn write_keycodes(&mut self, keymap: &Keymap) -> Result<(), Box<dyn std::error::Error>> {
         let ctx = &keymap.context;
-        if let Some(name) = keymap.keycodes_section_name.as_ref() {
+        if let Some(name) = keymap
+            .keycodes_section_name
+            .as_ref()
+            .filter(|_| !self.canonical)
+        {
             writeln!(&mut self.buf, "xkb_keycodes \"{}\" {{", name)?;
         } else {
             writeln!(&mut self.buf, "xkb_keycodes {{")?;
@@ -138,7 +147,12 @@ impl KeymapWriter {
             }
         }
 
-        for alias in keymap.key_aliases.iter() {
+        let mut aliases: Vec<_> = keymap.key_aliases.iter().collect();
+        if self.canonical {
+            aliases.sort_by_key(|alias| ctx.xkb_atom_text(alias.alias));
+        }
+
+        for alias in aliases {
             writeln!(
                 &mut self.buf,
                 "\talias {:14} = {};",
@@ -154,7 +168,11 @@ impl KeymapWriter {
     fn write_types(&mut self, keymap: &Keymap) -> Result<(), Box<dyn std::error::Error>> {
         let ctx = &keymap.context;
 
-        if let Some(name) = keymap.types_section_name.as_ref() {
+        if let Some(name) = keymap
+            .types_section_name
+            .as_ref()
+            .filter(|_| !self.canonical)
+        {
             writeln!(&mut self.buf, "xkb_types \"{}\" {{", name)?;
         } else {
             writeln!(&mut self.buf, "xkb_types {{")?;
@@ -162,50 +180,65 @@ impl KeymapWriter {
 
         self.write_vmods(ctx, keymap)?;
 
-        for _type in keymap.types.iter() {
-            writeln!(
-                &mut self.buf,
-                "\ttype \"{}\" {{",
-                ctx.xkb_atom_text(_type.name)
-            )?;
-
-            writeln!(
-                &mut self.buf,
-                "\t\tmodifiers= {};",
-                ctx.mod_mask_text(&keymap.mods, _type.mods.mods)
-            )?;
+        let mut types: Vec<_> = keymap.types.iter().collect();
+        if self.canonical {
+            types.sort_by_key(|_type| ctx.xkb_atom_text(_type.name));
+        }
 
-            for entry in _type.entries.iter() {
-                if entry.level == 0 && entry.preserve.mods == 0 {
-                    continue;
-                }
+        for _type in types {
+            self.write_type(ctx, keymap, _type)?;
+        }
+        write!(&mut self.buf, "}};\n\n")?;
 
-                let s = ctx.mod_mask_text(&keymap.mods, entry.mods.mods);
+        Ok(())
+    }
+    fn write_type(
+        &mut self,
+        ctx: &Context,
+        keymap: &Keymap,
+        _type: &KeyType,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        writeln!(
+            &mut self.buf,
+            "\ttype \"{}\" {{",
+            ctx.xkb_atom_text(_type.name)
+        )?;
 
-                writeln!(&mut self.buf, "\t\tmap[{}]= {};", s, entry.level + 1)?;
+        writeln!(
+            &mut self.buf,
+            "\t\tmodifiers= {};",
+            ctx.mod_mask_text(&keymap.mods, _type.mods.mods)
+        )?;
 
-                if entry.preserve.mods != 0 {
-                    writeln!(
-                        &mut self.buf,
-                        "\t\tpreserve[{}]= {};",
-                        s,
-                        ctx.mod_mask_text(&keymap.mods, entry.preserve.mods)
-                    )?;
-                }
+        for entry in _type.entries.iter() {
+            if entry.level == 0 && entry.preserve.mods == 0 {
+                continue;
             }
 
-            for (n, level_name) in _type.level_names.iter() {
+            let s = ctx.mod_mask_text(&keymap.mods, entry.mods.mods);
+
+            writeln!(&mut self.buf, "\t\tmap[{}]= {};", s, entry.level + 1)?;
+
+            if entry.preserve.mods != 0 {
                 writeln!(
                     &mut self.buf,
-                    "\t\tlevel_name[{}]= \"{}\";",
-                    n + 1,
-                    ctx.xkb_atom_text(*level_name)
+                    "\t\tpreserve[{}]= {};",
+                    s,
+                    ctx.mod_mask_text(&keymap.mods, entry.preserve.mods)
                 )?;
             }
+        }
 
-            writeln!(&mut self.buf, "\t}};")?;
+        for (n, level_name) in _type.level_names.iter() {
+            writeln!(
+                &mut self.buf,
+                "\t\tlevel_name[{}]= \"{}\";",
+                n + 1,
+                ctx.xkb_atom_text(*level_name)
+            )?;
         }
-        write!(&mut self.buf, "}};\n\n")?;
+
+        writeln!(&mut self.buf, "\t}};")?;
 
         Ok(())
     }
@@ -494,7 +527,11 @@ impl KeymapWriter {
     fn write_compat(&mut self, keymap: &Keymap) -> Result<(), Box<dyn std::error::Error>> {
         let ctx = &keymap.context;
 
-        if let Some(name) = keymap.compat_section_name.as_ref() {
+        if let Some(name) = keymap
+            .compat_section_name
+            .as_ref()
+            .filter(|_| !self.canonical)
+        {
             writeln!(&mut self.buf, "xkb_compatibility \"{}\" {{", name)?;
         } else {
             writeln!(&mut self.buf, "xkb_compatibility {{")?;
@@ -721,7 +758,11 @@ impl KeymapWriter {
     fn write_symbols(&mut self, keymap: &Keymap) -> Result<(), Box<dyn std::error::Error>> {
         let ctx = &keymap.context;
 
-        if let Some(name) = keymap.symbols_section_name.as_ref() {
+        if let Some(name) = keymap
+            .symbols_section_name
+            .as_ref()
+            .filter(|_| !self.canonical)
+        {
             writeln!(&mut self.buf, "xkb_symbols \"{}\" {{", name)?;
         } else {
             writeln!(&mut self.buf, "xkb_symbols {{")?;
@@ -781,9 +822,7 @@ impl KeymapWriter {
 }
 
 impl Keymap {
-    fn write_keymap(&self) -> Result<String, Box<dyn std::error::Error>> {
-        let mut writer = KeymapWriter { buf: String::new() };
-
+    fn write_keymap(&self, mut writer: KeymapWriter) -> Result<String, Box<dyn std::error::Error>> {
         writeln!(&mut writer.buf, "xkb_keymap {{")?;
         writer.write_keycodes(self)?;
         writer.write_types(self)?;
@@ -797,6 +836,41 @@ impl Keymap {
     pub(crate) fn text_v1_keymap_get_as_string(
         &self,
     ) -> Result<String, Box<dyn std::error::Error>> {
-        self.write_keymap()
+        self.write_keymap(KeymapWriter::default())
+    }
+
+    /// Returns the keymap as a string which only depends on its meaning.
+    ///
+    /// Unlike [Keymap::get_as_string()], this leaves out the section names,
+    /// and writes the key aliases and the key types sorted by name.
+    pub(crate) fn canonical_text(&self) -> Result<String, Box<dyn std::error::Error>> {
+        self.write_keymap(KeymapWriter {
+            canonical: true,
+            ..Default::default()
+        })
+    }
+
+    /// Returns an action as it would be written in a keymap.
+    pub(crate) fn action_text(&self, action: &Action) -> String {
+        let mut writer = KeymapWriter::default();
+        let _ = writer.write_action(&self.context, self, action, "", "");
+
+        writer.buf
+    }
+
+    /// Returns a key type definition as it would be written in a keymap.
+    pub(crate) fn key_type_text(&self, _type: &KeyType) -> String {
+        let mut writer = KeymapWriter::default();
+        let _ = writer.write_type(&self.context, self, _type);
+
+        writer.buf
+    }
+
+    /// Returns an LED definition as it would be written in a keymap.
+    pub(crate) fn led_text(&self, led: &Led) -> String {
+        let mut writer = KeymapWriter::default();
+        let _ = writer.write_led_map(&self.context, led, &self.mods);
+
+        writer.buf
     }
 }
@@ -54,6 +54,7 @@ use super::xkbcomp::ComponentNames;
 
 use crate::context::Context;
 use crate::errors::*;
+use crate::keymap::cache::ConsumedFile;
 use crate::keymap::XKB_MAX_GROUPS;
 use crate::rust_xkbcommon::*;
 
@@ -268,6 +269,10 @@ struct Matcher<'c> {
 
     // current rule
     rule: Rule,
+
+    // files opened through `! include`,
+    // added to the consumed files of the context
+    included_files: Vec<ConsumedFile>,
 }
 
 impl<'c> Matcher<'c> {
@@ -294,6 +299,8 @@ impl<'c> Matcher<'c> {
 
             mapping: Mapping::default(),
             rule: Rule::default(),
+
+            included_files: vec![],
         }
     }
 
@@ -362,7 +369,7 @@ impl<'c> Matcher<'c> {
             }
         }
 
-        let file = std::fs::File::open(&buf).map_err(|e| {
+        let mut file = std::fs::File::open(&buf).map_err(|e| {
             let error_msg = format!(
                 "{:?}: Failed to open included XKB rules \"{}\"",
                 XkbMessageCode::NoId,
@@ -375,6 +382,12 @@ impl<'c> Matcher<'c> {
                 error: e,
             }
         })?;
+        if self.ctx.track_files {
+            match ConsumedFile::read(buf.clone().into(), &mut file) {
+                Ok(consumed) => self.included_files.push(consumed),
+                Err(e) => log::warn!("Could not record file for the keymap cache: {}", e),
+            }
+        }
 
         // Read the rules file
         if let Err(e) = self.read_rules_file(include_depth + 1, file, buf.clone().into()) {
@@ -1137,29 +1150,32 @@ impl ComponentNames {
         let opt_file =
             context.find_file_in_xkb_path(rule_names.rules.as_ref(), XkbFileType::Rules, &mut 0);
 
+        // `find_file_in_xkb_path` logs the paths searched
+        let Some((path, file)) = opt_file else {
+            let name = rule_names.rules.clone().unwrap_or_default();
+            return Err(RulesCompileError::RulesFileNotFound(name));
+        };
+
         let mut matcher = Matcher::new(context, rule_names);
 
-        if let Some((path, file)) = opt_file {
-            let result = matcher.read_rules_file(0, file, path.clone());
+        let result = matcher.read_rules_file(0, file, path.clone());
 
-            if result.is_err()
-            || !matcher.kccgst.contains_key(&RulesKccgst::Keycodes) //keycodes 
-            || !matcher.kccgst.contains_key(&RulesKccgst::Types) //types
-            || !matcher.kccgst.contains_key(&RulesKccgst::Compat) //compat
+        if result.is_err()
+            || !matcher.kccgst.contains_key(&RulesKccgst::Keycodes)
+            || !matcher.kccgst.contains_key(&RulesKccgst::Types)
+            || !matcher.kccgst.contains_key(&RulesKccgst::Compat)
             || !matcher.kccgst.contains_key(&RulesKccgst::Symbols)
-            //symbols
-            {
-                log::error!(
-                    "{:?}: No components returned from XKB rules {:?}",
-                    XkbMessageCode::NoId,
-                    path
-                );
+        {
+            log::error!(
+                "{:?}: No components returned from XKB rules {:?}",
+                XkbMessageCode::NoId,
+                path
+            );
 
-                if let Err(e) = result {
-                    return Err(RulesCompileError::MatcherError(e));
-                } else {
-                    return Err(RulesCompileError::NoComponentsReturned(path));
-                }
+            if let Err(e) = result {
+                return Err(RulesCompileError::MatcherError(e));
+            } else {
+                return Err(RulesCompileError::NoComponentsReturned(path));
             }
         }
 
@@ -1186,6 +1202,9 @@ impl ComponentNames {
                 .unwrap_or_else(|| "".into()),
         };
 
+        let included_files = matcher.included_files;
+        context.consumed_files.extend(included_files);
+
         //TODO: logging
 
         Ok(out)
@@ -61,11 +61,34 @@ use crate::errors::*;
 use crate::keymap::*;
 use crate::rust_xkbcommon::*;
 
-pub(crate) struct ComponentNames {
-    pub(crate) keycodes: String,
-    pub(crate) types: String,
-    pub(crate) compat: String,
-    pub(crate) symbols: String,
+/// The KcCGST (Keycodes, Compat, Geometry, Symbols, Types) components which the rules
+/// resolve from RMLVO names, as in [ComponentNames::from_names()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentNames {
+    /// The keycodes component, e.g. `evdev+aliases(qwerty)`.
+    pub keycodes: String,
+    /// The types component, e.g. `complete`.
+    pub types: String,
+    /// The compat component, e.g. `complete`.
+    pub compat: String,
+    /// The symbols component, e.g. `pc+us+inet(evdev)`.
+    pub symbols: String,
+}
+
+impl ComponentNames {
+    /// Resolve the components of RMLVO names using the rules, without compiling a keymap.
+    ///
+    /// The names are completed with the defaults as in [Keymap::new_from_names()].
+    pub fn from_names(
+        context: &mut Context,
+        rmlvo: Option<RuleNames>,
+    ) -> Result<Self, KeymapCompileError> {
+        let mut rmlvo = rmlvo.unwrap_or(RuleNames::empty());
+
+        context.sanitize_rule_names(&mut rmlvo);
+
+        Ok(Self::from_rules(context, &rmlvo)?)
+    }
 }
 
 impl RuleNames {
@@ -271,6 +294,32 @@ impl KeymapBuilder<TextV1> {
 
         Ok(keymap)
     }
+    pub(crate) fn keymap_new_from_overlay(
+        mut self,
+        base: &str,
+        fragment: &str,
+        merge: MergeMode,
+    ) -> Result<Keymap, KeymapCompileError> {
+        let mut xkb_file = XkbFile::parse_string(&mut self.context, base, "(keymap)", None)
+            .map_err(|error| KeymapCompileError::CouldNotParseString { error })?
+            .ok_or(KeymapCompileError::NoMapFoundForString)?;
+
+        let fragment =
+            XkbFile::parse_string(&mut self.context, fragment, "(overlay fragment)", None)
+                .map_err(|error| KeymapCompileError::CouldNotParseString { error })?
+                .ok_or(KeymapCompileError::NoMapFoundForString)?;
+
+        xkb_file.overlay(fragment, merge)?;
+
+        self.compile_keymap_file(xkb_file)?;
+
+        let mut keymap = self.build();
+
+        keymap.update_derived_keymap_fields();
+
+        Ok(keymap)
+    }
+
     pub(crate) fn keymap_new_from_file(
         mut self,
         file: std::fs::File,
@@ -0,0 +1,222 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+//! Command-line handling shared by the tools.
+
+use xkbcommon_rs::xkb_context::ContextFlags;
+use xkbcommon_rs::xkb_keymap::RuleNames;
+use xkbcommon_rs::{Context, Keymap, KeymapFormat};
+
+/// Exit code for invalid command-line arguments, as in `xkbcli`.
+pub const EXIT_INVALID_USAGE: i32 = 2;
+
+/// Prints the library diagnostics to stderr.
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{}: {}",
+                record.level().as_str().to_lowercase(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Print the errors of the library, and with `verbose` also its warnings and debug messages.
+pub fn init_logging(verbose: bool) {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(match verbose {
+        true => log::LevelFilter::Debug,
+        false => log::LevelFilter::Error,
+    });
+}
+
+/// The command-line arguments, after the program name.
+pub struct Args {
+    args: std::env::Args,
+}
+
+impl Args {
+    pub fn new() -> Self {
+        let mut args = std::env::args();
+        args.next();
+
+        Self { args }
+    }
+
+    /// The next argument, split into its name and an inline value, e.g. for `--layout=us`.
+    pub fn next_arg(&mut self) -> Option<(String, Option<String>)> {
+        let arg = self.args.next()?;
+
+        match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => {
+                Some((name.to_owned(), Some(value.to_owned())))
+            }
+            _ => Some((arg, None)),
+        }
+    }
+
+    /// The value of an option, either inline or the next argument.
+    pub fn value(&mut self, name: &str, inline: Option<String>) -> Result<String, String> {
+        inline
+            .or_else(|| self.args.next())
+            .ok_or_else(|| format!("option {} requires a value", name))
+    }
+}
+
+/// The options selecting a keymap: RMLVO names or a keymap file, and the include paths.
+#[derive(Default)]
+pub struct KeymapArgs {
+    pub rules: Option<String>,
+    pub model: Option<String>,
+    pub layout: Option<String>,
+    pub variant: Option<String>,
+    pub options: Option<String>,
+    /// The keymap file to compile instead of the RMLVO names; `-` is stdin.
+    pub keymap: Option<String>,
+    /// The include paths, with `None` for the default include paths.
+    pub includes: Vec<Option<String>>,
+}
+
+pub const KEYMAP_USAGE: &str = " --rules <rules>        The XKB ruleset
+ --model <model>        The XKB model
+ --layout <layout>      The XKB layout
+ --variant <variant>    The XKB layout variant
+ --options <options>    The XKB options
+ --keymap <file>        Use a keymap file instead of RMLVO names; '-' reads stdin
+ --include <path>       Add the given path to the include path list. This option is
+                        order-dependent, include paths given first are searched first.
+                        If an include path is given, the default include path list is
+                        not used unless --include-defaults is also given.
+ --include-defaults     Add the default set of include directories.
+                        This option is order-dependent, include paths given first are
+                        searched first.";
+
+impl KeymapArgs {
+    /// Parse an option which selects the keymap.
+    ///
+    /// Returns `Ok(false)` if the option is not one of them.
+    pub fn parse(
+        &mut self,
+        args: &mut Args,
+        name: &str,
+        inline: Option<String>,
+    ) -> Result<bool, String> {
+        let field = match name {
+            "--rules" => &mut self.rules,
+            "--model" => &mut self.model,
+            "--layout" => &mut self.layout,
+            "--variant" => &mut self.variant,
+            "--options" => &mut self.options,
+            "--keymap" => &mut self.keymap,
+            "--include" => {
+                self.includes.push(Some(args.value(name, inline)?));
+                return Ok(true);
+            }
+            "--include-defaults" => {
+                self.includes.push(None);
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        };
+
+        *field = Some(args.value(name, inline)?);
+
+        Ok(true)
+    }
+
+    /// Whether any of the RMLVO names was given.
+    pub fn has_rmlvo(&self) -> bool {
+        [
+            &self.rules,
+            &self.model,
+            &self.layout,
+            &self.variant,
+            &self.options,
+        ]
+        .iter()
+        .any(|name| name.is_some())
+    }
+
+    pub fn rule_names(&self) -> RuleNames {
+        RuleNames {
+            rules: self.rules.clone(),
+            model: self.model.clone(),
+            layout: self.layout.clone(),
+            variant: self.variant.clone(),
+            options: self.options.clone(),
+        }
+    }
+
+    /// Create the context with the include paths.
+    pub fn context(&self) -> Result<Context, String> {
+        if self.includes.is_empty() {
+            return Context::new(ContextFlags::NO_FLAGS).map_err(|e| e.to_string());
+        }
+
+        let mut context =
+            Context::new(ContextFlags::NO_DEFAULT_INCLUDES).map_err(|e| e.to_string())?;
+
+        for include in &self.includes {
+            match include {
+                Some(path) => context
+                    .include_path_append(path)
+                    .map_err(|e| format!("failed to add include path {}: {}", path, e))?,
+                None => context
+                    .include_path_append_default()
+                    .map_err(|e| format!("failed to add the default include paths: {}", e))?,
+            }
+        }
+
+        Ok(context)
+    }
+
+    /// Compile the keymap from the keymap file, or else from the RMLVO names.
+    pub fn keymap(&self, context: Context) -> Result<Keymap, String> {
+        let Some(path) = &self.keymap else {
+            return Keymap::new_from_names(context, Some(self.rule_names()), 0)
+                .map_err(|e| format!("failed to compile the keymap: {}", e));
+        };
+
+        let string = match path.as_str() {
+            "-" => std::io::read_to_string(std::io::stdin()),
+            path => std::fs::read_to_string(path),
+        }
+        .map_err(|e| format!("failed to read {}: {}", path, e))?;
+
+        Keymap::new_from_string(context, &string, KeymapFormat::TextV1, 0)
+            .map_err(|e| format!("failed to compile the keymap from {}: {}", path, e))
+    }
+}
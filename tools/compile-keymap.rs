@@ -0,0 +1,127 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+//! Compile a keymap from RMLVO names or a keymap file, and print it.
+
+mod common;
+
+use common::*;
+use xkbcommon_rs::xkb_keymap::ComponentNames;
+use xkbcommon_rs::KeymapFormat;
+
+fn usage(program: &str) -> String {
+    format!(
+        "Usage: {} [OPTIONS]
+
+Compile the given RMLVO names or keymap file, and print the keymap
+
+Options:
+ --help                 Print this help and exit
+ --verbose              Enable verbose debugging output
+ --kccgst               Print the KcCGST components resolved by the rules instead of the keymap
+{}",
+        program, KEYMAP_USAGE
+    )
+}
+
+fn print_kccgst(args: &KeymapArgs) -> Result<(), String> {
+    let mut context = args.context()?;
+
+    let kccgst = ComponentNames::from_names(&mut context, Some(args.rule_names()))
+        .map_err(|e| format!("failed to resolve the components: {}", e))?;
+
+    println!("xkb_keymap {{");
+    println!("  xkb_keycodes {{ include \"{}\" }};", kccgst.keycodes);
+    println!("  xkb_types {{ include \"{}\" }};", kccgst.types);
+    println!("  xkb_compat {{ include \"{}\" }};", kccgst.compat);
+    println!("  xkb_symbols {{ include \"{}\" }};", kccgst.symbols);
+    println!("}};");
+
+    Ok(())
+}
+
+fn print_keymap(args: &KeymapArgs) -> Result<(), String> {
+    let keymap = args.keymap(args.context()?)?;
+
+    let string = keymap
+        .get_as_string(KeymapFormat::TextV1)
+        .map_err(|e| format!("failed to serialize the keymap: {}", e))?;
+
+    print!("{}", string);
+
+    Ok(())
+}
+
+fn main() {
+    let program = std::env::args()
+        .next()
+        .unwrap_or_else(|| "compile-keymap".into());
+
+    let mut args = Args::new();
+    let mut keymap_args = KeymapArgs::default();
+    let mut kccgst = false;
+    let mut verbose = false;
+
+    while let Some((name, inline)) = args.next_arg() {
+        match name.as_str() {
+            "--help" | "-h" => {
+                println!("{}", usage(&program));
+                return;
+            }
+            "--verbose" => verbose = true,
+            "--kccgst" => kccgst = true,
+            _ => match keymap_args.parse(&mut args, &name, inline) {
+                Ok(true) => {}
+                Ok(false) => {
+                    eprintln!("unrecognized argument: {}\n\n{}", name, usage(&program));
+                    std::process::exit(EXIT_INVALID_USAGE);
+                }
+                Err(e) => {
+                    eprintln!("{}\n\n{}", e, usage(&program));
+                    std::process::exit(EXIT_INVALID_USAGE);
+                }
+            },
+        }
+    }
+
+    if keymap_args.keymap.is_some() && (kccgst || keymap_args.has_rmlvo()) {
+        eprintln!(
+            "--keymap cannot be used with --kccgst or RMLVO names\n\n{}",
+            usage(&program)
+        );
+        std::process::exit(EXIT_INVALID_USAGE);
+    }
+
+    init_logging(verbose);
+
+    let result = match kccgst {
+        true => print_kccgst(&keymap_args),
+        false => print_keymap(&keymap_args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
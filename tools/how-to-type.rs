@@ -0,0 +1,227 @@
+/*
+ * Copyright © 2024 wysiwys
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice (including the next
+ * paragraph) shall be included in all copies or substantial portions of the
+ * Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+ * FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+ * DEALINGS IN THE SOFTWARE.
+ *
+ */
+
+//! Print the keys and modifiers which produce a character or a keysym.
+
+mod common;
+
+use common::*;
+use xkbcommon_rs::keysym::{keysym_from_name, keysym_get_name, keysym_suggest, KeysymFlags};
+use xkbcommon_rs::xkb_keymap::KeyLookup;
+use xkbcommon_rs::Keymap;
+use xkeysym::Keysym;
+
+fn usage(program: &str) -> String {
+    format!(
+        "Usage: {} [OPTIONS] <character/codepoint/keysym>
+
+Print the key combinations which produce the given character or keysym.
+The argument is a single character, a Unicode code point such as U+00E9 or 0xe9,
+or else the name of a keysym.
+
+Options:
+ --help                 Print this help and exit
+ --verbose              Enable verbose debugging output
+ --keysym               Treat the argument only as the name of a keysym
+{}",
+        program, KEYMAP_USAGE
+    )
+}
+
+/// What to look up, as given on the command line.
+enum Target {
+    Char(char),
+    Keysym(Keysym),
+}
+
+fn parse_codepoint(arg: &str) -> Option<char> {
+    let hex = arg
+        .strip_prefix("U+")
+        .or_else(|| arg.strip_prefix("0x"))
+        .or_else(|| arg.strip_prefix("0X"))?;
+
+    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+}
+
+fn parse_keysym(arg: &str) -> Result<Keysym, String> {
+    if let Some(keysym) = keysym_from_name(arg, KeysymFlags::NO_FLAGS)
+        .or_else(|| keysym_from_name(arg, KeysymFlags::CASE_INSENSITIVE))
+    {
+        return Ok(keysym);
+    }
+
+    match keysym_suggest(arg, 3).as_slice() {
+        [] => Err(format!("unrecognized keysym: {}", arg)),
+        suggestions => Err(format!(
+            "unrecognized keysym: {}; did you mean {}?",
+            arg,
+            suggestions.join(", ")
+        )),
+    }
+}
+
+fn parse_target(arg: &str, keysym_only: bool) -> Result<Target, String> {
+    if keysym_only {
+        return parse_keysym(arg).map(Target::Keysym);
+    }
+
+    let mut chars = arg.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(Target::Char(c));
+    }
+
+    match parse_codepoint(arg) {
+        Some(c) => Ok(Target::Char(c)),
+        None => parse_keysym(arg).map(Target::Keysym),
+    }
+}
+
+fn mod_names(keymap: &Keymap, mask: u32) -> String {
+    let names: Vec<_> = (0..keymap.num_mods())
+        .filter(|idx| mask & (1 << idx) != 0)
+        .filter_map(|idx| keymap.mod_get_name(idx))
+        .collect();
+
+    match names.as_slice() {
+        [] => "[ ]".into(),
+        names => format!("[ {} ]", names.join(" ")),
+    }
+}
+
+fn print_lookups(keymap: &Keymap, lookups: &[KeyLookup]) {
+    println!(
+        "{:<8} {:<9} {:<8} {:<20} {:<7} {:<20} MODIFIERS",
+        "KEYCODE", "KEY NAME", "LAYOUT", "LAYOUT NAME", "LEVEL#", "KEYSYM"
+    );
+
+    for lookup in lookups {
+        let keycode = lookup.keycode.raw();
+        let keysym = keymap
+            .key_get_syms_by_level(keycode, lookup.layout, lookup.level)
+            .ok()
+            .and_then(|syms| syms.first().and_then(keysym_get_name))
+            .unwrap_or_default();
+
+        for mask in &lookup.mods {
+            println!(
+                "{:<8} {:<9} {:<8} {:<20} {:<7} {:<20} {}",
+                keycode,
+                keymap.key_get_name(keycode).unwrap_or("?"),
+                lookup.layout + 1,
+                keymap.layout_get_name(lookup.layout).unwrap_or("?"),
+                lookup.level + 1,
+                keysym,
+                mod_names(keymap, *mask)
+            );
+        }
+    }
+}
+
+fn how_to_type(args: &KeymapArgs, target: &Target) -> Result<(), String> {
+    let keymap = args.keymap(args.context()?)?;
+
+    let lookups = match target {
+        Target::Char(c) => {
+            println!("character: {} (U+{:04X})", c, *c as u32);
+            keymap.char_lookup(*c)
+        }
+        Target::Keysym(keysym) => {
+            println!(
+                "keysym: {} ({:#06x})",
+                keysym_get_name(keysym).unwrap_or_default(),
+                keysym.raw()
+            );
+            keymap.keysym_lookup(*keysym)
+        }
+    };
+
+    print_lookups(&keymap, &lookups);
+
+    Ok(())
+}
+
+fn main() {
+    let program = std::env::args()
+        .next()
+        .unwrap_or_else(|| "how-to-type".into());
+
+    let mut args = Args::new();
+    let mut keymap_args = KeymapArgs::default();
+    let mut keysym_only = false;
+    let mut verbose = false;
+    let mut target = None;
+
+    while let Some((name, inline)) = args.next_arg() {
+        match name.as_str() {
+            "--help" | "-h" => {
+                println!("{}", usage(&program));
+                return;
+            }
+            "--verbose" => verbose = true,
+            "--keysym" => keysym_only = true,
+            _ => match keymap_args.parse(&mut args, &name, inline) {
+                Ok(true) => {}
+                Ok(false) if target.is_none() && !name.starts_with("--") => {
+                    target = Some(name);
+                }
+                Ok(false) => {
+                    eprintln!("unrecognized argument: {}\n\n{}", name, usage(&program));
+                    std::process::exit(EXIT_INVALID_USAGE);
+                }
+                Err(e) => {
+                    eprintln!("{}\n\n{}", e, usage(&program));
+                    std::process::exit(EXIT_INVALID_USAGE);
+                }
+            },
+        }
+    }
+
+    if keymap_args.keymap.is_some() && keymap_args.has_rmlvo() {
+        eprintln!(
+            "--keymap cannot be used with RMLVO names\n\n{}",
+            usage(&program)
+        );
+        std::process::exit(EXIT_INVALID_USAGE);
+    }
+
+    let Some(target) = target else {
+        eprintln!("missing character or keysym\n\n{}", usage(&program));
+        std::process::exit(EXIT_INVALID_USAGE);
+    };
+
+    let target = match parse_target(&target, keysym_only) {
+        Ok(target) => target,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(EXIT_INVALID_USAGE);
+        }
+    };
+
+    init_logging(verbose);
+
+    if let Err(e) = how_to_type(&keymap_args, &target) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}